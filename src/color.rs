@@ -0,0 +1,184 @@
+/*!
+The color module provides an API-agnostic RGBA color type alongside the conversions commonly needed in 2D rendering.
+*/
+
+/**
+A color with red, green, blue and alpha components, each of which ranges from 0 to 1.
+
+Colors don't track whether their components are sRGB-encoded or linear, use [to_linear](Color::to_linear) and
+[to_srgb](Color::to_srgb) to convert between the two. Colors parsed from hex values are usually sRGB-encoded.
+
+# Example
+```
+use alloy_graphene::color::Color;
+
+let color = Color::from_hex_str("#ff8000").unwrap();
+assert_eq!(color.to_rgba8(), [255, 128, 0, 255]);
+assert_eq!(Color::from_hex_str("f80").unwrap(), Color::from_rgba8(255, 136, 0, 255));
+assert_eq!(Color::from_hex_str("#ff800080").unwrap(), Color::from_hex(0xFF800080));
+assert_eq!(Color::from_hex_str("f808").unwrap(), Color::from_hex(0xFF880088));
+assert!(Color::from_hex_str("#ff80").is_ok());
+assert!(Color::from_hex_str("#12345").is_err());
+assert!(Color::from_hex_str("#gggggg").is_err());
+
+let linear = Color::new(0.5, 0.25, 1.0, 0.5).to_linear();
+assert_eq!(linear.to_srgb().to_rgba8(), Color::new(0.5, 0.25, 1.0, 0.5).to_rgba8());
+```
+*/
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Color {
+    /// The red component
+    pub r: f32,
+    /// The green component
+    pub g: f32,
+    /// The blue component
+    pub b: f32,
+    /// The alpha component, 0 being fully transparent
+    pub a: f32,
+}
+
+/// Error enum for the failed parsing of a hex color string
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum ColorParseError {
+    /// Used if the string didn't consist of 3, 4, 6 or 8 hex digits after the optional `#`
+    #[error("Expected 3, 4, 6 or 8 hex digits but got {0} characters")]
+    InvalidLength(usize),
+    /// Used if the string contained a character that isn't a hex digit
+    #[error("{0} is not a valid hex color")]
+    InvalidDigit(String),
+}
+
+impl Color {
+    /// Opaque white
+    pub const WHITE: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+    /// Opaque black
+    pub const BLACK: Color = Color::new(0.0, 0.0, 0.0, 1.0);
+    /// Fully transparent black
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Returns a new color with the passed components
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color { r, g, b, a }
+    }
+
+    /// Returns a new color from components ranging from 0 to 255
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+
+    /// Returns a new color from a value in the `0xRRGGBBAA` format
+    pub fn from_u32(rgba: u32) -> Color {
+        let [r, g, b, a] = rgba.to_be_bytes();
+
+        Color::from_rgba8(r, g, b, a)
+    }
+
+    /// Returns a new color from a value in the `0xRRGGBBAA` format, an alias of [from_u32](Color::from_u32)
+    pub fn from_hex(hex: u32) -> Color {
+        Color::from_u32(hex)
+    }
+
+    /// Parses a color from a string in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` format, the `#` being optional.
+    /// Colors without an alpha component are opaque.
+    pub fn from_hex_str(s: &str) -> Result<Color, ColorParseError> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit(s.to_owned()));
+        }
+
+        let expanded = match digits.len() {
+            3 | 4 => digits.chars().flat_map(|c| [c, c]).collect::<String>(),
+            6 | 8 => digits.to_owned(),
+            len => return Err(ColorParseError::InvalidLength(len)),
+        };
+
+        let value = u32::from_str_radix(&expanded, 16).map_err(|_| ColorParseError::InvalidDigit(s.to_owned()))?;
+
+        Ok(match expanded.len() {
+            6 => Color::from_u32(value << 8 | 0xFF),
+            _ => Color::from_u32(value),
+        })
+    }
+
+    /// Returns the components in RGBA order, e.g. to pass them to glClearColor or a `vec4` uniform
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Returns the components in RGBA order scaled to range from 0 to 255, clamping components outside of 0 to 1
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        self.to_array().map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Converts the sRGB-encoded color components to linear ones, alpha is left untouched
+    pub fn to_linear(&self) -> Color {
+        Color::new(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a)
+    }
+
+    /// Converts the linear color components to sRGB-encoded ones, alpha is left untouched
+    pub fn to_srgb(&self) -> Color {
+        Color::new(linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b), self.a)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> [f32; 4] {
+        color.to_array()
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([r, g, b, a]: [f32; 4]) -> Color {
+        Color::new(r, g, b, a)
+    }
+}
+
+/// Converts a single sRGB-encoded component to linear, following the piecewise sRGB transfer function
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a single linear component to sRGB-encoded, following the piecewise sRGB transfer function
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_hex_forms() {
+        let expected = Color::from_u32(0xFF880088);
+
+        assert_eq!(Color::from_hex_str("#f808").unwrap(), expected);
+        assert_eq!(Color::from_hex_str("ff880088").unwrap(), expected);
+        assert_eq!(Color::from_hex_str("#f80").unwrap(), Color::from_u32(0xFF8800FF));
+        assert_eq!(Color::from_hex_str("ff8800").unwrap(), Color::from_u32(0xFF8800FF));
+        assert_eq!(Color::from_hex(0xFF880088), expected);
+    }
+
+    #[test]
+    fn rejects_malformed_hex_strings() {
+        assert_eq!(Color::from_hex_str("#12345"), Err(ColorParseError::InvalidLength(5)));
+        assert_eq!(Color::from_hex_str("#ggg"), Err(ColorParseError::InvalidDigit("#ggg".to_owned())));
+    }
+
+    #[test]
+    fn round_trips_through_arrays() {
+        let color = Color::from_hex_str("#ff800080").unwrap();
+
+        assert_eq!(color.to_array(), [1.0, 128.0 / 255.0, 0.0, 128.0 / 255.0]);
+        assert_eq!(Color::from(color.to_array()), color);
+        assert_eq!(<[f32; 4]>::from(Color::from([0.25, 0.5, 0.75, 1.0])), [0.25, 0.5, 0.75, 1.0]);
+    }
+}
@@ -0,0 +1,224 @@
+/*!
+The error module provides a single error type that every error of graphene can be converted into.
+
+The specific errors stay available, [GrapheneError](crate::error::GrapheneError) is meant for application code that doesn't need to
+tell them apart and wants to propagate all of them using `?`.
+
+# Example
+```
+use alloy_graphene::color::Color;
+use alloy_graphene::error::GrapheneError;
+
+fn background() -> Result<Color, GrapheneError> {
+    Ok(Color::from_hex_str("#20242c")?)
+}
+
+fn accent() -> Result<Color, GrapheneError> {
+    Ok(Color::from_hex_str("#zz0000")?)
+}
+
+assert!(background().is_ok());
+assert!(matches!(accent(), Err(GrapheneError::ColorParse(_))));
+```
+*/
+
+/// Unifies all errors of graphene, each variant wraps the error of the same name and displays exactly like it
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum GrapheneError {
+    /// See [ColorParseError](crate::color::ColorParseError)
+    #[error(transparent)]
+    ColorParse(#[from] crate::color::ColorParseError),
+    /// See [InvalidEnumValue](crate::opengl::InvalidEnumValue)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    InvalidEnumValue(#[from] crate::opengl::InvalidEnumValue),
+    /// See [ContextStringRetrievalError](crate::opengl::context::ContextStringRetrievalError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ContextStringRetrieval(#[from] crate::opengl::context::ContextStringRetrievalError),
+    /// See [ClipControlError](crate::opengl::state::ClipControlError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ClipControl(#[from] crate::opengl::state::ClipControlError),
+    /// See [ActiveTextureUnitError](crate::opengl::state::ActiveTextureUnitError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ActiveTextureUnit(#[from] crate::opengl::state::ActiveTextureUnitError),
+    /// See [RegionError](crate::opengl::state::RegionError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Region(#[from] crate::opengl::state::RegionError),
+    /// See [ShaderCreationError](crate::opengl::shader::ShaderCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ShaderCreation(#[from] crate::opengl::shader::ShaderCreationError),
+    /// See [ShaderCompileError](crate::opengl::shader::ShaderCompileError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ShaderCompile(#[from] crate::opengl::shader::ShaderCompileError),
+    /// See [ShaderCompileFromPathError](crate::opengl::shader::ShaderCompileFromPathError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ShaderCompileFromPath(#[from] crate::opengl::shader::ShaderCompileFromPathError),
+    /// See [SourceLenRetrievalError](crate::opengl::shader::SourceLenRetrievalError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    SourceLenRetrieval(#[from] crate::opengl::shader::SourceLenRetrievalError),
+    /// See [SourceRetrievalError](crate::opengl::shader::SourceRetrievalError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    SourceRetrieval(#[from] crate::opengl::shader::SourceRetrievalError),
+    /// See [SpirvQueryError](crate::opengl::shader::SpirvQueryError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    SpirvQuery(#[from] crate::opengl::shader::SpirvQueryError),
+    /// See [GeometryLimitError](crate::opengl::shader::GeometryLimitError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    GeometryLimit(#[from] crate::opengl::shader::GeometryLimitError),
+    /// See [ProgramCreationError](crate::opengl::program::ProgramCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ProgramCreation(#[from] crate::opengl::program::ProgramCreationError),
+    /// See [ProgramLinkError](crate::opengl::program::ProgramLinkError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ProgramLink(#[from] crate::opengl::program::ProgramLinkError),
+    /// See [ParallelBuildError](crate::opengl::program::ParallelBuildError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ParallelBuild(#[from] crate::opengl::program::ParallelBuildError),
+    /// See [ProgramFromFilesError](crate::opengl::program::ProgramFromFilesError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ProgramFromFiles(#[from] crate::opengl::program::ProgramFromFilesError),
+    /// See [UniformError](crate::opengl::program::UniformError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Uniform(#[from] crate::opengl::program::UniformError),
+    /// See [UniformReadError](crate::opengl::program::UniformReadError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    UniformRead(#[from] crate::opengl::program::UniformReadError),
+    /// See [IntrospectionError](crate::opengl::program::IntrospectionError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Introspection(#[from] crate::opengl::program::IntrospectionError),
+    /// See [UniformBlockError](crate::opengl::program::UniformBlockError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    UniformBlock(#[from] crate::opengl::program::UniformBlockError),
+    /// See [VaoCompatibilityError](crate::opengl::program::VaoCompatibilityError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    VaoCompatibility(#[from] crate::opengl::program::VaoCompatibilityError),
+    /// See [ProgramBinaryError](crate::opengl::program::ProgramBinaryError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    ProgramBinary(#[from] crate::opengl::program::ProgramBinaryError),
+    /// See [BufferCreationError](crate::opengl::buffer::BufferCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    BufferCreation(#[from] crate::opengl::buffer::BufferCreationError),
+    /// See [BufferDataError](crate::opengl::buffer::BufferDataError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    BufferData(#[from] crate::opengl::buffer::BufferDataError),
+    /// See [AlignmentError](crate::opengl::buffer::AlignmentError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Alignment(#[from] crate::opengl::buffer::AlignmentError),
+    /// See [BindRangeError](crate::opengl::buffer::BindRangeError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    BindRange(#[from] crate::opengl::buffer::BindRangeError),
+    /// See [DispatchError](crate::opengl::buffer::DispatchError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Dispatch(#[from] crate::opengl::buffer::DispatchError),
+    /// See [VertexArrayCreationError](crate::opengl::vertex_array::VertexArrayCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    VertexArrayCreation(#[from] crate::opengl::vertex_array::VertexArrayCreationError),
+    /// See [VertexAttribError](crate::opengl::vertex_array::VertexAttribError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    VertexAttrib(#[from] crate::opengl::vertex_array::VertexAttribError),
+    /// See [AttributeStateError](crate::opengl::vertex_array::AttributeStateError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    AttributeState(#[from] crate::opengl::vertex_array::AttributeStateError),
+    /// See [VertexBindingError](crate::opengl::vertex_array::VertexBindingError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    VertexBinding(#[from] crate::opengl::vertex_array::VertexBindingError),
+    /// See [MeshBuildError](crate::opengl::mesh::MeshBuildError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    MeshBuild(#[from] crate::opengl::mesh::MeshBuildError),
+    /// See [TextureCreationError](crate::opengl::texture::TextureCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    TextureCreation(#[from] crate::opengl::texture::TextureCreationError),
+    /// See [TextureUploadError](crate::opengl::texture::TextureUploadError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    TextureUpload(#[from] crate::opengl::texture::TextureUploadError),
+    /// See [TextureBuildError](crate::opengl::texture::TextureBuildError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    TextureBuild(#[from] crate::opengl::texture::TextureBuildError),
+    /// See [CompressedLoadError](crate::opengl::texture::CompressedLoadError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    CompressedLoad(#[from] crate::opengl::texture::CompressedLoadError),
+    /// See [TextureLoadError](crate::opengl::texture::TextureLoadError)
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    TextureLoad(#[from] crate::opengl::texture::TextureLoadError),
+    /// See [TextureReadError](crate::opengl::texture::TextureReadError)
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    TextureRead(#[from] crate::opengl::texture::TextureReadError),
+    /// See [FramebufferCreationError](crate::opengl::framebuffer::FramebufferCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    FramebufferCreation(#[from] crate::opengl::framebuffer::FramebufferCreationError),
+    /// See [FramebufferAttachError](crate::opengl::framebuffer::FramebufferAttachError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    FramebufferAttach(#[from] crate::opengl::framebuffer::FramebufferAttachError),
+    /// See [FramebufferInvalidateError](crate::opengl::framebuffer::FramebufferInvalidateError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    FramebufferInvalidate(#[from] crate::opengl::framebuffer::FramebufferInvalidateError),
+    /// See [FramebufferError](crate::opengl::framebuffer::FramebufferError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    Framebuffer(#[from] crate::opengl::framebuffer::FramebufferError),
+    /// See [RenderTargetError](crate::opengl::framebuffer::RenderTargetError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    RenderTarget(#[from] crate::opengl::framebuffer::RenderTargetError),
+    /// See [RenderbufferCreationError](crate::opengl::renderbuffer::RenderbufferCreationError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    RenderbufferCreation(#[from] crate::opengl::renderbuffer::RenderbufferCreationError),
+    /// See [RenderbufferStorageError](crate::opengl::renderbuffer::RenderbufferStorageError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    RenderbufferStorage(#[from] crate::opengl::renderbuffer::RenderbufferStorageError),
+    /// See [FontParseError](crate::opengl::font::FontParseError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    FontParse(#[from] crate::opengl::font::FontParseError),
+    /// See [SpriteBatchError](crate::opengl::batch::SpriteBatchError)
+    #[cfg(feature = "opengl")]
+    #[error(transparent)]
+    SpriteBatch(#[from] crate::opengl::batch::SpriteBatchError),
+    /// See [ReloadError](crate::opengl::hot_reload::ReloadError)
+    #[cfg(feature = "hot-reload")]
+    #[error(transparent)]
+    Reload(#[from] crate::opengl::hot_reload::ReloadError),
+}
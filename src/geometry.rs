@@ -0,0 +1,379 @@
+/*!
+The geometry module provides the primitives graphene uses to describe 2D space, independent of any graphic API.
+*/
+
+/// A point in 2D space
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Point {
+    /// The x coordinate
+    pub x: f32,
+    /// The y coordinate
+    pub y: f32,
+}
+
+impl Point {
+    /// Returns a new point at the passed coordinates
+    pub fn new(x: f32, y: f32) -> Point {
+        Point { x, y }
+    }
+}
+
+/// The extent of something in 2D space
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Size {
+    /// The width
+    pub w: f32,
+    /// The height
+    pub h: f32,
+}
+
+impl Size {
+    /// Returns a new size with the passed width and height
+    pub fn new(w: f32, h: f32) -> Size {
+        Size { w, h }
+    }
+}
+
+/**
+An axis-aligned rectangle described by its top-left corner and its size.
+
+Rectangles are half-open, they contain their top and left edges but not their bottom and right ones.
+This way rectangles that merely touch neither intersect nor share a point.
+
+# Example
+```
+use alloy_graphene::geometry::{Point, Rect};
+
+let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+
+// fully contained
+let inner = Rect::new(2.0, 2.0, 4.0, 4.0);
+assert_eq!(a.intersection(inner), Some(inner));
+
+// overlapping
+assert_eq!(a.intersection(Rect::new(5.0, 5.0, 10.0, 10.0)), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+
+// touching edges and disjoint
+assert!(!a.intersects(Rect::new(10.0, 0.0, 5.0, 5.0)));
+assert_eq!(a.intersection(Rect::new(10.0, 0.0, 5.0, 5.0)), None);
+assert_eq!(a.intersection(Rect::new(20.0, 20.0, 5.0, 5.0)), None);
+
+assert!(a.contains(Point::new(0.0, 0.0)));
+assert!(!a.contains(Point::new(10.0, 5.0)));
+```
+*/
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Rect {
+    /// The x coordinate of the left edge
+    pub x: f32,
+    /// The y coordinate of the top edge
+    pub y: f32,
+    /// The width of the rectangle
+    pub w: f32,
+    /// The height of the rectangle
+    pub h: f32,
+}
+
+impl Rect {
+    /// Returns a new rectangle with the passed top-left corner and size
+    pub fn new(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect { x, y, w, h }
+    }
+
+    /// Returns a new rectangle with the passed top-left corner and size
+    pub fn from_point_size(point: Point, size: Size) -> Rect {
+        Rect::new(point.x, point.y, size.w, size.h)
+    }
+
+    /// Returns the top-left corner of the rectangle
+    pub fn position(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// Returns the size of the rectangle
+    pub fn size(&self) -> Size {
+        Size::new(self.w, self.h)
+    }
+
+    /// Returns the x coordinate of the right edge
+    pub fn right(&self) -> f32 {
+        self.x + self.w
+    }
+
+    /// Returns the y coordinate of the bottom edge
+    pub fn bottom(&self) -> f32 {
+        self.y + self.h
+    }
+
+    /// Returns whether or not the point lies within the rectangle, see [Rect] for how edges are treated
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.x && point.x < self.right() && point.y >= self.y && point.y < self.bottom()
+    }
+
+    /// Returns whether or not the rectangles overlap, rectangles that merely touch don't
+    pub fn intersects(&self, other: Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the area covered by both rectangles, `None` if they don't overlap
+    pub fn intersection(&self, other: Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if right <= x || bottom <= y {
+            return None;
+        }
+
+        Some(Rect::new(x, y, right - x, bottom - y))
+    }
+}
+
+/**
+A vertex as produced by graphene's mesh generators, consisting of a position and a texture coordinate.
+
+The layout is `#[repr(C)]` so the vertices can be uploaded to the GPU as they are.
+*/
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Vertex {
+    /// The position of the vertex
+    pub position: [f32; 2],
+    /// The texture coordinate of the vertex
+    pub uv: [f32; 2],
+}
+
+// the vertex consists of nothing but f32s, so it has no padding and every bit pattern is valid
+unsafe impl bytemuck::Zeroable for Vertex {}
+unsafe impl bytemuck::Pod for Vertex {}
+
+/**
+Generates a quad spanning the unit square from (0, 0) to (1, 1) as two triangles.
+
+The texture coordinates equal the positions, so with a top-left origin the first row of an uploaded image ends up at the top.
+
+# Example
+```
+use alloy_graphene::geometry::quad;
+
+let (vertices, indices) = quad();
+assert_eq!(vertices.len(), 4);
+assert_eq!(indices.len(), 6);
+```
+*/
+pub fn quad() -> (Vec<Vertex>, Vec<u16>) {
+    let vertices = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+        .into_iter()
+        .map(|p| Vertex { position: p, uv: p })
+        .collect();
+
+    (vertices, vec![0, 1, 2, 2, 3, 0])
+}
+
+/**
+Generates a circle inscribed in the unit square from (0, 0) to (1, 1) as a fan of triangles around its center.
+
+The first vertex is the center, followed by one vertex per segment on the circle's edge.
+The texture coordinates equal the positions.
+
+# Panics
+Panics if less than 3 segments are requested or if the vertices couldn't be indexed by a u16.
+
+# Example
+```
+use alloy_graphene::geometry::circle;
+
+let (vertices, indices) = circle(32);
+assert_eq!(vertices.len(), 33);
+assert_eq!(indices.len(), 32 * 3);
+```
+*/
+pub fn circle(segments: u16) -> (Vec<Vertex>, Vec<u16>) {
+    assert!(segments >= 3, "a circle requires at least 3 segments, got {}", segments);
+    assert!(segments < u16::MAX, "a circle can have at most {} segments", u16::MAX - 1);
+
+    let center = Vertex {
+        position: [0.5, 0.5],
+        uv: [0.5, 0.5],
+    };
+
+    let edge = (0..segments).map(|i| {
+        let angle = std::f32::consts::TAU * i as f32 / segments as f32;
+        let p = [0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()];
+        Vertex { position: p, uv: p }
+    });
+
+    let vertices = std::iter::once(center).chain(edge).collect();
+    let indices = (0..segments).flat_map(|i| [0, i + 1, (i + 1) % segments + 1]).collect();
+
+    (vertices, indices)
+}
+
+/**
+A 3x3 matrix stored in column-major order, the layout GLSL expects for `mat3` uniforms without transposing.
+
+This is deliberately minimal, use a dedicated math crate for anything beyond building 2D transforms and convert via
+[to_cols_array](Mat3::to_cols_array).
+
+# Example
+```
+use alloy_graphene::geometry::Mat3;
+
+let m = Mat3::translation(2.0, 3.0) * Mat3::scale(4.0, 5.0);
+assert_eq!(m.transform_point(1.0, 1.0), (6.0, 8.0));
+```
+*/
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Mat3 {
+    /// The columns of the matrix
+    pub cols: [[f32; 3]; 3],
+}
+
+impl Mat3 {
+    /// The identity matrix
+    pub const IDENTITY: Mat3 = Mat3 {
+        cols: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    /// Returns a matrix translating by the passed offsets
+    pub fn translation(x: f32, y: f32) -> Mat3 {
+        Mat3 {
+            cols: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [x, y, 1.0]],
+        }
+    }
+
+    /// Returns a matrix scaling by the passed factors
+    pub fn scale(x: f32, y: f32) -> Mat3 {
+        Mat3 {
+            cols: [[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Returns a matrix rotating counter-clockwise by the passed angle in radians
+    pub fn rotation(angle: f32) -> Mat3 {
+        let (sin, cos) = angle.sin_cos();
+
+        Mat3 {
+            cols: [[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Returns the matrix' components in column-major order
+    pub fn to_cols_array(&self) -> [f32; 9] {
+        let [a, b, c] = self.cols;
+
+        [a[0], a[1], a[2], b[0], b[1], b[2], c[0], c[1], c[2]]
+    }
+
+    /// Transforms the passed point, including the translation
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let [a, b, c] = self.cols;
+
+        (a[0] * x + b[0] * y + c[0], a[1] * x + b[1] * y + c[1])
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Mat3 {
+        Mat3::IDENTITY
+    }
+}
+
+impl std::ops::Mul for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        let mut cols = [[0.0; 3]; 3];
+
+        for (col, rhs_col) in cols.iter_mut().zip(rhs.cols) {
+            for (row, value) in col.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.cols[k][row] * rhs_col[k]).sum();
+            }
+        }
+
+        Mat3 { cols }
+    }
+}
+
+/**
+A 4x4 matrix stored in column-major order, the layout GLSL expects for `mat4` uniforms without transposing.
+
+Like [Mat3] this only covers what 2D rendering needs, most notably an orthographic projection.
+
+# Example
+```
+use alloy_graphene::geometry::{Mat4, Rect};
+
+// maps pixel coordinates with a top-left origin to normalised device coordinates
+let projection = Mat4::orthographic(Rect::new(0.0, 0.0, 800.0, 600.0));
+let cols = projection.to_cols_array();
+assert_eq!((cols[0], cols[5], cols[12], cols[13]), (2.0 / 800.0, -2.0 / 600.0, -1.0, 1.0));
+```
+*/
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Mat4 {
+    /// The columns of the matrix
+    pub cols: [[f32; 4]; 4],
+}
+
+impl Mat4 {
+    /// The identity matrix
+    pub const IDENTITY: Mat4 = Mat4 {
+        cols: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+
+    /// Returns an orthographic projection mapping the passed rectangle to normalised device coordinates,
+    /// its top edge ending up at the top of the viewport. Depth is left untouched
+    pub fn orthographic(bounds: Rect) -> Mat4 {
+        let sx = 2.0 / bounds.w;
+        let sy = -2.0 / bounds.h;
+
+        Mat4 {
+            cols: [
+                [sx, 0.0, 0.0, 0.0],
+                [0.0, sy, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [-1.0 - bounds.x * sx, 1.0 - bounds.y * sy, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Returns the matrix' components in column-major order
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let mut array = [0.0; 16];
+
+        for (chunk, col) in array.chunks_exact_mut(4).zip(self.cols) {
+            chunk.copy_from_slice(&col);
+        }
+
+        array
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Mat4 {
+        Mat4::IDENTITY
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    /// Embeds a 2D transform, mapping its translation onto the x and y axes
+    fn from(m: Mat3) -> Mat4 {
+        let [a, b, c] = m.cols;
+
+        Mat4 {
+            cols: [
+                [a[0], a[1], 0.0, a[2]],
+                [b[0], b[1], 0.0, b[2]],
+                [0.0, 0.0, 1.0, 0.0],
+                [c[0], c[1], 0.0, c[2]],
+            ],
+        }
+    }
+}
@@ -37,6 +37,14 @@ It currently supports only OpenGL. However other graphic libraries will be added
 #![cfg_attr(feature = "clippy", warn(unseparated_literal_suffix))]
 #![cfg_attr(feature = "clippy", warn(wrong_pub_self_convention))]
 
+/// Module for an API-agnostic color type
+pub mod color;
+/// Module for an error type unifying all of graphene's errors
+pub mod error;
+/// Module for API-agnostic 2D geometry primitives
+pub mod geometry;
+/// Module for conversions between 2D coordinate spaces
+pub mod math;
 /// Module for idiomatic OpenGL bindings
 #[cfg(feature = "opengl")]
 pub mod opengl;
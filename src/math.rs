@@ -0,0 +1,56 @@
+/*!
+The math module provides the conversions between the coordinate spaces used in 2D applications.
+
+Pixel coordinates are considered to have their origin in the top-left corner with the y axis pointing down,
+as is common for windowing systems and UI layouts. Normalised device coordinates (NDC) range from -1 to 1 on both axes
+with their origin in the center and the y axis pointing up.
+*/
+
+use crate::geometry::Rect;
+
+/**
+Converts a point in pixel coordinates into normalised device coordinates relative to the passed viewport.
+
+Points outside of the viewport are mapped linearly as well, resulting in coordinates outside of the -1 to 1 range.
+
+# Example
+```
+use alloy_graphene::geometry::Rect;
+use alloy_graphene::math::viewport_to_ndc;
+
+let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+assert_eq!(viewport_to_ndc((400.0, 300.0), viewport), (0.0, 0.0));
+assert_eq!(viewport_to_ndc((0.0, 0.0), viewport), (-1.0, 1.0));
+assert_eq!(viewport_to_ndc((800.0, 600.0), viewport), (1.0, -1.0));
+assert_eq!(viewport_to_ndc((1200.0, -300.0), viewport), (2.0, 2.0));
+```
+*/
+pub fn viewport_to_ndc(point: (f32, f32), viewport: Rect) -> (f32, f32) {
+    let x = (point.0 - viewport.x) / viewport.w * 2.0 - 1.0;
+    let y = 1.0 - (point.1 - viewport.y) / viewport.h * 2.0;
+
+    (x, y)
+}
+
+/**
+Converts a point in normalised device coordinates into pixel coordinates relative to the passed viewport.
+This is the inverse of [viewport_to_ndc](viewport_to_ndc).
+
+# Example
+```
+use alloy_graphene::geometry::Rect;
+use alloy_graphene::math::ndc_to_viewport;
+
+let viewport = Rect::new(100.0, 50.0, 800.0, 600.0);
+assert_eq!(ndc_to_viewport((0.0, 0.0), viewport), (500.0, 350.0));
+assert_eq!(ndc_to_viewport((-1.0, 1.0), viewport), (100.0, 50.0));
+assert_eq!(ndc_to_viewport((1.0, -1.0), viewport), (900.0, 650.0));
+assert_eq!(ndc_to_viewport((-2.0, -2.0), viewport), (-300.0, 950.0));
+```
+*/
+pub fn ndc_to_viewport(ndc: (f32, f32), viewport: Rect) -> (f32, f32) {
+    let x = viewport.x + (ndc.0 + 1.0) / 2.0 * viewport.w;
+    let y = viewport.y + (1.0 - ndc.1) / 2.0 * viewport.h;
+
+    (x, y)
+}
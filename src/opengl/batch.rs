@@ -0,0 +1,675 @@
+/*!
+The opengl batch module provides an immediate-mode batcher for textured 2D quads.
+
+Issuing a draw call per sprite quickly becomes the bottleneck when drawing thousands of them. A [SpriteBatch](SpriteBatch)
+accumulates the quads instead and draws all quads sharing a texture in a single call.
+*/
+
+use super::buffer::{Buffer, BufferCreationError, BufferDataError, BufferTarget, BufferUsage};
+use super::font::{BitmapFont, FontMetrics};
+use super::program::{LinkedProgram, ProgramCreationError, ProgramLinkError, ShaderProgram, UniformError, UniformLocation, UniformVariant};
+use super::shader::{Shader, ShaderCompileError, ShaderCreationError, ShaderType};
+use super::state::get_viewport;
+use super::texture::Texture2D;
+use super::vertex_array::{AttribType, VertexArray, VertexArrayCreationError, VertexAttribError};
+use crate::color::Color;
+use crate::geometry::{Point, Rect};
+
+const VERTEX_SRC: &str = "#version 330 core
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in vec2 a_uv;
+layout(location = 2) in vec4 a_color;
+
+uniform vec2 u_viewport;
+
+out vec2 v_uv;
+out vec4 v_color;
+
+void main() {
+    vec2 ndc = a_position / u_viewport * 2.0 - 1.0;
+    gl_Position = vec4(ndc.x, -ndc.y, 0.0, 1.0);
+    v_uv = a_uv;
+    v_color = a_color;
+}
+";
+
+const FRAGMENT_SRC: &str = "#version 330 core
+uniform sampler2D u_texture;
+
+in vec2 v_uv;
+in vec4 v_color;
+
+out vec4 color;
+
+void main() {
+    color = texture(u_texture, v_uv) * v_color;
+}
+";
+
+/// The GLSL source of [ROUNDED_RECT_SDF], a macro so it can be spliced into the built-in rounded rectangle shader
+macro_rules! rounded_rect_sdf {
+    () => {
+        "float rounded_rect_sdf(vec2 p, vec2 half_size, float radius) {
+    vec2 q = abs(p) - half_size + radius;
+    return length(max(q, 0.0)) + min(max(q.x, q.y), 0.0) - radius;
+}
+"
+    };
+}
+
+/**
+A GLSL function returning the signed distance of the point `p` to the edge of a rectangle with rounded corners, which
+is negative inside and positive outside of it.
+
+`p` is relative to the rectangle's center, `half_size` is half its width and height and `radius` the corner radius.
+Paste it into custom fragment shaders to draw UI elements with rounded corners, see
+[draw_rounded_rect](SpriteBatch::draw_rounded_rect) for a ready made one.
+*/
+pub const ROUNDED_RECT_SDF: &str = rounded_rect_sdf!();
+
+const ROUNDED_RECT_FRAGMENT_SRC: &str = concat!(
+    "#version 330 core
+uniform vec2 u_rect_size;
+uniform float u_corner_radius;
+
+in vec2 v_uv;
+in vec4 v_color;
+
+out vec4 color;
+
+",
+    rounded_rect_sdf!(),
+    "
+void main() {
+    float distance = rounded_rect_sdf((v_uv - 0.5) * u_rect_size, u_rect_size * 0.5, u_corner_radius);
+    // fades out over a pixel to smooth the edge
+    color = vec4(v_color.rgb, v_color.a * clamp(0.5 - distance, 0.0, 1.0));
+}
+"
+);
+
+/// The uniforms of the built-in rounded rectangle shader, see [SpriteBatch::draw_rounded_rect]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct RoundedRectUniforms {
+    /// The width and height of the rectangle in pixels
+    pub rect_size: [f32; 2],
+    /// The corner radius in pixels, at most half of the shorter side
+    pub corner_radius: f32,
+}
+
+impl RoundedRectUniforms {
+    /// Returns the uniforms for the passed rectangle, clamping the radius between 0 and half of its shorter side
+    pub fn new(rect: Rect, corner_radius: f32) -> RoundedRectUniforms {
+        let max_radius = (rect.w.min(rect.h) * 0.5).max(0.0);
+
+        RoundedRectUniforms {
+            rect_size: [rect.w, rect.h],
+            corner_radius: corner_radius.clamp(0.0, max_radius),
+        }
+    }
+
+    /// Sets the `u_rect_size` and `u_corner_radius` uniforms of the passed program, which has to be bound
+    pub fn apply(&self, program: &LinkedProgram) -> Result<(), UniformError> {
+        program.set_uniforms(&[
+            ("u_rect_size", UniformVariant::Vec2(self.rect_size)),
+            ("u_corner_radius", UniformVariant::F32(self.corner_radius)),
+        ])
+    }
+}
+
+/// A single corner of a batched quad as it is uploaded to the GPU
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+struct SpriteVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+// sound for the same reason as the impls of geometry::Vertex
+unsafe impl bytemuck::Zeroable for SpriteVertex {}
+unsafe impl bytemuck::Pod for SpriteVertex {}
+
+/// Error enum for the failed creation or flushing of a sprite batch
+#[derive(thiserror::Error, Debug)]
+pub enum SpriteBatchError {
+    /// Used if one of the default shaders couldn't be created
+    #[error("Unable to create the sprite shader: {source}")]
+    ShaderCreation {
+        #[from]
+        /// The underlying creation error
+        source: ShaderCreationError,
+    },
+    /// Used if one of the default shaders couldn't be compiled
+    #[error("Unable to compile the sprite shader: {source}")]
+    ShaderCompile {
+        #[from]
+        /// The underlying compilation error
+        source: ShaderCompileError,
+    },
+    /// Used if the default program couldn't be created
+    #[error("Unable to create the sprite program: {source}")]
+    ProgramCreation {
+        #[from]
+        /// The underlying creation error
+        source: ProgramCreationError,
+    },
+    /// Used if the default program couldn't be linked
+    #[error("Unable to link the sprite program: {source}")]
+    ProgramLink {
+        #[from]
+        /// The underlying link error
+        source: ProgramLinkError,
+    },
+    /// Used if one of the default program's uniforms couldn't be found or set
+    #[error("Unable to set a uniform of the sprite program: {source}")]
+    Uniform {
+        #[from]
+        /// The underlying uniform error
+        source: UniformError,
+    },
+    /// Used if the vertex array couldn't be created
+    #[error("Unable to create the vertex array: {source}")]
+    VertexArrayCreation {
+        #[from]
+        /// The underlying creation error
+        source: VertexArrayCreationError,
+    },
+    /// Used if one of the buffers couldn't be created
+    #[error("Unable to create a buffer: {source}")]
+    BufferCreation {
+        #[from]
+        /// The underlying creation error
+        source: BufferCreationError,
+    },
+    /// Used if the quads couldn't be uploaded
+    #[error("Unable to upload the batched quads: {source}")]
+    BufferData {
+        #[from]
+        /// The underlying upload error
+        source: BufferDataError,
+    },
+    /// Used if the vertex layout couldn't be described to the vertex array
+    #[error("Unable to describe a vertex attribute: {source}")]
+    VertexAttrib {
+        #[from]
+        /// The underlying description error
+        source: VertexAttribError,
+    },
+    /// Used if quads were batched before a texture was set via [set_texture](SpriteBatch::set_texture)
+    #[error("Quads were batched before a texture was set")]
+    NoTexture,
+    /// Used if more quads were batched than can be drawn in a single call
+    #[error("Too many quads were batched to be drawn in a single call")]
+    TooManyQuads,
+}
+
+/**
+Accumulates textured quads and draws them in as few draw calls as possible using a built-in sprite program.
+
+Quads are specified in pixels relative to the top-left corner of the current viewport. A new draw call is only started
+when the texture changes, so sort sprites by texture where possible.
+The texture set via [set_texture](SpriteBatch::set_texture) has to stay alive until the batch was flushed.
+
+# Example
+```ignore
+let mut batch = SpriteBatch::new().expect("Unable to create sprite batch");
+
+batch.begin();
+batch.set_texture(&atlas).expect("Unable to switch texture");
+for sprite in &sprites {
+    batch.draw_quad(sprite.dest, sprite.uv, Color::WHITE);
+}
+batch.end().expect("Unable to draw sprites");
+```
+*/
+#[derive(Debug)]
+pub struct SpriteBatch {
+    renderer: SpriteRenderer,
+    queue: SpriteQueue,
+}
+
+/// The OpenGL objects a sprite batch draws with
+#[derive(Debug)]
+struct SpriteRenderer {
+    program: LinkedProgram,
+    viewport_location: UniformLocation,
+    vertex_array: VertexArray,
+    vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    // the number of quads the index buffer currently holds indices for
+    index_capacity: usize,
+    // only compiled once the first rounded rectangle is drawn
+    rounded_rect: Option<RoundedRectProgram>,
+}
+
+/// The built-in program drawing rounded rectangles, see [SpriteBatch::draw_rounded_rect]
+#[derive(Debug)]
+struct RoundedRectProgram {
+    program: LinkedProgram,
+    viewport_location: UniformLocation,
+}
+
+impl RoundedRectProgram {
+    fn new() -> Result<RoundedRectProgram, SpriteBatchError> {
+        let vertex = Shader::new(ShaderType::Vertex)?.compile(VERTEX_SRC)?;
+        let fragment = Shader::new(ShaderType::FragmentShader)?.compile(ROUNDED_RECT_FRAGMENT_SRC)?;
+        let program = ShaderProgram::new()?.link([vertex, fragment])?;
+        let viewport_location = program.uniform_location("u_viewport")?;
+
+        Ok(RoundedRectProgram {
+            program,
+            viewport_location,
+        })
+    }
+}
+
+/// The quads of a sprite batch waiting to be drawn and the bookkeeping of when to draw them, independent of OpenGL
+#[derive(Debug, Default)]
+struct SpriteQueue {
+    vertices: Vec<SpriteVertex>,
+    texture: Option<gl::types::GLuint>,
+    draw_calls: usize,
+}
+
+/// Returns the corners of a quad covering `dest` that samples the `uv` region
+fn quad_vertices(dest: Rect, uv: Rect, color: Color) -> [SpriteVertex; 4] {
+    let color = color.to_array();
+
+    // same corner order as geometry::quad: top-left, bottom-left, bottom-right, top-right
+    [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)].map(|(x, y)| SpriteVertex {
+        position: [dest.x + x * dest.w, dest.y + y * dest.h],
+        uv: [uv.x + x * uv.w, uv.y + y * uv.h],
+        color,
+    })
+}
+
+impl SpriteQueue {
+    fn begin(&mut self) {
+        self.vertices.clear();
+        self.texture = None;
+        self.draw_calls = 0;
+    }
+
+    /// Switches to the passed texture, handing the quads batched so far to `draw` first if it differs from the current one
+    fn set_texture<F>(&mut self, texture: gl::types::GLuint, draw: F) -> Result<(), SpriteBatchError>
+    where
+        F: FnOnce(gl::types::GLuint, &[SpriteVertex]) -> Result<(), SpriteBatchError>,
+    {
+        if self.texture != Some(texture) {
+            self.flush(draw)?;
+            self.texture = Some(texture);
+        }
+
+        Ok(())
+    }
+
+    fn push_quad(&mut self, dest: Rect, uv: Rect, color: Color) {
+        self.vertices.extend(quad_vertices(dest, uv, color));
+    }
+
+    /// Adds a quad per visible glyph of the text, see [FontMetrics::layout]
+    fn push_text(&mut self, metrics: &FontMetrics, text: &str, position: Point, color: Color) {
+        for glyph in metrics.layout(text, position) {
+            self.push_quad(glyph.dest, glyph.uv, color);
+        }
+    }
+
+    /// Hands the quads batched since the last flush to `draw` and counts the draw call, does nothing if there are none
+    fn flush<F>(&mut self, draw: F) -> Result<(), SpriteBatchError>
+    where
+        F: FnOnce(gl::types::GLuint, &[SpriteVertex]) -> Result<(), SpriteBatchError>,
+    {
+        if self.vertices.is_empty() {
+            return Ok(());
+        }
+
+        let texture = self.texture.ok_or(SpriteBatchError::NoTexture)?;
+        draw(texture, &self.vertices)?;
+
+        self.vertices.clear();
+        self.draw_calls += 1;
+
+        Ok(())
+    }
+}
+
+impl SpriteBatch {
+    /// Creates the batch's sprite program, vertex array and buffers
+    pub fn new() -> Result<SpriteBatch, SpriteBatchError> {
+        let vertex = Shader::new(ShaderType::Vertex)?.compile(VERTEX_SRC)?;
+        let fragment = Shader::new(ShaderType::FragmentShader)?.compile(FRAGMENT_SRC)?;
+        let program = ShaderProgram::new()?.link([vertex, fragment])?;
+
+        let viewport_location = program.uniform_location("u_viewport")?;
+        {
+            let binding = program.bind();
+            let texture_location = binding.uniform_location("u_texture")?;
+            binding.set_uniform_i32(texture_location, 0)?;
+        }
+
+        let vertex_array = VertexArray::new()?;
+        let vertex_buffer = Buffer::new(BufferTarget::Array)?;
+        let index_buffer = Buffer::new(BufferTarget::ElementArray)?;
+
+        vertex_array.bind();
+        vertex_buffer.bind();
+        // the element array binding is part of the vertex array's state
+        index_buffer.bind();
+
+        let stride = size_of::<SpriteVertex>() as i32;
+        vertex_array.vertex_attrib_pointer(0, 2, AttribType::Float, false, stride, 0)?;
+        vertex_array.vertex_attrib_pointer(1, 2, AttribType::Float, false, stride, 8)?;
+        vertex_array.vertex_attrib_pointer(2, 4, AttribType::Float, false, stride, 16)?;
+        vertex_array.unbind();
+
+        Ok(SpriteBatch {
+            renderer: SpriteRenderer {
+                program,
+                viewport_location,
+                vertex_array,
+                vertex_buffer,
+                index_buffer,
+                index_capacity: 0,
+                rounded_rect: None,
+            },
+            queue: SpriteQueue::default(),
+        })
+    }
+
+    /// Starts a new batch, discarding any quads that weren't flushed and resetting the [draw call count](SpriteBatch::draw_calls)
+    pub fn begin(&mut self) {
+        self.queue.begin();
+    }
+
+    /// Sets the texture the following quads are sampled from, flushing the quads batched so far if it differs from the current one
+    pub fn set_texture(&mut self, texture: &Texture2D) -> Result<(), SpriteBatchError> {
+        let renderer = &mut self.renderer;
+        self.queue
+            .set_texture(texture.get_id(), |texture, vertices| renderer.draw(texture, vertices))
+    }
+
+    /// Adds a quad covering `dest` in pixels that samples the `uv` region of the current texture, tinted by `color`
+    pub fn draw_quad(&mut self, dest: Rect, uv: Rect, color: Color) {
+        self.queue.push_quad(dest, uv, color);
+    }
+
+    /**
+    Switches to the font's atlas and adds a quad per visible glyph of the text, tinted by `color`.
+    `position` is the top-left corner of the text's first line in pixels, see [FontMetrics::layout] for the details.
+
+    # Example
+    ```ignore
+    batch.begin();
+    batch.draw_text(&font, "Score: 42", Point::new(8.0, 8.0), Color::WHITE).expect("Unable to draw score");
+    batch.end().expect("Unable to draw text");
+    ```
+    */
+    pub fn draw_text(&mut self, font: &BitmapFont, text: &str, position: Point, color: Color) -> Result<(), SpriteBatchError> {
+        self.set_texture(font.atlas())?;
+        self.queue.push_text(font.metrics(), text, position, color);
+
+        Ok(())
+    }
+
+    /**
+    Draws a rectangle with rounded corners filled with `color`, smoothing its edges over a pixel.
+
+    The rectangle is drawn with a built-in shader using [ROUNDED_RECT_SDF] that is compiled on first use. Since its size
+    and radius are passed as uniforms, see [RoundedRectUniforms], the quads batched so far are flushed first and the
+    rectangle takes a draw call of its own.
+
+    # Example
+    ```ignore
+    batch.begin();
+    batch.draw_rounded_rect(Rect::new(16.0, 16.0, 200.0, 48.0), 8.0, Color::from_hex(0x3366ccff)).expect("Unable to draw button");
+    batch.end().expect("Unable to draw UI");
+    ```
+    */
+    pub fn draw_rounded_rect(&mut self, rect: Rect, corner_radius: f32, color: Color) -> Result<(), SpriteBatchError> {
+        self.flush()?;
+
+        let vertices = quad_vertices(rect, Rect::new(0.0, 0.0, 1.0, 1.0), color);
+        self.renderer
+            .draw_rounded_rect(RoundedRectUniforms::new(rect, corner_radius), &vertices)?;
+        self.queue.draw_calls += 1;
+
+        Ok(())
+    }
+
+    /**
+    Draws all quads batched since the last flush in a single draw call, does nothing if there are none.
+
+    Fails with [NoTexture](SpriteBatchError::NoTexture) if quads were batched before [set_texture](SpriteBatch::set_texture)
+    was called, rather than silently sampling texture 0.
+    */
+    pub fn flush(&mut self) -> Result<(), SpriteBatchError> {
+        let renderer = &mut self.renderer;
+        self.queue.flush(|texture, vertices| renderer.draw(texture, vertices))
+    }
+
+    /// Flushes the remaining quads, ending the batch
+    pub fn end(&mut self) -> Result<(), SpriteBatchError> {
+        self.flush()
+    }
+
+    /// Returns the number of draw calls issued since the last [begin](SpriteBatch::begin)
+    pub fn draw_calls(&self) -> usize {
+        self.queue.draw_calls
+    }
+}
+
+impl SpriteRenderer {
+    /// Draws the passed quads sampling the passed texture in a single draw call
+    fn draw(&mut self, texture: gl::types::GLuint, vertices: &[SpriteVertex]) -> Result<(), SpriteBatchError> {
+        let index_count = self.upload(vertices)?;
+
+        let (_, _, width, height) = get_viewport();
+        let binding = self.program.bind();
+        binding.set_uniform_vec2(self.viewport_location, [width as f32, height as f32])?;
+
+        unsafe {
+            gl_call!(gl::ActiveTexture(gl::TEXTURE0));
+            gl_call!(gl::BindTexture(gl::TEXTURE_2D, texture));
+            gl_call!(gl::DrawElements(gl::TRIANGLES, index_count, gl::UNSIGNED_INT, std::ptr::null()));
+        }
+
+        drop(binding);
+        self.vertex_array.unbind();
+
+        Ok(())
+    }
+
+    /// Draws a single rounded rectangle using the built-in rounded rectangle program, compiling it first if necessary
+    fn draw_rounded_rect(&mut self, uniforms: RoundedRectUniforms, vertices: &[SpriteVertex; 4]) -> Result<(), SpriteBatchError> {
+        if self.rounded_rect.is_none() {
+            self.rounded_rect = Some(RoundedRectProgram::new()?);
+        }
+
+        let index_count = self.upload(vertices)?;
+
+        if let Some(rounded_rect) = &self.rounded_rect {
+            let (_, _, width, height) = get_viewport();
+            let binding = rounded_rect.program.bind();
+            binding.set_uniform_vec2(rounded_rect.viewport_location, [width as f32, height as f32])?;
+            uniforms.apply(&binding)?;
+
+            unsafe { gl_call!(gl::DrawElements(gl::TRIANGLES, index_count, gl::UNSIGNED_INT, std::ptr::null())) };
+        }
+
+        self.vertex_array.unbind();
+
+        Ok(())
+    }
+
+    /// Binds the vertex array and uploads the passed quads as well as enough indices for them, returning the index count
+    fn upload(&mut self, vertices: &[SpriteVertex]) -> Result<i32, SpriteBatchError> {
+        let quads = vertices.len() / 4;
+        let index_count = i32::try_from(quads * 6).map_err(|_| SpriteBatchError::TooManyQuads)?;
+
+        // uploading the indices binds the index buffer, which must only affect this vertex array
+        self.vertex_array.bind();
+
+        if quads > self.index_capacity {
+            self.index_capacity = quads.next_power_of_two();
+            let indices = (0..self.index_capacity as u32)
+                .flat_map(|quad| [0, 1, 2, 2, 3, 0].map(|i| quad * 4 + i))
+                .collect::<Vec<u32>>();
+            self.index_buffer.data(&indices, BufferUsage::StaticDraw)?;
+        }
+
+        self.vertex_buffer.data(vertices, BufferUsage::StreamDraw)?;
+
+        Ok(index_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEST: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        w: 16.0,
+        h: 16.0,
+    };
+
+    /// Flushes the queue, recording the texture and quad count of every draw call instead of drawing
+    fn flush(queue: &mut SpriteQueue, draws: &mut Vec<(gl::types::GLuint, usize)>) -> Result<(), SpriteBatchError> {
+        queue.flush(|texture, vertices| {
+            draws.push((texture, vertices.len() / 4));
+            Ok(())
+        })
+    }
+
+    fn set_texture(queue: &mut SpriteQueue, texture: gl::types::GLuint, draws: &mut Vec<(gl::types::GLuint, usize)>) {
+        queue
+            .set_texture(texture, |texture, vertices| {
+                draws.push((texture, vertices.len() / 4));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn quads_sharing_a_texture_take_one_draw_call() {
+        let mut queue = SpriteQueue::default();
+        let mut draws = Vec::new();
+
+        queue.begin();
+        set_texture(&mut queue, 1, &mut draws);
+        queue.push_quad(DEST, DEST, Color::WHITE);
+        set_texture(&mut queue, 1, &mut draws);
+        queue.push_quad(DEST, DEST, Color::WHITE);
+        flush(&mut queue, &mut draws).unwrap();
+
+        assert_eq!(draws, [(1, 2)]);
+        assert_eq!(queue.draw_calls, 1);
+    }
+
+    #[test]
+    fn switching_textures_flushes() {
+        let mut queue = SpriteQueue::default();
+        let mut draws = Vec::new();
+
+        queue.begin();
+        set_texture(&mut queue, 1, &mut draws);
+        queue.push_quad(DEST, DEST, Color::WHITE);
+        set_texture(&mut queue, 2, &mut draws);
+        queue.push_quad(DEST, DEST, Color::WHITE);
+        flush(&mut queue, &mut draws).unwrap();
+        // flushing an empty queue doesn't draw
+        flush(&mut queue, &mut draws).unwrap();
+
+        assert_eq!(draws, [(1, 1), (2, 1)]);
+        assert_eq!(queue.draw_calls, 2);
+    }
+
+    #[test]
+    fn quads_without_a_texture_fail_to_flush() {
+        let mut queue = SpriteQueue::default();
+        let mut draws = Vec::new();
+
+        queue.begin();
+        queue.push_quad(DEST, DEST, Color::WHITE);
+
+        assert!(matches!(flush(&mut queue, &mut draws), Err(SpriteBatchError::NoTexture)));
+        assert!(draws.is_empty());
+    }
+
+    #[test]
+    fn text_takes_a_quad_per_glyph() {
+        let metrics = FontMetrics::parse_fnt(
+            "common lineHeight=16 scaleW=64 scaleH=64
+char id=65 x=0 y=0 width=8 height=16 xoffset=0 yoffset=0 xadvance=8
+char id=66 x=32 y=16 width=16 height=16 xoffset=0 yoffset=0 xadvance=16",
+        )
+        .unwrap();
+        let mut queue = SpriteQueue::default();
+
+        queue.begin();
+        queue.push_text(&metrics, "AB", Point::new(0.0, 0.0), Color::WHITE);
+
+        assert_eq!(queue.vertices.len(), 8);
+        let uvs = queue.vertices.iter().map(|vertex| vertex.uv).collect::<Vec<_>>();
+        assert_eq!(
+            uvs,
+            [
+                [0.0, 0.0],
+                [0.0, 0.25],
+                [0.125, 0.25],
+                [0.125, 0.0],
+                [0.5, 0.25],
+                [0.5, 0.5],
+                [0.75, 0.5],
+                [0.75, 0.25],
+            ]
+        );
+        // the second glyph starts where the first one advanced the pen to
+        assert_eq!(queue.vertices[4].position, [8.0, 0.0]);
+    }
+
+    /// Mirrors ROUNDED_RECT_SDF and the coverage computed by the rounded rectangle shader
+    fn rounded_rect_coverage(uniforms: RoundedRectUniforms, uv: [f32; 2]) -> f32 {
+        let [w, h] = uniforms.rect_size;
+        let radius = uniforms.corner_radius;
+        let q = [
+            ((uv[0] - 0.5) * w).abs() - w * 0.5 + radius,
+            ((uv[1] - 0.5) * h).abs() - h * 0.5 + radius,
+        ];
+        let outside = (q[0].max(0.0).powi(2) + q[1].max(0.0).powi(2)).sqrt();
+        let distance = outside + q[0].max(q[1]).min(0.0) - radius;
+
+        (0.5 - distance).clamp(0.0, 1.0)
+    }
+
+    #[test]
+    fn rounded_rects_leave_the_corners_transparent() {
+        let uniforms = RoundedRectUniforms::new(Rect::new(0.0, 0.0, 64.0, 32.0), 8.0);
+        let vertices = quad_vertices(Rect::new(0.0, 0.0, 64.0, 32.0), Rect::new(0.0, 0.0, 1.0, 1.0), Color::WHITE);
+
+        // the shader only sees the interpolated uvs, the corners of the quad are its corner pixels
+        for vertex in &vertices {
+            assert_eq!(rounded_rect_coverage(uniforms, vertex.uv), 0.0);
+        }
+        assert_eq!(rounded_rect_coverage(uniforms, [0.5, 0.5]), 1.0);
+        // the edges between the corners are covered halfway
+        assert_eq!(rounded_rect_coverage(uniforms, [0.5, 0.0]), 0.5);
+    }
+
+    #[test]
+    fn corner_radii_are_clamped_to_half_the_shorter_side() {
+        let rect = Rect::new(0.0, 0.0, 64.0, 32.0);
+
+        assert_eq!(RoundedRectUniforms::new(rect, 100.0).corner_radius, 16.0);
+        assert_eq!(RoundedRectUniforms::new(rect, -1.0).corner_radius, 0.0);
+        assert_eq!(RoundedRectUniforms::new(rect, 4.0).rect_size, [64.0, 32.0]);
+    }
+
+    #[test]
+    fn the_sdf_is_part_of_the_rounded_rect_shader() {
+        assert!(ROUNDED_RECT_FRAGMENT_SRC.contains(ROUNDED_RECT_SDF));
+        assert!(ROUNDED_RECT_FRAGMENT_SRC.starts_with("#version 330 core"));
+    }
+}
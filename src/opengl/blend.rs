@@ -0,0 +1,126 @@
+/*!
+The opengl blend module provides idiomatic bindings to OpenGL's blend state, which determines how the colors of
+rendered fragments are combined with the colors already in the framebuffer.
+*/
+
+gl_enum! {
+    /// The factors the source and destination colors are multiplied with before being combined by the [BlendEquation]
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum BlendFactor {
+        /// `0`
+        Zero = gl::ZERO,
+        /// `1`
+        One = gl::ONE,
+        /// The source color
+        SrcColor = gl::SRC_COLOR,
+        /// One minus the source color
+        OneMinusSrcColor = gl::ONE_MINUS_SRC_COLOR,
+        /// The destination color
+        DstColor = gl::DST_COLOR,
+        /// One minus the destination color
+        OneMinusDstColor = gl::ONE_MINUS_DST_COLOR,
+        /// The source alpha
+        SrcAlpha = gl::SRC_ALPHA,
+        /// One minus the source alpha
+        OneMinusSrcAlpha = gl::ONE_MINUS_SRC_ALPHA,
+        /// The destination alpha
+        DstAlpha = gl::DST_ALPHA,
+        /// One minus the destination alpha
+        OneMinusDstAlpha = gl::ONE_MINUS_DST_ALPHA,
+        /// The constant blend color
+        ConstantColor = gl::CONSTANT_COLOR,
+        /// One minus the constant blend color
+        OneMinusConstantColor = gl::ONE_MINUS_CONSTANT_COLOR,
+        /// The constant blend color's alpha
+        ConstantAlpha = gl::CONSTANT_ALPHA,
+        /// One minus the constant blend color's alpha
+        OneMinusConstantAlpha = gl::ONE_MINUS_CONSTANT_ALPHA,
+        /// The minimum of the source alpha and one minus the destination alpha, only valid as a source factor
+        SrcAlphaSaturate = gl::SRC_ALPHA_SATURATE,
+    }
+}
+
+gl_enum! {
+    /// The equations used to combine the weighted source and destination colors
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum BlendEquation {
+        /// `source + destination`
+        Add = gl::FUNC_ADD,
+        /// `source - destination`
+        Subtract = gl::FUNC_SUBTRACT,
+        /// `destination - source`
+        ReverseSubtract = gl::FUNC_REVERSE_SUBTRACT,
+        /// The component-wise minimum, ignoring the factors
+        Min = gl::MIN,
+        /// The component-wise maximum, ignoring the factors
+        Max = gl::MAX,
+    }
+}
+
+/// Commonly used blend configurations, see [set_blend_preset]
+#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BlendPreset {
+    /// Regular alpha blending of colors that aren't premultiplied, the alpha channel accumulates coverage
+    AlphaOver,
+    /// Alpha blending of colors that were premultiplied with their alpha
+    PremultipliedAlpha,
+    /// Adds the source color weighted by its alpha onto the destination, e.g. for lights and particles
+    Additive,
+}
+
+/// Enables blending of fragments into the framebuffer
+pub fn enable_blend() {
+    unsafe { gl_call!(gl::Enable(gl::BLEND)) };
+}
+
+/// Disables blending, fragments overwrite the framebuffer's colors
+pub fn disable_blend() {
+    unsafe { gl_call!(gl::Disable(gl::BLEND)) };
+}
+
+/// Sets the factors the source and destination colors are weighted with, for all channels alike
+pub fn set_blend_func(src: BlendFactor, dst: BlendFactor) {
+    unsafe { gl_call!(gl::BlendFunc(src.as_glenum(), dst.as_glenum())) };
+}
+
+/// Sets the factors the source and destination colors are weighted with, separately for the color and alpha channels
+pub fn set_blend_func_separate(src_rgb: BlendFactor, dst_rgb: BlendFactor, src_a: BlendFactor, dst_a: BlendFactor) {
+    unsafe {
+        gl_call!(gl::BlendFuncSeparate(
+            src_rgb.as_glenum(),
+            dst_rgb.as_glenum(),
+            src_a.as_glenum(),
+            dst_a.as_glenum()
+        ))
+    };
+}
+
+/// Sets the equation the weighted source and destination colors are combined with
+pub fn set_blend_equation(eq: BlendEquation) {
+    unsafe { gl_call!(gl::BlendEquation(eq.as_glenum())) };
+}
+
+/**
+Enables blending and configures it according to the passed preset, which also resets the equation to [BlendEquation::Add].
+
+# Example
+```ignore
+set_blend_preset(BlendPreset::AlphaOver);
+// draw translucent sprites
+```
+*/
+pub fn set_blend_preset(preset: BlendPreset) {
+    enable_blend();
+    set_blend_equation(BlendEquation::Add);
+
+    match preset {
+        BlendPreset::AlphaOver => set_blend_func_separate(
+            BlendFactor::SrcAlpha,
+            BlendFactor::OneMinusSrcAlpha,
+            BlendFactor::One,
+            BlendFactor::OneMinusSrcAlpha,
+        ),
+        BlendPreset::PremultipliedAlpha => set_blend_func(BlendFactor::One, BlendFactor::OneMinusSrcAlpha),
+        BlendPreset::Additive => set_blend_func(BlendFactor::SrcAlpha, BlendFactor::One),
+    }
+}
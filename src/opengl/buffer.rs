@@ -0,0 +1,450 @@
+/*!
+The opengl buffer module provides idiomatic bindings to OpenGL buffer objects.
+
+Buffers store unformatted data on the GPU, such as vertices, indices or the contents of uniform blocks.
+What the data is used for is determined by the target a buffer is bound to.
+You can read more about buffers here: <https://www.khronos.org/opengl/wiki/Buffer_Object>
+*/
+
+gl_enum! {
+    /// The targets a buffer can be bound to, which determine what its data is used for
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum BufferTarget {
+        /// Used for vertex data, also known as VBO
+        Array = gl::ARRAY_BUFFER,
+        /// Used for indices into the vertex data, also known as EBO/IBO
+        ElementArray = gl::ELEMENT_ARRAY_BUFFER,
+        /// Used for the contents of uniform blocks, also known as UBO
+        Uniform = gl::UNIFORM_BUFFER,
+        /// Used for the contents of shader storage blocks, also known as SSBO
+        ShaderStorage = gl::SHADER_STORAGE_BUFFER,
+        /// Used for the work group counts of indirect compute dispatches, see [dispatch_compute_indirect]
+        DispatchIndirect = gl::DISPATCH_INDIRECT_BUFFER,
+    }
+}
+
+gl_enum! {
+    /**
+    Hints the driver how the data of a buffer is going to be used so it can choose where to store it.
+
+    See <https://www.khronos.org/opengl/wiki/Buffer_Object#Buffer_Object_Usage> for more information.
+    */
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum BufferUsage {
+        /// The data is set once and used many times
+        StaticDraw = gl::STATIC_DRAW,
+        /// The data is modified repeatedly and used many times
+        DynamicDraw = gl::DYNAMIC_DRAW,
+        /// The data is set once and used at most a few times
+        StreamDraw = gl::STREAM_DRAW,
+    }
+}
+
+/// Stores the underlying data of a buffer
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [Buffer](Buffer) struct.
+#[derive(Debug)]
+pub struct BufferInner {
+    /// The id of the buffer, generated by OpenGL and valid for the lifetime of the buffer
+    pub id: gl::types::GLuint,
+    /// The target the buffer is bound to. See [BufferTarget](BufferTarget) for more information
+    pub target: BufferTarget,
+}
+
+/// A copy of the publicly relevant data of a buffer, see [Buffer::snapshot](Buffer::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct BufferSnapshot {
+    /// The id of the buffer, generated by OpenGL. Only valid as long as the buffer it was taken from is alive
+    pub id: gl::types::GLuint,
+    /// The target the buffer is bound to. See [BufferTarget](BufferTarget) for more information
+    pub target: BufferTarget,
+}
+
+impl PartialEq for BufferInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for BufferInner {}
+
+impl std::hash::Hash for BufferInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for buffers to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for BufferInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BufferInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A buffer stores unformatted data on the GPU for the target it was created for.
+
+# Example
+```ignore
+let vertices: [f32; 6] = [-0.5, -0.5, 0.5, -0.5, 0.0, 0.5];
+let buffer = Buffer::new(BufferTarget::Array).expect("Unable to create buffer");
+buffer.data(&vertices, BufferUsage::StaticDraw).expect("Unable to upload vertices");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Buffer {
+    inner: BufferInner,
+}
+
+impl std::fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Buffer")
+            .field("id", &self.inner.id)
+            .field("target", &self.inner.target)
+            .finish()
+    }
+}
+
+impl Buffer {
+    /// Returns a reference to the inner (private) data of the buffer.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &BufferInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the buffer.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut BufferInner {
+        &mut self.inner
+    }
+
+    /// Retrieves the id of the buffer.
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the buffer's id and target, which is the safe alternative to [inner](Buffer::inner) for reading.
+    pub fn snapshot(&self) -> BufferSnapshot {
+        BufferSnapshot {
+            id: self.inner.id,
+            target: self.inner.target,
+        }
+    }
+
+    /// Retrieves the target of the buffer.
+    pub fn get_target(&self) -> BufferTarget {
+        self.inner.target
+    }
+
+    /// Binds the buffer to its target.
+    pub fn bind(&self) {
+        unsafe { gl_call!(gl::BindBuffer(self.inner.target.as_glenum(), self.inner.id)) };
+    }
+}
+
+impl super::GlObject for Buffer {
+    const SHAREABLE: bool = true;
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteBuffers(1, &self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a buffer
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BufferCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Buffer {
+    /// Returns a new buffer for the passed target or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+    pub fn new(target: BufferTarget) -> Result<Buffer, BufferCreationError> {
+        let mut id = 0;
+
+        unsafe { gl_call!(gl::GenBuffers(1, &mut id)) };
+
+        if id == 0 {
+            return Err(BufferCreationError::Unknown);
+        }
+
+        Ok(Buffer {
+            inner: BufferInner { id, target },
+        })
+    }
+}
+
+/// Error enum for the failed upload of a buffer's data
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BufferDataError {
+    /// Used if the data is larger than what OpenGL can address
+    #[error("The data is too large to be uploaded")]
+    TooLarge,
+    /// Used if OpenGL was unable to allocate the memory for the data
+    #[error("OpenGL ran out of memory while allocating the buffer's data store")]
+    OutOfMemory,
+    /// Used if the buffer couldn't be modified, e.g. because its data store is immutable
+    #[error("The buffer's data store couldn't be modified")]
+    InvalidOperation,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Buffer {
+    /// Binds the buffer and replaces its data store with a copy of the passed data.
+    /// The buffer stays bound afterwards.
+    pub fn data<T: bytemuck::Pod>(&self, data: &[T], usage: BufferUsage) -> Result<(), BufferDataError> {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let size = gl::types::GLsizeiptr::try_from(bytes.len()).map_err(|_| BufferDataError::TooLarge)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::BufferData(self.inner.target.as_glenum(), size, bytes.as_ptr() as *const _, usage.as_glenum());
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::OUT_OF_MEMORY => Err(BufferDataError::OutOfMemory),
+            gl::INVALID_OPERATION => Err(BufferDataError::InvalidOperation),
+            _ => Err(BufferDataError::Unknown),
+        }
+    }
+}
+
+/// Error struct for a buffer range whose offset doesn't meet the alignment the driver requires for its target
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[error("The offset {offset} isn't a multiple of the required alignment of {alignment} bytes")]
+pub struct AlignmentError {
+    /// The misaligned offset in bytes
+    pub offset: usize,
+    /// The alignment in bytes the offset has to be a multiple of
+    pub alignment: usize,
+}
+
+/// Checks that the offset is a multiple of the alignment, drivers that don't report an alignment accept every offset
+fn check_alignment(offset: usize, alignment: usize) -> Result<(), AlignmentError> {
+    if !offset.is_multiple_of(alignment.max(1)) {
+        return Err(AlignmentError { offset, alignment });
+    }
+
+    Ok(())
+}
+
+/// Error enum for the failed binding of a buffer range to an indexed binding point
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum BindRangeError {
+    /// Used if the target has no indexed binding points, i.e. if it's neither [Uniform](BufferTarget::Uniform) nor
+    /// [ShaderStorage](BufferTarget::ShaderStorage)
+    #[error("{0} buffers have no indexed binding points")]
+    InvalidTarget(BufferTarget),
+    /// Used if the offset doesn't meet GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT or GL_SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT
+    #[error("Misaligned buffer range: {source}")]
+    Misaligned {
+        #[from]
+        /// The underlying alignment error
+        source: AlignmentError,
+    },
+    /// Used if the binding point exceeds the number of binding points, the size is 0 or the range exceeds the buffer
+    #[error("Invalid binding point, offset or size")]
+    InvalidValue,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Buffer {
+    /**
+    Binds the range of the buffer that starts at `offset` and spans `size` bytes to the indexed binding point of the
+    passed target, e.g. to source several uniform blocks from one buffer.
+
+    The offset has to be a multiple of GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT for uniform buffers and of
+    GL_SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT for shader storage buffers, which is checked before calling into OpenGL.
+    The range is also bound to the target itself, just like glBindBufferRange does.
+
+    # Example
+    ```ignore
+    // two 256 byte blocks packed into one buffer, 256 being a common alignment
+    let buffer = Buffer::new(BufferTarget::Uniform).expect("Unable to create buffer");
+    buffer.data(&[0u8; 512], BufferUsage::DynamicDraw).expect("Unable to allocate buffer");
+    buffer.bind_range(BufferTarget::Uniform, 0, 0, 256).expect("Unable to bind camera block");
+    buffer.bind_range(BufferTarget::Uniform, 1, 256, 256).expect("Unable to bind lights block");
+    ```
+    */
+    pub fn bind_range(&self, target: BufferTarget, binding_point: u32, offset: usize, size: usize) -> Result<(), BindRangeError> {
+        let alignment_parameter = match target {
+            BufferTarget::Uniform => gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT,
+            BufferTarget::ShaderStorage => gl::SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT,
+            _ => return Err(BindRangeError::InvalidTarget(target)),
+        };
+
+        let mut alignment = 0;
+        unsafe { gl_call!(gl::GetIntegerv(alignment_parameter, &mut alignment)) };
+
+        check_alignment(offset, alignment.max(0) as usize)?;
+
+        let offset = gl::types::GLintptr::try_from(offset).map_err(|_| BindRangeError::InvalidValue)?;
+        let size = gl::types::GLsizeiptr::try_from(size).map_err(|_| BindRangeError::InvalidValue)?;
+
+        let rc = unsafe {
+            gl::BindBufferRange(target.as_glenum(), binding_point, self.inner.id, offset, size);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_ENUM => Err(BindRangeError::InvalidTarget(target)),
+            gl::INVALID_VALUE => Err(BindRangeError::InvalidValue),
+            _ => Err(BindRangeError::Unknown),
+        }
+    }
+}
+
+/**
+The work group counts of an indirect compute dispatch as they are read from the buffer, see [dispatch_compute_indirect].
+
+The layout is `#[repr(C)]` so commands can be uploaded as they are, or written by another compute shader.
+*/
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct DispatchIndirectCommand {
+    /// The number of work groups along the x axis
+    pub x: u32,
+    /// The number of work groups along the y axis
+    pub y: u32,
+    /// The number of work groups along the z axis
+    pub z: u32,
+}
+
+// the command consists of nothing but u32s, so it has no padding and every bit pattern is valid
+unsafe impl bytemuck::Zeroable for DispatchIndirectCommand {}
+unsafe impl bytemuck::Pod for DispatchIndirectCommand {}
+
+/// Error enum for the failed indirect dispatch of a compute shader
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum DispatchError {
+    /// Used if the driver doesn't provide glDispatchComputeIndirect, which is part of OpenGL 4.3
+    #[error("Indirect compute dispatches are not supported by the driver")]
+    Unsupported,
+    /// Used if the offset isn't a multiple of 4
+    #[error("The offset of the dispatch command isn't a multiple of 4")]
+    InvalidOffset,
+    /// Used if no compute program is bound, the command exceeds the buffer or its group counts exceed
+    /// GL_MAX_COMPUTE_WORK_GROUP_COUNT
+    #[error("No compute program is bound or the dispatch command is invalid")]
+    InvalidOperation,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/**
+Binds the buffer to GL_DISPATCH_INDIRECT_BUFFER and dispatches the bound compute program with the work group counts
+of the [DispatchIndirectCommand] stored at `offset` bytes into the buffer, which stays bound afterwards.
+
+The counts can be computed on the GPU, e.g. by a previous compute pass writing to the buffer as a shader storage
+buffer. Requires OpenGL 4.3, fails with [Unsupported](DispatchError::Unsupported) otherwise.
+
+# Example
+```ignore
+let commands = Buffer::new(BufferTarget::DispatchIndirect).expect("Unable to create buffer");
+commands.data(&[DispatchIndirectCommand { x: 16, y: 16, z: 1 }], BufferUsage::StaticDraw).expect("Unable to upload command");
+
+let _binding = particles.bind();
+dispatch_compute_indirect(&commands, 0).expect("Unable to dispatch particle update");
+```
+*/
+pub fn dispatch_compute_indirect(buffer: &Buffer, offset: usize) -> Result<(), DispatchError> {
+    if !gl::DispatchComputeIndirect::is_loaded() {
+        return Err(DispatchError::Unsupported);
+    }
+
+    let offset = gl::types::GLintptr::try_from(offset).map_err(|_| DispatchError::InvalidOperation)?;
+
+    let rc = unsafe {
+        gl::BindBuffer(gl::DISPATCH_INDIRECT_BUFFER, buffer.get_id());
+        gl::DispatchComputeIndirect(offset);
+        super::get_error()
+    };
+
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_VALUE => Err(DispatchError::InvalidOffset),
+        gl::INVALID_OPERATION => Err(DispatchError::InvalidOperation),
+        _ => Err(DispatchError::Unknown),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_have_to_be_multiples_of_the_alignment() {
+        assert_eq!(check_alignment(0, 256), Ok(()));
+        assert_eq!(check_alignment(512, 256), Ok(()));
+        assert_eq!(
+            check_alignment(64, 256),
+            Err(AlignmentError {
+                offset: 64,
+                alignment: 256
+            })
+        );
+        assert_eq!(check_alignment(3, 0), Ok(()));
+    }
+
+    #[test]
+    fn dispatch_commands_are_tightly_packed() {
+        let command = DispatchIndirectCommand { x: 1, y: 2, z: 3 };
+
+        assert_eq!(size_of::<DispatchIndirectCommand>(), 12);
+        assert_eq!(bytemuck::cast_slice::<_, u32>(&[command]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn debug_shows_the_id_and_target() {
+        // dropping would delete the buffer through the unloaded function
+        let buffer = Buffer {
+            inner: BufferInner {
+                id: 2,
+                target: BufferTarget::Uniform,
+            },
+        };
+
+        assert_eq!(format!("{buffer:?}"), "Buffer { id: 2, target: Uniform }");
+        std::mem::forget(buffer);
+    }
+
+    #[test]
+    fn snapshot_matches_the_getters() {
+        // dropping would delete the buffer through the unloaded function
+        let buffer = Buffer {
+            inner: BufferInner {
+                id: 2,
+                target: BufferTarget::ShaderStorage,
+            },
+        };
+        let snapshot = buffer.snapshot();
+
+        assert_eq!(snapshot.id, buffer.get_id());
+        assert_eq!(snapshot.target, buffer.get_target());
+        std::mem::forget(buffer);
+    }
+}
@@ -0,0 +1,48 @@
+/*!
+The opengl clear module provides idiomatic bindings to clearing the buffers of the currently bound framebuffer.
+
+Like the rest of graphene these functions don't check whether a context is current, which is up to the caller.
+*/
+
+use crate::color::Color;
+
+bitflags::bitflags! {
+    /**
+    The buffers of a framebuffer that can be cleared, combine them to clear multiple buffers at once.
+
+    # Example
+    ```ignore
+    set_clear_color(Color::BLACK);
+    clear(ClearMask::COLOR | ClearMask::DEPTH);
+    ```
+    */
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub struct ClearMask: gl::types::GLbitfield {
+        /// The color buffers currently enabled for drawing
+        const COLOR = gl::COLOR_BUFFER_BIT;
+        /// The depth buffer
+        const DEPTH = gl::DEPTH_BUFFER_BIT;
+        /// The stencil buffer
+        const STENCIL = gl::STENCIL_BUFFER_BIT;
+    }
+}
+
+/// Clears the passed buffers of the currently bound framebuffer to their respective clear values
+pub fn clear(mask: ClearMask) {
+    unsafe { gl_call!(gl::Clear(mask.bits())) };
+}
+
+/// Sets the color the color buffers are cleared to
+pub fn set_clear_color(c: Color) {
+    unsafe { gl_call!(gl::ClearColor(c.r, c.g, c.b, c.a)) };
+}
+
+/// Sets the value the depth buffer is cleared to, which is clamped to the range of 0 to 1
+pub fn set_clear_depth(d: f64) {
+    unsafe { gl_call!(gl::ClearDepth(d)) };
+}
+
+/// Sets the value the stencil buffer is cleared to, which is masked with the number of stencil bits
+pub fn set_clear_stencil(s: i32) {
+    unsafe { gl_call!(gl::ClearStencil(s)) };
+}
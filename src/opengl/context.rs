@@ -0,0 +1,391 @@
+/*!
+The opengl context module provides idiomatic bindings to queries about the current OpenGL context.
+
+graphene doesn't create contexts itself, this is the job of the windowing layer. All functions in this module
+operate on whichever context is current on the calling thread.
+*/
+
+use super::shader::{GlslProfile, GlslVersion};
+use super::state::{get_scissor, get_viewport, CompareFunc};
+use crate::geometry::Rect;
+
+/// Error enum for the failed retrieval of a string describing the current context
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum ContextStringRetrievalError {
+    /// Used if OpenGL didn't return a string, e.g. because no context is current
+    #[error("OpenGL didn't return a string, is a context current?")]
+    Unavailable,
+    /// Used if the string returned by OpenGL wasn't valid UTF8
+    #[error("The string returned by OpenGL wasn't valid UTF8: {source}")]
+    InvalidUTF8 {
+        #[from]
+        /// The underlying UTF8 conversion error
+        source: std::str::Utf8Error,
+    },
+    /// Used if the string returned by OpenGL couldn't be parsed
+    #[error("Unable to parse the string returned by OpenGL: {0}")]
+    Unparsable(String),
+}
+
+/// Retrieves one of the strings describing the current context, such as GL_VERSION or GL_RENDERER
+fn get_string(name: gl::types::GLenum) -> Result<String, ContextStringRetrievalError> {
+    let ptr = unsafe { gl_call!(gl::GetString(name)) };
+
+    if ptr.is_null() {
+        return Err(ContextStringRetrievalError::Unavailable);
+    }
+
+    let cstr = unsafe { std::ffi::CStr::from_ptr(ptr as *const std::ffi::c_char) };
+
+    Ok(cstr.to_str()?.to_owned())
+}
+
+/// Retrieves the string at the passed index of one of the indexed strings describing the current context, such as GL_EXTENSIONS
+fn get_string_i(name: gl::types::GLenum, index: gl::types::GLuint) -> Result<String, ContextStringRetrievalError> {
+    let ptr = unsafe { gl_call!(gl::GetStringi(name, index)) };
+
+    if ptr.is_null() {
+        return Err(ContextStringRetrievalError::Unavailable);
+    }
+
+    let cstr = unsafe { std::ffi::CStr::from_ptr(ptr as *const std::ffi::c_char) };
+
+    Ok(cstr.to_str()?.to_owned())
+}
+
+/**
+Parses a GL_SHADING_LANGUAGE_VERSION string as reported by desktop and ES drivers.
+
+Desktop drivers report e.g. `"4.60 NVIDIA"` which is considered to target the core profile. Versions below 1.50 predate
+profiles and are reported as compatibility, which [GlslVersion](super::shader::GlslVersion) displays without a profile.
+ES drivers report e.g. `"OpenGL ES GLSL ES 3.20"`. Returns `None` if no version number could be found.
+
+# Example
+```
+use alloy_graphene::opengl::context::parse_glsl_version;
+use alloy_graphene::opengl::shader::{GlslProfile, GlslVersion};
+
+assert_eq!(parse_glsl_version("4.60 NVIDIA"), Some(GlslVersion { major: 4, minor: 60, profile: GlslProfile::Core }));
+assert_eq!(parse_glsl_version("1.20 Mesa"), Some(GlslVersion { major: 1, minor: 20, profile: GlslProfile::Compatibility }));
+assert_eq!(parse_glsl_version("OpenGL ES GLSL ES 3.20"), Some(GlslVersion { major: 3, minor: 20, profile: GlslProfile::Es }));
+assert_eq!(parse_glsl_version("unknown"), None);
+```
+*/
+pub fn parse_glsl_version(version: &str) -> Option<GlslVersion> {
+    let profile = if version.contains("OpenGL ES") {
+        GlslProfile::Es
+    } else {
+        GlslProfile::Core
+    };
+
+    version.split_whitespace().find_map(|token| {
+        let mut parts = token.split('.');
+        let major = parts.next()?.parse::<u32>().ok()?;
+        let minor = parts.next()?;
+
+        if minor.is_empty() || !minor.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        // GLSL spells minor versions with two digits, some drivers drop the trailing zero though
+        let minor = match minor.len() {
+            1 => minor.parse::<u32>().ok()? * 10,
+            _ => minor[..2].parse::<u32>().ok()?,
+        };
+
+        let profile = match profile {
+            GlslProfile::Core if major * 100 + minor < 150 => GlslProfile::Compatibility,
+            profile => profile,
+        };
+
+        Some(GlslVersion { major, minor, profile })
+    })
+}
+
+/**
+Retrieves the highest GLSL version supported by the current context.
+
+This can be passed to [compile_with_version](super::shader::Shader::compile_with_version) to make generated shaders target the highest supported version.
+
+# Example
+```ignore
+let version = max_glsl_version().expect("Unable to query the GLSL version");
+let compiled = shader.compile_with_version(version, body).expect("Unable to compile shader");
+```
+*/
+pub fn max_glsl_version() -> Result<GlslVersion, ContextStringRetrievalError> {
+    let version = get_string(gl::SHADING_LANGUAGE_VERSION)?;
+
+    parse_glsl_version(&version).ok_or(ContextStringRetrievalError::Unparsable(version))
+}
+
+/// Retrieves the names of all extensions supported by the current context
+pub fn extensions() -> Result<std::collections::HashSet<String>, ContextStringRetrievalError> {
+    let mut count = 0;
+
+    unsafe { gl_call!(gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count)) };
+
+    (0..count.max(0) as gl::types::GLuint)
+        .map(|i| get_string_i(gl::EXTENSIONS, i))
+        .collect()
+}
+
+/**
+Retrieves the names of all extensions supported by the current context that contain the passed string, sorted by name.
+
+This is mostly useful for diagnostics, e.g. to include all tessellation related extensions in a bug report.
+
+# Example
+```ignore
+for extension in extensions_matching("KHR").expect("Unable to query extensions") {
+    println!("{}", extension);
+}
+```
+*/
+pub fn extensions_matching(substr: &str) -> Result<Vec<String>, ContextStringRetrievalError> {
+    Ok(filter_extensions(extensions()?.iter().map(String::as_str), substr))
+}
+
+/// Returns the passed extension names that contain the passed string, sorted by name
+fn filter_extensions<'a>(all: impl Iterator<Item = &'a str>, substr: &str) -> Vec<String> {
+    let mut matching = all.filter(|e| e.contains(substr)).map(str::to_owned).collect::<Vec<_>>();
+    matching.sort();
+
+    matching
+}
+
+/**
+Returns whether or not the default framebuffer of the current context is double-buffered.
+
+Swapping the buffers and controlling vsync are the responsibility of the windowing layer that created the context,
+graphene only provides this read-only query.
+*/
+pub fn is_double_buffered() -> bool {
+    let mut value = gl::FALSE;
+
+    unsafe { gl_call!(gl::GetBooleanv(gl::DOUBLEBUFFER, &mut value)) };
+
+    value == gl::TRUE
+}
+
+/// The context flag marking a GL_KHR_no_error context, which isn't part of the OpenGL 4.5 core bindings
+const CONTEXT_FLAG_NO_ERROR_BIT_KHR: gl::types::GLint = 0x0000_0008;
+
+/**
+Checks whether the current context was created as a GL_KHR_no_error context and configures graphene's error checks accordingly.
+
+In such a context glGetError always reports GL_NO_ERROR, so graphene skips all of its error checks once one was detected.
+Call this once after making a context current on a thread, the result is remembered per thread.
+Without a call graphene assumes a regular context and always checks for errors.
+
+# Example
+```ignore
+// after creating the context with the windowing layer's no-error flag set in release builds
+if detect_no_error_context() {
+    log::info!("Running without OpenGL error checks");
+}
+```
+*/
+pub fn detect_no_error_context() -> bool {
+    let no_error = get_integer(gl::CONTEXT_FLAGS) & CONTEXT_FLAG_NO_ERROR_BIT_KHR != 0;
+    set_no_error_context(no_error);
+
+    no_error
+}
+
+/// Tells graphene whether or not the context current on this thread is a GL_KHR_no_error context without querying it,
+/// e.g. because the windowing layer already reports it. See [detect_no_error_context] for more information.
+pub fn set_no_error_context(no_error: bool) {
+    super::set_no_error_context(no_error);
+}
+
+/// Retrieves `N` integers describing the current context's state
+fn get_integers<const N: usize>(pname: gl::types::GLenum) -> [gl::types::GLint; N] {
+    let mut values = [0; N];
+
+    unsafe { gl_call!(gl::GetIntegerv(pname, values.as_mut_ptr())) };
+
+    values
+}
+
+/// Retrieves a single integer describing the current context's state
+fn get_integer(pname: gl::types::GLenum) -> gl::types::GLint {
+    get_integers::<1>(pname)[0]
+}
+
+/// Retrieves a single boolean describing the current context's state
+fn get_boolean(pname: gl::types::GLenum) -> bool {
+    let mut value = gl::FALSE;
+
+    unsafe { gl_call!(gl::GetBooleanv(pname, &mut value)) };
+
+    value == gl::TRUE
+}
+
+/// Formats a comparison function by name, falling back to its raw value if graphene doesn't know it
+fn format_compare_func(value: gl::types::GLint) -> String {
+    CompareFunc::try_from(value as gl::types::GLenum).map_or_else(|e| format!("{:#06X}", e.value), |func| func.to_string())
+}
+
+/**
+Collects the parts of the current context's state that are typically relevant for graphics bugs into a human-readable report.
+
+The report contains the context strings, the current program/VAO/FBO bindings, blend/depth/stencil/cull state,
+viewport and scissor rectangles and the 2D textures bound to each texture unit. Its exact format isn't stable and only
+meant to be read by humans, e.g. when attached to a bug report.
+
+Querying the texture bindings temporarily switches the active texture unit, it is restored before returning.
+
+# Example
+```ignore
+log::error!("Rendering failed, current state:\n{}", dump_state());
+```
+*/
+pub fn dump_state() -> String {
+    use std::fmt::Write;
+
+    let string = |name| get_string(name).unwrap_or_else(|e| format!("<{}>", e));
+    let mut report = String::new();
+
+    // writing into a String can't fail, so the results are ignored throughout
+    let _ = writeln!(report, "Version: {}", string(gl::VERSION));
+    let _ = writeln!(report, "Vendor: {}", string(gl::VENDOR));
+    let _ = writeln!(report, "Renderer: {}", string(gl::RENDERER));
+    let _ = writeln!(report, "GLSL version: {}", string(gl::SHADING_LANGUAGE_VERSION));
+
+    let _ = writeln!(report, "Program: {}", get_integer(gl::CURRENT_PROGRAM));
+    let _ = writeln!(report, "Vertex array: {}", get_integer(gl::VERTEX_ARRAY_BINDING));
+    let _ = writeln!(
+        report,
+        "Framebuffer: draw {}, read {}",
+        get_integer(gl::DRAW_FRAMEBUFFER_BINDING),
+        get_integer(gl::READ_FRAMEBUFFER_BINDING)
+    );
+
+    let _ = writeln!(
+        report,
+        "Blend: enabled {}, src rgb {:#06X}, dst rgb {:#06X}, src alpha {:#06X}, dst alpha {:#06X}, equation rgb {:#06X}, equation alpha {:#06X}",
+        get_boolean(gl::BLEND),
+        get_integer(gl::BLEND_SRC_RGB),
+        get_integer(gl::BLEND_DST_RGB),
+        get_integer(gl::BLEND_SRC_ALPHA),
+        get_integer(gl::BLEND_DST_ALPHA),
+        get_integer(gl::BLEND_EQUATION_RGB),
+        get_integer(gl::BLEND_EQUATION_ALPHA)
+    );
+    let _ = writeln!(
+        report,
+        "Depth: test {}, func {}, write mask {}",
+        get_boolean(gl::DEPTH_TEST),
+        format_compare_func(get_integer(gl::DEPTH_FUNC)),
+        get_boolean(gl::DEPTH_WRITEMASK)
+    );
+    let _ = writeln!(
+        report,
+        "Stencil: test {}, front func {} ref {} mask {:#X}, back func {} ref {} mask {:#X}",
+        get_boolean(gl::STENCIL_TEST),
+        format_compare_func(get_integer(gl::STENCIL_FUNC)),
+        get_integer(gl::STENCIL_REF),
+        get_integer(gl::STENCIL_VALUE_MASK) as u32,
+        format_compare_func(get_integer(gl::STENCIL_BACK_FUNC)),
+        get_integer(gl::STENCIL_BACK_REF),
+        get_integer(gl::STENCIL_BACK_VALUE_MASK) as u32
+    );
+    let _ = writeln!(
+        report,
+        "Cull: enabled {}, face {:#06X}, front face {:#06X}",
+        get_boolean(gl::CULL_FACE),
+        get_integer(gl::CULL_FACE_MODE),
+        get_integer(gl::FRONT_FACE)
+    );
+
+    let [x, y, w, h] = get_integers::<4>(gl::VIEWPORT);
+    let _ = writeln!(report, "Viewport: x {}, y {}, width {}, height {}", x, y, w, h);
+    let [x, y, w, h] = get_integers::<4>(gl::SCISSOR_BOX);
+    let _ = writeln!(
+        report,
+        "Scissor: enabled {}, x {}, y {}, width {}, height {}",
+        get_boolean(gl::SCISSOR_TEST),
+        x,
+        y,
+        w,
+        h
+    );
+
+    let active = get_integer(gl::ACTIVE_TEXTURE) as gl::types::GLenum;
+    let units = get_integer(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS).max(0) as u32;
+    let _ = writeln!(report, "Active texture unit: {}", active.saturating_sub(gl::TEXTURE0));
+
+    for unit in 0..units {
+        unsafe { gl_call!(gl::ActiveTexture(gl::TEXTURE0 + unit)) };
+
+        let texture = get_integer(gl::TEXTURE_BINDING_2D);
+        if texture != 0 {
+            let _ = writeln!(report, "Texture unit {}: 2D texture {}", unit, texture);
+        }
+    }
+
+    unsafe { gl_call!(gl::ActiveTexture(active)) };
+
+    report
+}
+
+/// Returns the current viewport in window coordinates with the origin in the lower-left corner
+pub fn current_viewport() -> Rect {
+    let (x, y, w, h) = get_viewport();
+
+    Rect::new(x as f32, y as f32, w as f32, h as f32)
+}
+
+/// Returns the current scissor box in window coordinates with the origin in the lower-left corner.
+/// The box is reported even if the scissor test is disabled.
+pub fn current_scissor() -> Rect {
+    let (x, y, w, h) = get_scissor();
+
+    Rect::new(x as f32, y as f32, w as f32, h as f32)
+}
+
+/**
+Tells graphene which context is current on the calling thread, `None` if the context is unknown.
+
+graphene can't identify contexts itself since creating them is the job of the windowing layer. Call this after making a
+context current to enable the detection of objects used on a context they aren't shared with, see [GlObject](super::GlObject).
+The ids are arbitrary as long as every context has a distinct one.
+
+# Example
+```ignore
+window_a.make_current();
+set_current_context(Some(0));
+let vao = VertexArray::new().expect("Unable to create vertex array");
+
+window_b.make_current();
+set_current_context(Some(1));
+vao.bind(); // logs a warning in debug builds
+```
+*/
+pub fn set_current_context(id: Option<u64>) {
+    super::set_current_context_id(id);
+}
+
+/// Returns the id of the context current on the calling thread as set by [set_current_context]
+pub fn current_context() -> Option<u64> {
+    super::current_context_id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_and_sorts_extensions() {
+        let all = [
+            "GL_KHR_no_error",
+            "GL_ARB_spirv_extensions",
+            "GL_KHR_debug",
+            "GL_EXT_texture_filter_anisotropic",
+        ];
+
+        assert_eq!(filter_extensions(all.into_iter(), "KHR"), ["GL_KHR_debug", "GL_KHR_no_error"]);
+        assert!(filter_extensions(all.into_iter(), "NV").is_empty());
+    }
+}
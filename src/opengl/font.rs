@@ -0,0 +1,331 @@
+/*!
+The opengl font module provides bitmap fonts to draw text with a [SpriteBatch](super::batch::SpriteBatch).
+
+A bitmap font consists of a texture atlas containing pre-rendered glyphs and a table describing where each glyph is
+located within the atlas and how it is positioned relative to the text's baseline. The table is read from the text
+variant of the AngelCode BMFont format (`.fnt`), which most bitmap font generators can export.
+You can read more about the format here: <https://www.angelcode.com/products/bmfont/doc/file_format.html>
+*/
+
+use super::texture::Texture2D;
+use crate::geometry::{Point, Rect};
+use std::collections::HashMap;
+
+/// The location of a glyph within the atlas and how it's positioned relative to the pen, all in pixels
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Glyph {
+    /// The x coordinate of the glyph's left edge within the atlas
+    pub x: f32,
+    /// The y coordinate of the glyph's top edge within the atlas
+    pub y: f32,
+    /// The width of the glyph within the atlas
+    pub width: f32,
+    /// The height of the glyph within the atlas
+    pub height: f32,
+    /// The horizontal distance from the pen to the glyph's left edge
+    pub x_offset: f32,
+    /// The vertical distance from the top of the line to the glyph's top edge
+    pub y_offset: f32,
+    /// How far the pen moves to the right after the glyph was drawn
+    pub x_advance: f32,
+}
+
+/// Error enum for the failed parsing of a `.fnt` descriptor
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum FontParseError {
+    /// Used if the descriptor has no `common` line, which contains the line height and the size of the atlas
+    #[error("The font descriptor has no common line")]
+    MissingCommon,
+    /// Used if a `common` or `char` line lacks one of the values graphene needs
+    #[error("Line {line} of the font descriptor lacks the {key} value")]
+    MissingKey {
+        /// The 1-based number of the offending line
+        line: usize,
+        /// The key of the missing value
+        key: &'static str,
+    },
+    /// Used if a value of a `common` or `char` line isn't a number
+    #[error("The {key} value on line {line} of the font descriptor isn't a number")]
+    InvalidValue {
+        /// The 1-based number of the offending line
+        line: usize,
+        /// The key of the invalid value
+        key: String,
+    },
+    /// Used if the id of a `char` line isn't a unicode scalar value
+    #[error("{id} on line {line} of the font descriptor isn't a valid character")]
+    InvalidChar {
+        /// The 1-based number of the offending line
+        line: usize,
+        /// The invalid id
+        id: u32,
+    },
+}
+
+/// A glyph positioned by [FontMetrics::layout], `dest` is in pixels and `uv` in normalised atlas coordinates
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct GlyphQuad {
+    /// The area the glyph covers
+    pub dest: Rect,
+    /// The area of the atlas the glyph is sampled from
+    pub uv: Rect,
+}
+
+/// The glyph table of a bitmap font, independent of the atlas texture
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontMetrics {
+    /// The distance in pixels between the tops of two consecutive lines
+    pub line_height: f32,
+    /// The width of the atlas in pixels
+    pub atlas_width: f32,
+    /// The height of the atlas in pixels
+    pub atlas_height: f32,
+    /// The glyphs of the font by the character they represent
+    pub glyphs: HashMap<char, Glyph>,
+}
+
+/// The `key=value` pairs of a single line of a `.fnt` descriptor
+struct FntLine<'a> {
+    number: usize,
+    values: HashMap<&'a str, &'a str>,
+}
+
+impl FntLine<'_> {
+    /// Returns the value of the passed key as a number
+    fn get<T: std::str::FromStr>(&self, key: &'static str) -> Result<T, FontParseError> {
+        let value = self.values.get(key).ok_or(FontParseError::MissingKey { line: self.number, key })?;
+
+        value.parse().map_err(|_| FontParseError::InvalidValue {
+            line: self.number,
+            key: key.to_owned(),
+        })
+    }
+}
+
+impl FontMetrics {
+    /**
+    Parses the text variant of a `.fnt` descriptor.
+
+    Only the `common` and `char` lines are read, all others, including kerning pairs, are ignored.
+    The descriptor has to describe a single page, glyphs on other pages are sampled from the wrong texture.
+
+    # Example
+    ```
+    use alloy_graphene::opengl::font::FontMetrics;
+
+    let metrics = FontMetrics::parse_fnt(
+        "common lineHeight=16 base=12 scaleW=64 scaleH=64 pages=1
+    char id=65 x=0 y=0 width=8 height=10 xoffset=0 yoffset=2 xadvance=9 page=0 chnl=15",
+    )
+    .expect("Unable to parse font");
+
+    assert_eq!(metrics.line_height, 16.0);
+    assert_eq!(metrics.glyphs[&'A'].x_advance, 9.0);
+    ```
+    */
+    pub fn parse_fnt(src: &str) -> Result<FontMetrics, FontParseError> {
+        let mut common = None;
+        let mut glyphs = HashMap::new();
+
+        for (index, line) in src.lines().enumerate() {
+            let mut tokens = line.split_whitespace();
+            let tag = tokens.next();
+
+            if tag != Some("common") && tag != Some("char") {
+                continue;
+            }
+
+            let line = FntLine {
+                number: index + 1,
+                values: tokens.filter_map(|token| token.split_once('=')).collect(),
+            };
+
+            if tag == Some("common") {
+                common = Some((line.get("lineHeight")?, line.get("scaleW")?, line.get("scaleH")?));
+                continue;
+            }
+
+            let id = line.get("id")?;
+            let char = char::from_u32(id).ok_or(FontParseError::InvalidChar { line: line.number, id })?;
+
+            glyphs.insert(
+                char,
+                Glyph {
+                    x: line.get("x")?,
+                    y: line.get("y")?,
+                    width: line.get("width")?,
+                    height: line.get("height")?,
+                    x_offset: line.get("xoffset")?,
+                    y_offset: line.get("yoffset")?,
+                    x_advance: line.get("xadvance")?,
+                },
+            );
+        }
+
+        let (line_height, atlas_width, atlas_height) = common.ok_or(FontParseError::MissingCommon)?;
+
+        Ok(FontMetrics {
+            line_height,
+            atlas_width,
+            atlas_height,
+            glyphs,
+        })
+    }
+
+    /**
+    Lays out the text with the top-left corner of its first line at `position` and returns a quad per visible glyph.
+
+    Newlines start a new line below the current one. Characters without a glyph are skipped, glyphs without an area,
+    like spaces usually are, only advance the pen.
+    */
+    pub fn layout(&self, text: &str, position: Point) -> Vec<GlyphQuad> {
+        let mut quads = Vec::new();
+        let mut pen = position;
+
+        for char in text.chars() {
+            if char == '\n' {
+                pen = Point::new(position.x, pen.y + self.line_height);
+                continue;
+            }
+
+            let Some(glyph) = self.glyphs.get(&char) else {
+                continue;
+            };
+
+            if glyph.width > 0.0 && glyph.height > 0.0 {
+                quads.push(GlyphQuad {
+                    dest: Rect::new(pen.x + glyph.x_offset, pen.y + glyph.y_offset, glyph.width, glyph.height),
+                    uv: Rect::new(
+                        glyph.x / self.atlas_width,
+                        glyph.y / self.atlas_height,
+                        glyph.width / self.atlas_width,
+                        glyph.height / self.atlas_height,
+                    ),
+                });
+            }
+
+            pen.x += glyph.x_advance;
+        }
+
+        quads
+    }
+}
+
+/**
+A bitmap font, consisting of the atlas texture its glyphs are sampled from and their metrics.
+
+# Example
+```ignore
+let atlas = Texture2D::from_image(&image::open("font.png")?)?;
+let font = BitmapFont::from_fnt(atlas, &std::fs::read_to_string("font.fnt")?)?;
+
+batch.begin();
+batch.draw_text(&font, "Hello, world!", Point::new(16.0, 16.0), Color::WHITE)?;
+batch.end()?;
+```
+*/
+#[derive(Debug)]
+pub struct BitmapFont {
+    atlas: Texture2D,
+    metrics: FontMetrics,
+}
+
+impl BitmapFont {
+    /// Returns a font sampling the passed atlas according to the passed metrics
+    pub fn new(atlas: Texture2D, metrics: FontMetrics) -> BitmapFont {
+        BitmapFont { atlas, metrics }
+    }
+
+    /// Returns a font sampling the passed atlas according to the passed `.fnt` descriptor, see [FontMetrics::parse_fnt]
+    pub fn from_fnt(atlas: Texture2D, descriptor: &str) -> Result<BitmapFont, FontParseError> {
+        Ok(BitmapFont::new(atlas, FontMetrics::parse_fnt(descriptor)?))
+    }
+
+    /// Returns the texture the glyphs are sampled from
+    pub fn atlas(&self) -> &Texture2D {
+        &self.atlas
+    }
+
+    /// Returns the metrics of the font's glyphs
+    pub fn metrics(&self) -> &FontMetrics {
+        &self.metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FNT: &str = "info face=\"Test Sans\" size=16 bold=0 italic=0
+common lineHeight=16 base=12 scaleW=64 scaleH=32 pages=1 packed=0
+page id=0 file=\"test.png\"
+chars count=3
+char id=32 x=0 y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=4 page=0 chnl=15
+char id=65 x=0 y=0 width=8 height=10 xoffset=0 yoffset=2 xadvance=9 page=0 chnl=15
+char id=66 x=8 y=16 width=8 height=10 xoffset=1 yoffset=2 xadvance=10 page=0 chnl=15
+kernings count=1
+kerning first=65 second=66 amount=-1
+";
+
+    #[test]
+    fn descriptors_are_parsed() {
+        let metrics = FontMetrics::parse_fnt(FNT).unwrap();
+
+        assert_eq!((metrics.line_height, metrics.atlas_width, metrics.atlas_height), (16.0, 64.0, 32.0));
+        assert_eq!(metrics.glyphs.len(), 3);
+        assert_eq!(
+            metrics.glyphs[&'B'],
+            Glyph {
+                x: 8.0,
+                y: 16.0,
+                width: 8.0,
+                height: 10.0,
+                x_offset: 1.0,
+                y_offset: 2.0,
+                x_advance: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_descriptors_are_rejected() {
+        assert_eq!(
+            FontMetrics::parse_fnt("char id=65 x=0"),
+            Err(FontParseError::MissingKey { line: 1, key: "y" })
+        );
+        assert_eq!(
+            FontMetrics::parse_fnt("common lineHeight=tall scaleW=64 scaleH=64"),
+            Err(FontParseError::InvalidValue {
+                line: 1,
+                key: "lineHeight".to_owned()
+            })
+        );
+        assert_eq!(
+            FontMetrics::parse_fnt("\nchar id=55296 x=0 y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=0"),
+            Err(FontParseError::InvalidChar { line: 2, id: 55296 })
+        );
+        assert_eq!(
+            FontMetrics::parse_fnt("info face=\"Test Sans\""),
+            Err(FontParseError::MissingCommon)
+        );
+    }
+
+    #[test]
+    fn glyphs_advance_the_pen_and_newlines_start_a_new_line() {
+        let metrics = FontMetrics::parse_fnt(FNT).unwrap();
+        let quads = metrics.layout("A B\n?A", Point::new(10.0, 20.0));
+
+        let dests = quads.iter().map(|quad| quad.dest).collect::<Vec<_>>();
+        assert_eq!(
+            dests,
+            [
+                Rect::new(10.0, 22.0, 8.0, 10.0),
+                // the space only advances the pen
+                Rect::new(10.0 + 9.0 + 4.0 + 1.0, 22.0, 8.0, 10.0),
+                // the unknown ? is skipped entirely
+                Rect::new(10.0, 38.0, 8.0, 10.0),
+            ]
+        );
+        assert_eq!(quads[1].uv, Rect::new(0.125, 0.5, 0.125, 0.3125));
+    }
+}
@@ -0,0 +1,662 @@
+/*!
+The opengl framebuffer module provides idiomatic bindings to OpenGL framebuffer objects (FBOs).
+
+Framebuffers allow rendering into textures instead of the window, which is the basis of offscreen passes and effects.
+You can read more about framebuffers here: <https://www.khronos.org/opengl/wiki/Framebuffer_Object>
+*/
+
+use super::renderbuffer::Renderbuffer;
+use super::texture::{Texture2D, TextureCreationError, TextureFormat, TextureUploadError};
+
+/// Stores the underlying data of a framebuffer
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [Framebuffer](Framebuffer) struct.
+#[derive(Debug)]
+pub struct FramebufferInner {
+    /// The id of the framebuffer, generated by OpenGL and valid for the lifetime of the framebuffer
+    pub id: gl::types::GLuint,
+    /// The context the framebuffer was created on if it was known, see [set_current_context](super::context::set_current_context)
+    pub context: Option<u64>,
+}
+
+/// A copy of the publicly relevant data of a framebuffer, see [Framebuffer::snapshot](Framebuffer::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct FramebufferSnapshot {
+    /// The id of the framebuffer, generated by OpenGL. Only valid as long as the framebuffer it was taken from is alive
+    pub id: gl::types::GLuint,
+}
+
+impl PartialEq for FramebufferInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for FramebufferInner {}
+
+impl std::hash::Hash for FramebufferInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for framebuffers to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for FramebufferInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FramebufferInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A framebuffer is a collection of attachments that can be rendered into instead of the window's default framebuffer.
+
+All functions that modify the framebuffer bind it to GL_FRAMEBUFFER in order to do so.
+
+# Example
+```ignore
+let texture = Texture2D::new().expect("Unable to create texture");
+texture.upload_rgba8(256, 256, &vec![0; 256 * 256 * 4]).expect("Unable to allocate texture");
+
+let framebuffer = Framebuffer::new().expect("Unable to create framebuffer");
+framebuffer.attach_color(0, &texture).expect("Unable to attach texture");
+framebuffer.check_complete().expect("Framebuffer is incomplete");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Framebuffer {
+    inner: FramebufferInner,
+}
+
+impl std::fmt::Debug for Framebuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Framebuffer").field("id", &self.inner.id).finish()
+    }
+}
+
+impl Framebuffer {
+    /// Returns a reference to the inner (private) data of the framebuffer.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &FramebufferInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the framebuffer.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut FramebufferInner {
+        &mut self.inner
+    }
+
+    /// Retrieves the id of the framebuffer.
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the framebuffer's id, which is the safe alternative to [inner](Framebuffer::inner) for reading.
+    pub fn snapshot(&self) -> FramebufferSnapshot {
+        FramebufferSnapshot { id: self.inner.id }
+    }
+
+    /// Binds the framebuffer for both drawing and reading.
+    pub fn bind(&self) {
+        super::check_context::<Framebuffer>(self.inner.context, self.inner.id);
+
+        unsafe { gl_call!(gl::BindFramebuffer(gl::FRAMEBUFFER, self.inner.id)) };
+    }
+
+    /// Binds the window's default framebuffer again, unbinding any framebuffer including this one.
+    pub fn unbind(&self) {
+        unsafe { gl_call!(gl::BindFramebuffer(gl::FRAMEBUFFER, 0)) };
+    }
+}
+
+impl super::GlObject for Framebuffer {
+    const SHAREABLE: bool = false;
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteFramebuffers(1, &self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a framebuffer
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum FramebufferCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Framebuffer {
+    /// Returns a new framebuffer or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+    pub fn new() -> Result<Framebuffer, FramebufferCreationError> {
+        let mut id = 0;
+
+        unsafe { gl_call!(gl::GenFramebuffers(1, &mut id)) };
+
+        if id == 0 {
+            return Err(FramebufferCreationError::Unknown);
+        }
+
+        Ok(Framebuffer {
+            inner: FramebufferInner {
+                id,
+                context: super::current_context_id(),
+            },
+        })
+    }
+}
+
+/// Error enum for the failed attachment of an image to a framebuffer
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum FramebufferAttachError {
+    /// Used if the attachment point exceeds the number supported by OpenGL, e.g. GL_MAX_COLOR_ATTACHMENTS
+    #[error("The attachment point isn't supported by OpenGL")]
+    InvalidAttachment,
+    /// Used if the attached object is incompatible with the attachment point
+    #[error("The object can't be attached to the attachment point")]
+    IncompatibleObject,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Framebuffer {
+    /// Attaches level 0 of the passed texture to the color attachment point with the passed index.
+    /// The texture must have been allocated before the framebuffer can be complete.
+    pub fn attach_color(&self, index: u32, tex: &Texture2D) -> Result<(), FramebufferAttachError> {
+        let attachment = gl::COLOR_ATTACHMENT0
+            .checked_add(index)
+            .ok_or(FramebufferAttachError::InvalidAttachment)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::FramebufferTexture2D(gl::FRAMEBUFFER, attachment, gl::TEXTURE_2D, tex.get_id(), 0);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_ENUM => Err(FramebufferAttachError::InvalidAttachment),
+            gl::INVALID_OPERATION => Err(FramebufferAttachError::IncompatibleObject),
+            _ => Err(FramebufferAttachError::Unknown),
+        }
+    }
+}
+
+impl Framebuffer {
+    /// Attaches the passed renderbuffer to the depth and/or stencil attachment point matching its format.
+    /// The renderbuffer's storage has to be allocated before it can be attached.
+    pub fn attach_depth_stencil(&self, rb: &Renderbuffer) -> Result<(), FramebufferAttachError> {
+        let format = rb
+            .get_format()
+            .map_err(|_| FramebufferAttachError::IncompatibleObject)?
+            .ok_or(FramebufferAttachError::IncompatibleObject)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, format.attachment_point(), gl::RENDERBUFFER, rb.get_id());
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_ENUM => Err(FramebufferAttachError::InvalidAttachment),
+            gl::INVALID_OPERATION => Err(FramebufferAttachError::IncompatibleObject),
+            _ => Err(FramebufferAttachError::Unknown),
+        }
+    }
+}
+
+/// An attachment point of a framebuffer
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum Attachment {
+    /// The color attachment point with the passed index, i.e. GL_COLOR_ATTACHMENTi
+    Color(u32),
+    /// The depth attachment point
+    Depth,
+    /// The stencil attachment point
+    Stencil,
+    /// The combined depth and stencil attachment point
+    DepthStencil,
+}
+
+impl Attachment {
+    /// Returns the OpenGL enum of the attachment point or None if the color index can't be represented as one
+    pub fn as_glenum(self) -> Option<gl::types::GLenum> {
+        match self {
+            Attachment::Color(index) => gl::COLOR_ATTACHMENT0.checked_add(index),
+            Attachment::Depth => Some(gl::DEPTH_ATTACHMENT),
+            Attachment::Stencil => Some(gl::STENCIL_ATTACHMENT),
+            Attachment::DepthStencil => Some(gl::DEPTH_STENCIL_ATTACHMENT),
+        }
+    }
+}
+
+/// Error enum for the failed invalidation of a framebuffer's attachments
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum FramebufferInvalidateError {
+    /// Used if the driver doesn't provide glInvalidateFramebuffer, which is part of OpenGL 4.3 and GL_ARB_invalidate_subdata
+    #[error("Invalidating framebuffers is not supported by the driver")]
+    Unsupported,
+    /// Used if an attachment point exceeds the number supported by OpenGL, e.g. GL_MAX_COLOR_ATTACHMENTS
+    #[error("The attachment point isn't supported by OpenGL")]
+    InvalidAttachment,
+    /// Used if the width or height of the region is negative
+    #[error("The region's size is negative: {width}x{height}")]
+    NegativeSize {
+        /// The width of the region
+        width: i32,
+        /// The height of the region
+        height: i32,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// Converts the attachments to the enums passed to glInvalidate(Sub)Framebuffer
+fn attachment_enums(attachments: &[Attachment]) -> Result<Vec<gl::types::GLenum>, FramebufferInvalidateError> {
+    attachments
+        .iter()
+        .map(|attachment| attachment.as_glenum().ok_or(FramebufferInvalidateError::InvalidAttachment))
+        .collect()
+}
+
+/// Maps the error of glInvalidate(Sub)Framebuffer to a [FramebufferInvalidateError]
+fn invalidate_result(rc: gl::types::GLenum, width: i32, height: i32) -> Result<(), FramebufferInvalidateError> {
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_ENUM | gl::INVALID_OPERATION => Err(FramebufferInvalidateError::InvalidAttachment),
+        gl::INVALID_VALUE => Err(FramebufferInvalidateError::NegativeSize { width, height }),
+        _ => Err(FramebufferInvalidateError::Unknown),
+    }
+}
+
+impl Framebuffer {
+    /**
+    Binds the framebuffer and tells OpenGL that the contents of the passed attachments aren't needed anymore, which
+    allows tiled GPUs to skip writing them back to memory at the end of a pass.
+    Requires OpenGL 4.3, fails with [Unsupported](FramebufferInvalidateError::Unsupported) otherwise.
+
+    # Example
+    ```ignore
+    // the depth buffer is only needed while rendering the pass
+    framebuffer.invalidate(&[Attachment::DepthStencil]).expect("Unable to invalidate depth and stencil");
+    ```
+    */
+    pub fn invalidate(&self, attachments: &[Attachment]) -> Result<(), FramebufferInvalidateError> {
+        if !gl::InvalidateFramebuffer::is_loaded() {
+            return Err(FramebufferInvalidateError::Unsupported);
+        }
+
+        let attachments = attachment_enums(attachments)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::InvalidateFramebuffer(gl::FRAMEBUFFER, attachments.len() as gl::types::GLsizei, attachments.as_ptr());
+            super::get_error()
+        };
+
+        invalidate_result(rc, 0, 0)
+    }
+
+    /// Binds the framebuffer and invalidates the contents of the passed attachments like [invalidate](Framebuffer::invalidate),
+    /// but only within the region starting at (x, y) with the size (w, h).
+    pub fn invalidate_sub(&self, attachments: &[Attachment], x: i32, y: i32, w: i32, h: i32) -> Result<(), FramebufferInvalidateError> {
+        if !gl::InvalidateSubFramebuffer::is_loaded() {
+            return Err(FramebufferInvalidateError::Unsupported);
+        }
+
+        let attachments = attachment_enums(attachments)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::InvalidateSubFramebuffer(
+                gl::FRAMEBUFFER,
+                attachments.len() as gl::types::GLsizei,
+                attachments.as_ptr(),
+                x,
+                y,
+                w,
+                h,
+            );
+            super::get_error()
+        };
+
+        invalidate_result(rc, w, h)
+    }
+}
+
+impl Framebuffer {
+    /**
+    Attaches level 0 of the passed depth texture to the depth attachment point, or to the depth and stencil attachment
+    point if its format has a stencil index as well. Unlike a renderbuffer the texture can be sampled afterwards,
+    e.g. as a `sampler2DShadow`.
+
+    Fails with [IncompatibleObject](FramebufferAttachError::IncompatibleObject) if the texture wasn't allocated with
+    one of the depth [TextureFormat]s.
+
+    # Example
+    ```ignore
+    let depth = Texture2D::new().expect("Unable to create texture");
+    depth.image_2d_level::<u32>(0, 256, 256, TextureFormat::DepthComponent24, &vec![0; 256 * 256]).expect("Unable to allocate texture");
+    framebuffer.attach_depth(&depth).expect("Unable to attach depth texture");
+    ```
+    */
+    pub fn attach_depth(&self, tex: &Texture2D) -> Result<(), FramebufferAttachError> {
+        let attachment = tex
+            .get_format()
+            .map_err(|_| FramebufferAttachError::IncompatibleObject)?
+            .and_then(TextureFormat::depth_attachment_point)
+            .ok_or(FramebufferAttachError::IncompatibleObject)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::FramebufferTexture2D(gl::FRAMEBUFFER, attachment, gl::TEXTURE_2D, tex.get_id(), 0);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_ENUM => Err(FramebufferAttachError::InvalidAttachment),
+            gl::INVALID_OPERATION => Err(FramebufferAttachError::IncompatibleObject),
+            _ => Err(FramebufferAttachError::Unknown),
+        }
+    }
+}
+
+/// Error enum describing why a framebuffer is incomplete
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum FramebufferError {
+    /// Used if at least one attachment is incomplete, e.g. because its texture wasn't allocated
+    #[error("At least one attachment is incomplete")]
+    IncompleteAttachment,
+    /// Used if the framebuffer has no attachments at all
+    #[error("The framebuffer has no attachments")]
+    MissingAttachment,
+    /// Used if a draw buffer refers to an attachment point without an attachment
+    #[error("A draw buffer refers to an empty attachment point")]
+    IncompleteDrawBuffer,
+    /// Used if the read buffer refers to an attachment point without an attachment
+    #[error("The read buffer refers to an empty attachment point")]
+    IncompleteReadBuffer,
+    /// Used if the attachments don't have the same number of samples
+    #[error("The attachments' sample counts don't match")]
+    IncompleteMultisample,
+    /// Used if layered and non-layered attachments are mixed
+    #[error("Layered and non-layered attachments are mixed")]
+    IncompleteLayerTargets,
+    /// Used if the combination of the attachments' formats isn't supported by the driver
+    #[error("The combination of attachment formats is unsupported")]
+    Unsupported,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Framebuffer {
+    /// Checks whether or not the framebuffer is complete, i.e. whether it can be rendered into.
+    pub fn check_complete(&self) -> Result<(), FramebufferError> {
+        self.bind();
+
+        let status = unsafe { gl_call!(gl::CheckFramebufferStatus(gl::FRAMEBUFFER)) };
+
+        match status {
+            gl::FRAMEBUFFER_COMPLETE => Ok(()),
+            gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Err(FramebufferError::IncompleteAttachment),
+            gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Err(FramebufferError::MissingAttachment),
+            gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Err(FramebufferError::IncompleteDrawBuffer),
+            gl::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Err(FramebufferError::IncompleteReadBuffer),
+            gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Err(FramebufferError::IncompleteMultisample),
+            gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Err(FramebufferError::IncompleteLayerTargets),
+            gl::FRAMEBUFFER_UNSUPPORTED => Err(FramebufferError::Unsupported),
+            _ => Err(FramebufferError::Unknown),
+        }
+    }
+}
+
+/// A 1x1 RGBA8 render target for warm-up and no-op draws, see [scratch_framebuffer]
+#[derive(Debug)]
+pub struct ScratchFramebuffer {
+    /// The complete framebuffer, the texture is attached to its color attachment 0
+    pub framebuffer: Framebuffer,
+    /// The 1x1 texture the framebuffer renders into
+    pub texture: Texture2D,
+}
+
+/// Error enum for the failed creation of a framebuffer rendering into a new texture, e.g. the [scratch_framebuffer] or a [PingPong]
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum RenderTargetError {
+    /// Used if the framebuffer couldn't be created
+    #[error("Unable to create framebuffer: {source}")]
+    FramebufferCreation {
+        #[from]
+        /// The underlying creation error
+        source: FramebufferCreationError,
+    },
+    /// Used if the texture couldn't be created
+    #[error("Unable to create texture: {source}")]
+    TextureCreation {
+        #[from]
+        /// The underlying creation error
+        source: TextureCreationError,
+    },
+    /// Used if the texture couldn't be allocated, e.g. because the size exceeds GL_MAX_TEXTURE_SIZE
+    #[error("Unable to allocate texture: {source}")]
+    TextureUpload {
+        #[from]
+        /// The underlying upload error
+        source: TextureUploadError,
+    },
+    /// Used if the texture couldn't be attached to the framebuffer
+    #[error("Unable to attach texture: {source}")]
+    Attach {
+        #[from]
+        /// The underlying attachment error
+        source: FramebufferAttachError,
+    },
+    /// Used if the framebuffer is incomplete
+    #[error("Framebuffer is incomplete: {source}")]
+    Incomplete {
+        #[from]
+        /// The reason the framebuffer is incomplete
+        source: FramebufferError,
+    },
+}
+
+/// Creates a complete framebuffer rendering into a new transparent RGBA8 texture of the passed size and binds the
+/// window's default framebuffer again
+fn render_target(width: u32, height: u32) -> Result<(Framebuffer, Texture2D), RenderTargetError> {
+    let texture = Texture2D::new()?;
+    texture.upload_rgba8(width, height, &vec![0; width as usize * height as usize * 4])?;
+
+    let framebuffer = Framebuffer::new()?;
+    framebuffer.attach_color(0, &texture)?;
+    framebuffer.check_complete()?;
+    framebuffer.unbind();
+
+    Ok((framebuffer, texture))
+}
+
+thread_local! {
+    static SCRATCH_FRAMEBUFFER: std::cell::RefCell<Option<std::rc::Rc<ScratchFramebuffer>>> = const { std::cell::RefCell::new(None) };
+}
+
+/**
+Returns the 1x1 scratch framebuffer of this thread, creating it on first use.
+
+The framebuffer is created under the current context and reused by all later calls, so features needing a throwaway
+render target don't each allocate their own. It's created anew if the current context changed in the meantime, see
+[set_current_context](super::context::set_current_context). The window's default framebuffer is bound again after
+creation.
+
+# Example
+```ignore
+let scratch = scratch_framebuffer().expect("Unable to create scratch framebuffer");
+scratch.framebuffer.bind();
+// draw once to make the driver compile the pipeline state
+```
+*/
+pub fn scratch_framebuffer() -> Result<std::rc::Rc<ScratchFramebuffer>, RenderTargetError> {
+    SCRATCH_FRAMEBUFFER.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+
+        if let Some(existing) = scratch
+            .as_ref()
+            .filter(|existing| existing.framebuffer.inner.context == super::current_context_id())
+        {
+            return Ok(existing.clone());
+        }
+
+        let (framebuffer, texture) = render_target(1, 1)?;
+        let created = std::rc::Rc::new(ScratchFramebuffer { framebuffer, texture });
+        *scratch = Some(created.clone());
+
+        Ok(created)
+    })
+}
+
+/// The two targets of a ping-pong pair and which of them is read from, independent of OpenGL
+#[derive(Debug)]
+struct PingPongTargets<T> {
+    targets: [T; 2],
+    read: usize,
+}
+
+impl<T> PingPongTargets<T> {
+    fn new(targets: [T; 2]) -> PingPongTargets<T> {
+        PingPongTargets { targets, read: 0 }
+    }
+
+    fn read(&self) -> &T {
+        &self.targets[self.read]
+    }
+
+    fn write(&self) -> &T {
+        &self.targets[1 - self.read]
+    }
+
+    fn swap(&mut self) {
+        self.read = 1 - self.read;
+    }
+}
+
+/**
+Two render targets of the same size for iterative effects such as blurs or simulations, each pass reads the result of
+the previous one and writes the next.
+
+[read](PingPong::read) returns the texture holding the latest result, [write](PingPong::write) the framebuffer the
+next pass renders into. [swap](PingPong::swap) exchanges the two once a pass is done, so the result just written is
+read by the next pass.
+
+# Example
+```ignore
+let mut ping_pong = PingPong::new(256, 256).expect("Unable to create render targets");
+
+for _ in 0..2 {
+    ping_pong.write().bind();
+    ping_pong.read().bind_unit(0).expect("Unable to bind previous result");
+    // draw a fullscreen quad sampling the previous result
+    ping_pong.swap();
+}
+
+// ping_pong.read() now holds the result of the second pass
+```
+*/
+#[derive(Debug)]
+pub struct PingPong {
+    targets: PingPongTargets<(Framebuffer, Texture2D)>,
+}
+
+impl PingPong {
+    /// Creates two complete framebuffers rendering into transparent RGBA8 textures of the passed size.
+    /// The window's default framebuffer is bound afterwards.
+    pub fn new(width: u32, height: u32) -> Result<PingPong, RenderTargetError> {
+        Ok(PingPong {
+            targets: PingPongTargets::new([render_target(width, height)?, render_target(width, height)?]),
+        })
+    }
+
+    /// Returns the texture holding the result of the last pass, initially transparent black
+    pub fn read(&self) -> &Texture2D {
+        &self.targets.read().1
+    }
+
+    /// Returns the framebuffer the next pass renders into
+    pub fn write(&self) -> &Framebuffer {
+        &self.targets.write().0
+    }
+
+    /// Exchanges the targets, making the one written by the last pass the one read by the next
+    pub fn swap(&mut self) {
+        self.targets.swap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attachments_map_to_their_attachment_points() {
+        assert_eq!(Attachment::Color(2).as_glenum(), Some(gl::COLOR_ATTACHMENT2));
+        assert_eq!(Attachment::DepthStencil.as_glenum(), Some(gl::DEPTH_STENCIL_ATTACHMENT));
+        assert_eq!(
+            attachment_enums(&[Attachment::Depth, Attachment::Color(u32::MAX)]),
+            Err(FramebufferInvalidateError::InvalidAttachment)
+        );
+    }
+
+    #[test]
+    fn swapping_alternates_the_read_and_write_targets() {
+        let mut targets = PingPongTargets::new(["first", "second"]);
+
+        assert_eq!((*targets.read(), *targets.write()), ("first", "second"));
+
+        targets.swap();
+        assert_eq!((*targets.read(), *targets.write()), ("second", "first"));
+
+        targets.swap();
+        assert_eq!((*targets.read(), *targets.write()), ("first", "second"));
+    }
+
+    #[test]
+    fn debug_shows_the_id() {
+        // dropping would delete the framebuffer through the unloaded function
+        let framebuffer = Framebuffer {
+            inner: FramebufferInner { id: 7, context: None },
+        };
+
+        assert_eq!(format!("{framebuffer:?}"), "Framebuffer { id: 7 }");
+        std::mem::forget(framebuffer);
+    }
+
+    #[test]
+    fn snapshot_matches_the_getters() {
+        // dropping would delete the framebuffer through the unloaded function
+        let framebuffer = Framebuffer {
+            inner: FramebufferInner { id: 7, context: None },
+        };
+
+        assert_eq!(framebuffer.snapshot().id, framebuffer.get_id());
+        std::mem::forget(framebuffer);
+    }
+}
@@ -0,0 +1,377 @@
+/*!
+The opengl hot_reload module allows reloading shader programs while the application is running whenever their sources change.
+
+This is meant for iterating on shaders during development, a program keeps using its previous version if the changed
+sources fail to compile or link, so a typo never takes down the application.
+Only the files passed to the [ShaderWatcher](ShaderWatcher) are watched, files they include are not, unless a whole
+directory is watched via [ShaderWatcher::directory](ShaderWatcher::directory).
+Their directories are watched rather than the files themselves, so saves that replace a file are picked up as well.
+*/
+
+use super::program::{LinkedProgram, ProgramCreationError, ProgramLinkError, ShaderProgram};
+use super::shader::{Shader, ShaderCompileFromPathError, ShaderCreationError, ShaderType};
+
+/// Error enum for the failed (re)loading of a watched shader program
+#[derive(thiserror::Error, Debug)]
+pub enum ReloadError {
+    /// Used if the source files couldn't be watched or the watcher reported an error
+    #[error("Unable to watch the shader sources: {source}")]
+    Watch {
+        #[from]
+        /// The underlying watcher error
+        source: notify::Error,
+    },
+    /// Used if one of the shaders couldn't be created
+    #[error("Unable to create shader: {source}")]
+    ShaderCreation {
+        #[from]
+        /// The underlying creation error
+        source: ShaderCreationError,
+    },
+    /// Used if one of the shaders couldn't be compiled from its file
+    #[error("Unable to compile shader: {source}")]
+    Compile {
+        #[from]
+        /// The underlying compilation error
+        source: ShaderCompileFromPathError,
+    },
+    /// Used if the program couldn't be created
+    #[error("Unable to create program: {source}")]
+    ProgramCreation {
+        #[from]
+        /// The underlying creation error
+        source: ProgramCreationError,
+    },
+    /// Used if the program couldn't be linked
+    #[error("Unable to link program: {source}")]
+    Link {
+        #[from]
+        /// The underlying link error
+        source: ProgramLinkError,
+    },
+}
+
+/// The callback reload errors are passed to, see [ShaderWatcher::on_error]
+type ErrorCallback = Box<dyn FnMut(&ReloadError)>;
+
+/**
+Watches the source files of a shader program and rebuilds the program from them on demand.
+
+# Example
+```ignore
+let mut watcher = ShaderWatcher::new([(ShaderType::Vertex, "sprite.vert"), (ShaderType::FragmentShader, "sprite.frag")])
+    .expect("Unable to watch shader sources");
+watcher.on_error(|e| log::error!("Unable to reload shader program: {}", e));
+let mut linked = watcher.link().expect("Unable to link shader program");
+
+loop {
+    // failures were already reported to the callback, the previous program is kept
+    let _ = linked.reload_if_changed();
+    // draw using the program
+}
+```
+*/
+pub struct ShaderWatcher {
+    sources: Vec<(ShaderType, std::path::PathBuf)>,
+    // the canonical paths of the sources, used to filter the events of their directories
+    watched: Vec<std::path::PathBuf>,
+    // the canonical path of the watched directory if the sources are the shaders it contains rather than fixed files
+    directory: Option<std::path::PathBuf>,
+    on_error: std::cell::RefCell<Option<ErrorCallback>>,
+    // only kept so the events keep coming in, dropping it stops watching
+    _watcher: notify::RecommendedWatcher,
+    events: std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+}
+
+// the callback can't be formatted
+impl std::fmt::Debug for ShaderWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShaderWatcher")
+            .field("sources", &self.sources)
+            .field("directory", &self.directory)
+            .finish()
+    }
+}
+
+/// Returns the canonical path of the passed directory, attributing failures to `path`
+fn canonical_directory(directory: &std::path::Path, path: &std::path::Path) -> Result<std::path::PathBuf, ReloadError> {
+    Ok(directory
+        .canonicalize()
+        .map_err(|e| notify::Error::io(e).add_path(path.to_path_buf()))?)
+}
+
+/// Returns the shader files directly inside the passed directory in the order of their paths, paired with the type
+/// their extension maps to, see [ShaderType::from_path]. Files with other extensions are skipped.
+fn scan_directory(directory: &std::path::Path) -> Result<Vec<(ShaderType, std::path::PathBuf)>, ReloadError> {
+    let entries = std::fs::read_dir(directory).map_err(|e| notify::Error::io(e).add_path(directory.to_path_buf()))?;
+
+    let mut sources = Vec::new();
+
+    for entry in entries {
+        let path = entry.map_err(notify::Error::io)?.path();
+
+        if let Some(r#type) = ShaderType::from_path(&path).filter(|_| path.is_file()) {
+            sources.push((r#type, path));
+        }
+    }
+
+    sources.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+    Ok(sources)
+}
+
+impl ShaderWatcher {
+    /// Starts watching the passed source files, each of which is compiled as a shader of the type it's paired with
+    pub fn new<I, P>(sources: I) -> Result<ShaderWatcher, ReloadError>
+    where
+        I: IntoIterator<Item = (ShaderType, P)>,
+        P: AsRef<std::path::Path>,
+    {
+        let sources = sources
+            .into_iter()
+            .map(|(r#type, path)| (r#type, path.as_ref().to_path_buf()))
+            .collect::<Vec<_>>();
+
+        // editors commonly save by writing a temporary file and renaming it over the original, which replaces the watched
+        // inode and silently ends a watch on the file itself, so the parent directories are watched instead
+        let mut directories = Vec::new();
+        let mut watched = Vec::with_capacity(sources.len());
+
+        for (_, path) in &sources {
+            let file_name = path
+                .file_name()
+                .ok_or_else(|| notify::Error::path_not_found().add_path(path.clone()))?;
+            let directory = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => std::path::Path::new("."),
+            };
+            let directory = canonical_directory(directory, path)?;
+
+            watched.push(directory.join(file_name));
+            if !directories.contains(&directory) {
+                directories.push(directory);
+            }
+        }
+
+        ShaderWatcher::watch(sources, watched, None, &directories)
+    }
+
+    /**
+    Starts watching all shader files directly inside the passed directory, the type of each is derived from its
+    extension, see [ShaderType::from_path].
+
+    The directory is scanned again on every rebuild, so shaders added to or removed from it are picked up as well.
+    Files with other extensions and subdirectories are ignored.
+
+    # Example
+    ```ignore
+    // shaders/ contains sprite.vert and sprite.frag
+    let linked = ShaderWatcher::directory("shaders").expect("Unable to watch shaders").link().expect("Unable to link shaders");
+    ```
+    */
+    pub fn directory<P: AsRef<std::path::Path>>(directory: P) -> Result<ShaderWatcher, ReloadError> {
+        let directory = canonical_directory(directory.as_ref(), directory.as_ref())?;
+
+        ShaderWatcher::watch(Vec::new(), Vec::new(), Some(directory.clone()), &[directory])
+    }
+
+    /// Starts watching the passed directories
+    fn watch(
+        sources: Vec<(ShaderType, std::path::PathBuf)>,
+        watched: Vec<std::path::PathBuf>,
+        directory: Option<std::path::PathBuf>,
+        directories: &[std::path::PathBuf],
+    ) -> Result<ShaderWatcher, ReloadError> {
+        use notify::Watcher;
+
+        let (sender, events) = std::sync::mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        for directory in directories {
+            watcher.watch(directory, notify::RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(ShaderWatcher {
+            sources,
+            watched,
+            directory,
+            on_error: Default::default(),
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Sets a callback that's passed every error that occurs when the linked program is reloaded via
+    /// [reload_if_changed](LinkedProgram::reload_if_changed), e.g. to log it or display it in an overlay.
+    /// The error is still returned by `reload_if_changed` afterwards.
+    pub fn on_error<F: FnMut(&ReloadError) + 'static>(&mut self, callback: F) -> &mut Self {
+        *self.on_error.borrow_mut() = Some(Box::new(callback));
+        self
+    }
+
+    /// Passes the error to the callback set via [on_error](ShaderWatcher::on_error), if any, and returns it
+    pub(crate) fn report(&self, error: ReloadError) -> ReloadError {
+        if let Some(callback) = self.on_error.borrow_mut().as_mut() {
+            callback(&error);
+        }
+
+        error
+    }
+
+    /// Compiles and links the watched sources into a program that can be reloaded via
+    /// [reload_if_changed](LinkedProgram::reload_if_changed)
+    pub fn link(self) -> Result<LinkedProgram, ReloadError> {
+        let mut linked = self.build()?;
+        linked.set_watcher(self);
+
+        Ok(linked)
+    }
+
+    /// Returns whether or not any of the watched files changed since the last call, consuming all pending events
+    pub(crate) fn has_changed(&self) -> Result<bool, ReloadError> {
+        let mut changed = false;
+
+        for event in self.events.try_iter() {
+            let event = event?;
+            // removing one of the shaders of a watched directory changes the program as well
+            let relevant = event.kind.is_modify() || event.kind.is_create() || (self.directory.is_some() && event.kind.is_remove());
+            changed |= relevant && event.paths.iter().any(|path| self.is_source(path));
+        }
+
+        Ok(changed)
+    }
+
+    /// Returns whether or not the passed canonical path is one of the program's sources
+    fn is_source(&self, path: &std::path::Path) -> bool {
+        match &self.directory {
+            Some(directory) => path.parent() == Some(directory.as_path()) && ShaderType::from_path(path).is_some(),
+            None => self.watched.contains(&path.to_path_buf()),
+        }
+    }
+
+    /// Compiles and links the watched sources into a new program
+    pub(crate) fn build(&self) -> Result<LinkedProgram, ReloadError> {
+        let sources = match &self.directory {
+            Some(directory) => scan_directory(directory)?,
+            None => self.sources.clone(),
+        };
+
+        let shaders = sources
+            .iter()
+            .map(|(r#type, path)| Ok(Shader::new(*r#type)?.compile_from_path(path)?))
+            .collect::<Result<Vec<_>, ReloadError>>()?;
+
+        Ok(ShaderProgram::new()?.link(shaders)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Polls the watcher until it reports a change, since events are delivered asynchronously
+    fn wait_for_change(watcher: &ShaderWatcher) -> bool {
+        for _ in 0..50 {
+            if watcher.has_changed().unwrap() {
+                // a single save can produce several events, the late ones mustn't leak into the next check
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                watcher.has_changed().unwrap();
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        false
+    }
+
+    #[test]
+    fn detects_writes_and_atomic_saves() {
+        let directory = std::env::temp_dir().join(format!("graphene-hot-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("sprite.frag");
+        std::fs::write(&path, "#version 450 core\n").unwrap();
+
+        let watcher = ShaderWatcher::new([(ShaderType::FragmentShader, &path)]).unwrap();
+        assert!(!watcher.has_changed().unwrap());
+
+        std::fs::write(&path, "#version 450 core\nvoid main() {}\n").unwrap();
+        assert!(wait_for_change(&watcher));
+
+        // a rename over the source replaces its inode, which must not end the watch
+        for _ in 0..2 {
+            let temporary = directory.join("sprite.frag.tmp");
+            std::fs::write(&temporary, "#version 450 core\nvoid main() {}\n").unwrap();
+            std::fs::rename(&temporary, &path).unwrap();
+            assert!(wait_for_change(&watcher));
+        }
+
+        // changes to unrelated files in the same directory are ignored
+        std::fs::write(directory.join("unrelated.txt"), "").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!watcher.has_changed().unwrap());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn directories_are_scanned_for_shaders() {
+        let directory = std::env::temp_dir().join(format!("graphene-hot-reload-scan-{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("nested.frag")).unwrap();
+        std::fs::write(directory.join("sprite.vert"), "").unwrap();
+        std::fs::write(directory.join("sprite.frag"), "").unwrap();
+        std::fs::write(directory.join("notes.txt"), "").unwrap();
+
+        let sources = scan_directory(&directory).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        // subdirectories are skipped even if their name looks like a shader
+        assert_eq!(
+            sources,
+            [
+                (ShaderType::FragmentShader, directory.join("sprite.frag")),
+                (ShaderType::Vertex, directory.join("sprite.vert")),
+            ]
+        );
+    }
+
+    #[test]
+    fn watched_directories_detect_new_and_changed_shaders() {
+        let directory = std::env::temp_dir().join(format!("graphene-hot-reload-directory-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("sprite.frag"), "#version 450 core\n").unwrap();
+
+        let watcher = ShaderWatcher::directory(&directory).unwrap();
+        assert!(!watcher.has_changed().unwrap());
+
+        std::fs::write(directory.join("sprite.vert"), "#version 450 core\n").unwrap();
+        assert!(wait_for_change(&watcher));
+
+        std::fs::write(directory.join("sprite.frag"), "#version 450 core\nvoid main() {}\n").unwrap();
+        assert!(wait_for_change(&watcher));
+
+        std::fs::write(directory.join("unrelated.txt"), "").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!watcher.has_changed().unwrap());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn errors_are_reported_to_the_callback() {
+        let directory = std::env::temp_dir().join(format!("graphene-hot-reload-callback-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let reported = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut watcher = ShaderWatcher::directory(&directory).unwrap();
+        let sink = reported.clone();
+        watcher.on_error(move |e| sink.borrow_mut().push(e.to_string()));
+
+        let error = watcher.report(ReloadError::Watch {
+            source: notify::Error::generic("broken"),
+        });
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert!(matches!(error, ReloadError::Watch { .. }));
+        assert_eq!(*reported.borrow(), ["Unable to watch the shader sources: broken"]);
+    }
+}
@@ -0,0 +1,313 @@
+/*!
+The opengl mesh module ties a vertex array together with the buffers it sources its vertices and indices from.
+
+Describing the layout of the vertex data and uploading it by hand is error-prone, a [Mesh](Mesh) is built from a
+declared layout via a [MeshBuilder](MeshBuilder) instead and can be drawn with a single call afterwards.
+*/
+
+use super::buffer::{Buffer, BufferCreationError, BufferDataError, BufferTarget, BufferUsage};
+use super::vertex_array::{AttribType, VertexArray, VertexArrayCreationError, VertexAttribError};
+
+gl_enum! {
+    /// The kinds of primitives the vertices of a mesh are assembled into when drawing
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum PrimitiveMode {
+        /// Every three vertices form a separate triangle
+        Triangles = gl::TRIANGLES,
+        /// Every vertex forms a triangle with the two preceding ones
+        TriangleStrip = gl::TRIANGLE_STRIP,
+        /// Every two vertices form a separate line
+        Lines = gl::LINES,
+        /// Every vertex is a separate point
+        Points = gl::POINTS,
+    }
+}
+
+/// A single attribute of a mesh's vertex layout, see [MeshBuilder::attribute](MeshBuilder::attribute)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct VertexAttribute {
+    /// The location of the attribute in the vertex shader
+    pub location: u32,
+    /// The number of components of the attribute, between 1 and 4
+    pub components: i32,
+    /// The type of the components as they are stored in the vertex data
+    pub ty: AttribType,
+}
+
+/// The types that can be used as indices of a mesh
+pub trait IndexType: bytemuck::Pod {
+    /// The OpenGL type matching the rust type
+    const GL_TYPE: gl::types::GLenum;
+}
+
+impl IndexType for u8 {
+    const GL_TYPE: gl::types::GLenum = gl::UNSIGNED_BYTE;
+}
+
+impl IndexType for u16 {
+    const GL_TYPE: gl::types::GLenum = gl::UNSIGNED_SHORT;
+}
+
+impl IndexType for u32 {
+    const GL_TYPE: gl::types::GLenum = gl::UNSIGNED_INT;
+}
+
+/// The indices of a mesh as they were passed to the builder
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct IndexData {
+    bytes: Vec<u8>,
+    count: usize,
+    r#type: gl::types::GLenum,
+}
+
+/// Error enum for the failed building of a mesh
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum MeshBuildError {
+    /// Used if no attributes were declared, which leaves the vertex data without a layout
+    #[error("The mesh's vertex layout doesn't declare any attributes")]
+    EmptyLayout,
+    /// Used if an attribute doesn't have between 1 and 4 components
+    #[error("The attribute at location {location} has {components} components, but has to have between 1 and 4")]
+    InvalidComponents {
+        /// The location of the offending attribute
+        location: u32,
+        /// The attribute's number of components
+        components: i32,
+    },
+    /// Used if the vertex data's length isn't a multiple of the size of a single vertex
+    #[error("The vertex data's length isn't a multiple of the vertex size of {0} bytes")]
+    MisalignedVertices(usize),
+    /// Used if the mesh has more vertices or indices than OpenGL can draw in a single call
+    #[error("The mesh has too many vertices or indices to be drawn")]
+    TooLarge,
+    /// Used if the vertex array couldn't be created
+    #[error("Unable to create the vertex array: {source}")]
+    VertexArrayCreation {
+        #[from]
+        /// The underlying creation error
+        source: VertexArrayCreationError,
+    },
+    /// Used if one of the buffers couldn't be created
+    #[error("Unable to create a buffer: {source}")]
+    BufferCreation {
+        #[from]
+        /// The underlying creation error
+        source: BufferCreationError,
+    },
+    /// Used if the vertex or index data couldn't be uploaded
+    #[error("Unable to upload the mesh's data: {source}")]
+    BufferData {
+        #[from]
+        /// The underlying upload error
+        source: BufferDataError,
+    },
+    /// Used if one of the attributes couldn't be described to the vertex array
+    #[error("Unable to describe a vertex attribute: {source}")]
+    VertexAttrib {
+        #[from]
+        /// The underlying description error
+        source: VertexAttribError,
+    },
+}
+
+/**
+Builds a [Mesh](Mesh) from a vertex layout, interleaved vertex data and optional indices.
+
+The attributes are expected to be tightly packed within each vertex in the order they were declared in.
+
+# Example
+```ignore
+let (vertices, indices) = alloy_graphene::geometry::quad();
+
+let mut builder = MeshBuilder::new();
+builder
+    .attribute(VertexAttribute { location: 0, components: 2, ty: AttribType::Float })
+    .attribute(VertexAttribute { location: 1, components: 2, ty: AttribType::Float })
+    .vertices(&vertices)
+    .indices(&indices);
+
+let mesh = builder.build().expect("Unable to build mesh");
+mesh.draw(PrimitiveMode::Triangles);
+```
+*/
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct MeshBuilder {
+    attributes: Vec<VertexAttribute>,
+    vertices: Vec<u8>,
+    indices: Option<IndexData>,
+}
+
+impl MeshBuilder {
+    /// Creates a builder without any attributes, vertices or indices
+    pub fn new() -> MeshBuilder {
+        MeshBuilder::default()
+    }
+
+    /// Appends an attribute to the vertex layout, following all previously declared ones within each vertex
+    pub fn attribute(&mut self, attribute: VertexAttribute) -> &mut MeshBuilder {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Sets the interleaved vertex data, replacing any previously set data
+    pub fn vertices<T: bytemuck::Pod>(&mut self, vertices: &[T]) -> &mut MeshBuilder {
+        self.vertices = bytemuck::cast_slice(vertices).to_vec();
+        self
+    }
+
+    /// Sets the indices the vertices are drawn in, replacing any previously set ones.
+    /// Meshes without indices draw their vertices in order.
+    pub fn indices<T: IndexType>(&mut self, indices: &[T]) -> &mut MeshBuilder {
+        self.indices = Some(IndexData {
+            bytes: bytemuck::cast_slice(indices).to_vec(),
+            count: indices.len(),
+            r#type: T::GL_TYPE,
+        });
+        self
+    }
+
+    /// Creates the vertex array and buffers of the mesh and uploads its data
+    pub fn build(self) -> Result<Mesh, MeshBuildError> {
+        if let Some(attribute) = self.attributes.iter().find(|attribute| !(1..=4).contains(&attribute.components)) {
+            return Err(MeshBuildError::InvalidComponents {
+                location: attribute.location,
+                components: attribute.components,
+            });
+        }
+
+        let stride = self
+            .attributes
+            .iter()
+            .map(|attribute| attribute.components as usize * attribute.ty.size_in_bytes())
+            .sum::<usize>();
+
+        if stride == 0 {
+            return Err(MeshBuildError::EmptyLayout);
+        }
+
+        if !self.vertices.len().is_multiple_of(stride) {
+            return Err(MeshBuildError::MisalignedVertices(stride));
+        }
+
+        let vertex_count = i32::try_from(self.vertices.len() / stride).map_err(|_| MeshBuildError::TooLarge)?;
+        let gl_stride = i32::try_from(stride).map_err(|_| MeshBuildError::TooLarge)?;
+
+        let vertex_array = VertexArray::new()?;
+        vertex_array.bind();
+
+        let vertex_buffer = Buffer::new(BufferTarget::Array)?;
+        vertex_buffer.data(&self.vertices, BufferUsage::StaticDraw)?;
+
+        let mut offset = 0;
+        for attribute in &self.attributes {
+            vertex_array.vertex_attrib_pointer(attribute.location, attribute.components, attribute.ty, false, gl_stride, offset)?;
+            offset += attribute.components as usize * attribute.ty.size_in_bytes();
+        }
+
+        // the element array binding is part of the vertex array's state, so the buffer has to be bound while it is bound
+        let index_buffer = match &self.indices {
+            Some(indices) => {
+                let buffer = Buffer::new(BufferTarget::ElementArray)?;
+                buffer.data(&indices.bytes, BufferUsage::StaticDraw)?;
+
+                let count = i32::try_from(indices.count).map_err(|_| MeshBuildError::TooLarge)?;
+                Some((buffer, count, indices.r#type))
+            }
+            None => None,
+        };
+
+        vertex_array.unbind();
+
+        Ok(Mesh {
+            vertex_array,
+            vertex_buffer,
+            index_buffer,
+            vertex_count,
+        })
+    }
+}
+
+/// A vertex array alongside the buffers it sources its vertices and indices from, see [MeshBuilder](MeshBuilder)
+#[derive(Debug)]
+pub struct Mesh {
+    vertex_array: VertexArray,
+    vertex_buffer: Buffer,
+    index_buffer: Option<(Buffer, gl::types::GLsizei, gl::types::GLenum)>,
+    vertex_count: gl::types::GLsizei,
+}
+
+impl Mesh {
+    /// Returns the vertex array describing the mesh's vertex layout
+    pub fn get_vertex_array(&self) -> &VertexArray {
+        &self.vertex_array
+    }
+
+    /// Returns the buffer storing the mesh's vertices
+    pub fn get_vertex_buffer(&self) -> &Buffer {
+        &self.vertex_buffer
+    }
+
+    /// Returns the buffer storing the mesh's indices if it has any
+    pub fn get_index_buffer(&self) -> Option<&Buffer> {
+        self.index_buffer.as_ref().map(|(buffer, _, _)| buffer)
+    }
+
+    /// Binds the mesh's vertex array and draws the mesh using the currently bound program.
+    /// The vertex array stays bound afterwards.
+    pub fn draw(&self, mode: PrimitiveMode) {
+        self.vertex_array.bind();
+
+        unsafe {
+            match &self.index_buffer {
+                Some((_, count, r#type)) => gl_call!(gl::DrawElements(mode.as_glenum(), *count, *r#type, std::ptr::null())),
+                None => gl_call!(gl::DrawArrays(mode.as_glenum(), 0, self.vertex_count)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns an attribute of `components` floats at the passed location
+    fn floats(location: u32, components: i32) -> VertexAttribute {
+        VertexAttribute {
+            location,
+            components,
+            ty: AttribType::Float,
+        }
+    }
+
+    #[test]
+    fn layouts_without_attributes_are_rejected() {
+        let mut builder = MeshBuilder::new();
+        builder.vertices(&[0.0f32; 4]);
+
+        assert_eq!(builder.build().err(), Some(MeshBuildError::EmptyLayout));
+    }
+
+    #[test]
+    fn vertex_data_has_to_be_a_multiple_of_the_vertex_size() {
+        let mut builder = MeshBuilder::new();
+        builder.attribute(floats(0, 2)).attribute(floats(1, 3)).vertices(&[0.0f32; 6]);
+
+        assert_eq!(builder.build().err(), Some(MeshBuildError::MisalignedVertices(20)));
+    }
+
+    #[test]
+    fn attributes_need_between_one_and_four_components() {
+        for components in [-1, 0, 5] {
+            let mut builder = MeshBuilder::new();
+            builder
+                .attribute(floats(0, 2))
+                .attribute(floats(1, components))
+                .vertices(&[0.0f32; 4]);
+
+            assert_eq!(
+                builder.build().err(),
+                Some(MeshBuildError::InvalidComponents { location: 1, components })
+            );
+        }
+    }
+}
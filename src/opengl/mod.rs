@@ -1 +1,373 @@
+/**
+Generates an enum wrapping a set of OpenGL enum values.
+
+Next to the enum itself this generates an `as_glenum` method and a `TryFrom<GLenum>` implementation, so every typed
+enum can be round-tripped from and to the raw value, e.g. when reading back state from OpenGL.
+*/
+macro_rules! gl_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        // sadly you cannot use repr with type aliases, so we cannot use repr(gl::types::GLenum)
+        // I doubt the OpenGL type for GLenum will ever change, as_glenum/try_from are the only ways to convert anyway
+        #[repr(u32)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $value,
+            )*
+        }
+
+        impl $name {
+            /// Returns the raw OpenGL enum value
+            pub fn as_glenum(self) -> gl::types::GLenum {
+                self as gl::types::GLenum
+            }
+        }
+
+        impl TryFrom<gl::types::GLenum> for $name {
+            type Error = $crate::opengl::InvalidEnumValue;
+
+            fn try_from(value: gl::types::GLenum) -> Result<Self, Self::Error> {
+                match value {
+                    $(v if v == $value => Ok($name::$variant),)*
+                    _ => Err($crate::opengl::InvalidEnumValue {
+                        value,
+                        r#type: stringify!($name),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+/**
+Wraps a call into OpenGL whose error state isn't checked by graphene afterwards.
+
+Usually this is the case for queries that can only fail because of a bug, either in graphene or in the driver.
+With the `strict-errors` feature enabled `glGetError` is called right after the wrapped call and any error panics,
+naming the offending OpenGL function and where graphene called it. This catches errors where they occur rather than
+at the next checked call, at the cost of an additional round-trip to the driver for every wrapped call.
+
+The strict check panics instead of returning the error since the wrapped calls are exactly those without an error type
+to return it through. Errors raised while the thread is already panicking, e.g. by the calls in `Drop` impls while
+unwinding, are only logged, since panicking again would abort the process.
+
+Calls whose errors graphene maps into its own error types must not be wrapped, since the strict check would consume
+the error before it can be mapped.
+*/
+macro_rules! gl_call {
+    ($call:expr) => {{
+        let result = $call;
+        #[cfg(feature = "strict-errors")]
+        $crate::opengl::strict_check(stringify!($call), concat!(module_path!(), ":", line!()));
+        result
+    }};
+}
+
+/// Returns the name of the OpenGL function a stringified call invokes, e.g. `gl::BindBuffer` for `gl::BindBuffer(target, id)`
+#[cfg(any(test, feature = "strict-errors"))]
+fn called_function(call: &str) -> &str {
+    call.split_once('(').map_or(call, |(function, _)| function.trim())
+}
+
+/**
+Panics if OpenGL reports an error, used by [gl_call](gl_call) if the `strict-errors` feature is enabled.
+
+The panic names the OpenGL function and graphene's call site of it rather than the caller's code, which is only part of the
+backtrace since graphene's public functions don't forward their callers' locations.
+*/
+#[cfg(feature = "strict-errors")]
+pub(crate) fn strict_check(call: &'static str, location: &'static str) {
+    report_strict_error(get_error(), call, location);
+}
+
+/// Logs the error reported after the call and panics unless the thread is already panicking, see [strict_check]
+#[cfg(feature = "strict-errors")]
+fn report_strict_error(rc: gl::types::GLenum, call: &str, location: &str) {
+    if rc == gl::NO_ERROR {
+        return;
+    }
+
+    let function = called_function(call);
+    log::error!("{} raised the OpenGL error {:#06x} in {}: {}", function, rc, location, call);
+
+    if !std::thread::panicking() {
+        panic!(
+            "Strict error check failed, {} raised the OpenGL error {:#06x} in {}, run with RUST_BACKTRACE=1 to find the calling code",
+            function, rc, location
+        );
+    }
+}
+
+thread_local! {
+    static NO_ERROR_CONTEXT: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Marks the context current on this thread as a GL_KHR_no_error context, see [context::detect_no_error_context](context::detect_no_error_context)
+pub(crate) fn set_no_error_context(no_error: bool) {
+    NO_ERROR_CONTEXT.with(|c| c.set(no_error));
+}
+
+/**
+Returns the current OpenGL error, every error check in graphene goes through this.
+
+In a GL_KHR_no_error context glGetError always reports GL_NO_ERROR, so the round-trip to the driver is skipped
+and GL_NO_ERROR is returned right away.
+*/
+pub(crate) fn get_error() -> gl::types::GLenum {
+    if NO_ERROR_CONTEXT.with(|c| c.get()) {
+        return gl::NO_ERROR;
+    }
+
+    unsafe { gl::GetError() }
+}
+
+thread_local! {
+    static CURRENT_CONTEXT: std::cell::Cell<Option<u64>> = const { std::cell::Cell::new(None) };
+}
+
+/// Sets the id of the context current on this thread, see [context::set_current_context](context::set_current_context)
+pub(crate) fn set_current_context_id(id: Option<u64>) {
+    CURRENT_CONTEXT.with(|c| c.set(id));
+}
+
+/// Returns the id of the context current on this thread if one was set
+pub(crate) fn current_context_id() -> Option<u64> {
+    CURRENT_CONTEXT.with(|c| c.get())
+}
+
+/**
+Implemented by all of graphene's wrappers around OpenGL objects.
+
+Contexts that share their resources only share some kinds of objects. Buffers, textures, renderbuffers, shaders and
+programs are shared, whereas container objects such as vertex arrays and framebuffers are not.
+Using an object that isn't shareable on a context other than the one it was created on is a bug that OpenGL doesn't
+necessarily report, graphene logs a warning in debug builds if it detects that.
+
+# Example
+```
+use alloy_graphene::opengl::GlObject;
+use alloy_graphene::opengl::buffer::Buffer;
+use alloy_graphene::opengl::vertex_array::VertexArray;
+
+assert!(Buffer::is_shareable());
+assert!(!VertexArray::is_shareable());
+```
+*/
+pub trait GlObject {
+    /// Whether or not objects of this type are shared between contexts that share their resources
+    const SHAREABLE: bool;
+
+    /// Returns whether or not objects of this type are shared between contexts that share their resources
+    fn is_shareable() -> bool {
+        Self::SHAREABLE
+    }
+}
+
+/// Warns in debug builds if an object that isn't shareable is used on a context other than the one it was created on.
+/// Objects are only checked if both contexts are known, see [context::set_current_context](context::set_current_context)
+pub(crate) fn check_context<T: GlObject>(created_on: Option<u64>, id: gl::types::GLuint) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    if let (Some(created_on), Some(current)) = (created_on, current_context_id()) {
+        if crosses_context(created_on, current, T::SHAREABLE) {
+            log::warn!(
+                "{} {} was created on context {} but is used on context {}, it isn't shared between contexts",
+                std::any::type_name::<T>(),
+                id,
+                created_on,
+                current
+            );
+        }
+    }
+}
+
+/// Returns whether or not using an object created on `created_on` on the `current` context is a bug
+fn crosses_context(created_on: u64, current: u64, shareable: bool) -> bool {
+    !shareable && created_on != current
+}
+
+/**
+Error struct for the failed conversion of a raw OpenGL enum value into one of graphene's typed enums.
+
+# Example
+```
+use alloy_graphene::opengl::shader::ShaderType;
+
+let raw = ShaderType::Vertex.as_glenum();
+assert_eq!(ShaderType::try_from(raw), Ok(ShaderType::Vertex));
+assert!(ShaderType::try_from(0).is_err());
+```
+*/
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[error("{value:#06x} is not a valid value for {r#type}")]
+pub struct InvalidEnumValue {
+    /// The raw value that couldn't be converted
+    pub value: gl::types::GLenum,
+    /// The name of the enum the value was supposed to be converted to
+    pub r#type: &'static str,
+}
+
+pub mod batch;
+pub mod blend;
+pub mod buffer;
+pub mod clear;
+pub mod context;
+pub mod font;
+pub mod framebuffer;
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+pub mod mesh;
+pub mod program;
+pub mod renderbuffer;
+pub mod resource;
 pub mod shader;
+pub mod state;
+pub mod texture;
+pub mod vertex_array;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_error_context_skips_error_checks() {
+        // glGetError isn't loaded without a context, so reaching it would panic
+        context::set_no_error_context(true);
+        assert_eq!(get_error(), gl::NO_ERROR);
+        context::set_no_error_context(false);
+    }
+
+    #[test]
+    fn vertex_array_used_on_another_context_crosses_it() {
+        assert!(crosses_context(1, 2, vertex_array::VertexArray::SHAREABLE));
+        assert!(crosses_context(1, 2, framebuffer::Framebuffer::SHAREABLE));
+    }
+
+    #[test]
+    fn shareable_objects_never_cross_contexts() {
+        assert!(!crosses_context(1, 2, buffer::Buffer::SHAREABLE));
+    }
+
+    #[test]
+    fn same_context_doesnt_cross() {
+        assert!(!crosses_context(1, 1, vertex_array::VertexArray::SHAREABLE));
+    }
+
+    #[test]
+    fn called_functions_are_named_without_their_arguments() {
+        assert_eq!(
+            called_function("gl::BindBuffer(self.target.as_glenum(), self.get_id())"),
+            "gl::BindBuffer"
+        );
+        assert_eq!(called_function("gl::Finish()"), "gl::Finish");
+        assert_eq!(called_function("gl :: Flush ()"), "gl :: Flush");
+        assert_eq!(called_function("unwrapped"), "unwrapped");
+    }
+
+    #[test]
+    #[cfg(feature = "strict-errors")]
+    #[should_panic(expected = "gl::BindBuffer raised the OpenGL error 0x0502 in graphene::buffer:12")]
+    fn strict_errors_panic() {
+        report_strict_error(gl::INVALID_OPERATION, "gl::BindBuffer(target, id)", "graphene::buffer:12");
+    }
+
+    #[test]
+    #[cfg(feature = "strict-errors")]
+    fn strict_errors_dont_panic_while_unwinding() {
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                // panicking again here would abort the test binary
+                report_strict_error(gl::INVALID_OPERATION, "gl::DeleteBuffers(1, &id)", "graphene::buffer:34");
+            }
+        }
+
+        let result = std::panic::catch_unwind(|| {
+            let _guard = Guard;
+            panic!("unwinding");
+        });
+
+        assert!(result.is_err());
+        report_strict_error(gl::NO_ERROR, "gl::Finish()", "graphene::buffer:56");
+    }
+
+    /// Asserts that converting each variant into its OpenGL value and back yields the variant again
+    fn assert_round_trips<T>(variants: &[T], as_glenum: fn(T) -> gl::types::GLenum)
+    where
+        T: Copy + std::fmt::Debug + PartialEq + TryFrom<gl::types::GLenum, Error = InvalidEnumValue>,
+    {
+        for &variant in variants {
+            assert_eq!(T::try_from(as_glenum(variant)), Ok(variant));
+        }
+    }
+
+    #[test]
+    fn gl_enums_round_trip() {
+        use state::{ClampColor, CompareFunc};
+
+        assert_round_trips(
+            &[
+                CompareFunc::Never,
+                CompareFunc::Less,
+                CompareFunc::Equal,
+                CompareFunc::LessEqual,
+                CompareFunc::Greater,
+                CompareFunc::NotEqual,
+                CompareFunc::GreaterEqual,
+                CompareFunc::Always,
+            ],
+            CompareFunc::as_glenum,
+        );
+        assert_round_trips(
+            &[
+                blend::BlendFactor::Zero,
+                blend::BlendFactor::One,
+                blend::BlendFactor::SrcAlpha,
+                blend::BlendFactor::OneMinusSrcAlpha,
+                blend::BlendFactor::SrcAlphaSaturate,
+            ],
+            blend::BlendFactor::as_glenum,
+        );
+        assert_round_trips(
+            &[texture::TextureWrap::Repeat, texture::TextureWrap::ClampToEdge],
+            texture::TextureWrap::as_glenum,
+        );
+        // Enabled and Disabled are GL_TRUE and GL_FALSE rather than dedicated enum values
+        assert_round_trips(
+            &[ClampColor::Enabled, ClampColor::Disabled, ClampColor::FixedOnly],
+            ClampColor::as_glenum,
+        );
+        assert_round_trips(
+            &[
+                renderbuffer::RenderbufferFormat::Depth24Stencil8,
+                renderbuffer::RenderbufferFormat::Depth32F,
+                renderbuffer::RenderbufferFormat::Stencil8,
+            ],
+            renderbuffer::RenderbufferFormat::as_glenum,
+        );
+    }
+
+    #[test]
+    fn invalid_gl_enum_values_are_rejected() {
+        assert_eq!(
+            texture::TextureWrap::try_from(gl::NEAREST),
+            Err(InvalidEnumValue {
+                value: gl::NEAREST,
+                r#type: "TextureWrap"
+            })
+        );
+        assert!(state::CompareFunc::try_from(gl::TRUE as gl::types::GLenum).is_err());
+    }
+}
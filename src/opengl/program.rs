@@ -0,0 +1,2585 @@
+/*!
+The opengl program module provides idiomatic bindings to OpenGL shader programs.
+
+A shader program combines compiled shaders of the different pipeline stages into one executable unit.
+
+In our scenario OpenGL programs go through a few stages:
+1. Creation
+2. Attachment of compiled shaders and linking
+3. Binding
+
+Analogous to shaders we model this by providing both a ShaderProgram and a LinkedProgram type which allows compiletime checking for
+whether or not a program was linked successfully before it is bound.
+*/
+
+use super::shader::{
+    check_geometry_limits, lint_early_fragment_tests, CompiledShader, GeometryLimitError, Shader, ShaderCompileError,
+    ShaderCompileFromPathError, ShaderCreationError, ShaderType,
+};
+use super::vertex_array::VertexArray;
+
+/// Stores the underlying data of a shader program
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [ShaderProgram](ShaderProgram) struct.
+#[derive(Debug)]
+pub struct ShaderProgramInner {
+    /// The id of the program, generated by OpenGL and valid for the lifetime of the program
+    pub id: gl::types::GLuint,
+}
+
+/// A copy of the publicly relevant data of a program,
+/// see [ShaderProgram::snapshot](ShaderProgram::snapshot) and [LinkedProgram::snapshot](LinkedProgram::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ProgramSnapshot {
+    /// The id of the program, generated by OpenGL. Only valid as long as the program it was taken from is alive
+    pub id: gl::types::GLuint,
+}
+
+impl PartialEq for ShaderProgramInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for ShaderProgramInner {}
+
+impl std::hash::Hash for ShaderProgramInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for programs to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for ShaderProgramInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ShaderProgramInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A shader program combines multiple compiled shaders into one executable that runs on the GPU.
+
+For our purposes a program has to be created and then linked with the compiled shaders of all stages it uses.
+We model this with the newtype pattern by having both a ShaderProgram class and a [LinkedProgram](LinkedProgram) class.
+
+# Example
+```ignore
+let vertex = Shader::new(ShaderType::Vertex)?.compile(vertex_src)?;
+let fragment = Shader::new(ShaderType::FragmentShader)?.compile(fragment_src)?;
+let program = ShaderProgram::new().expect("Unable to create shader program");
+let linked = program.link([vertex, fragment]).expect("Unable to link shader program");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShaderProgram {
+    inner: ShaderProgramInner,
+}
+
+impl std::fmt::Debug for ShaderProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShaderProgram").field("id", &self.inner.id).finish()
+    }
+}
+
+impl ShaderProgram {
+    /// Returns a reference to the inner (private) data of the program.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &ShaderProgramInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the program.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut ShaderProgramInner {
+        &mut self.inner
+    }
+
+    /**
+    Retrieves the id of the program.
+
+    # Example
+    ```ignore
+    let program = ShaderProgram::new().expect("Unable to create shader program");
+    assert_eq!(program.get_id(), 1); // example, YMMV
+    ```
+    */
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the program's id, which is the safe alternative to [inner](ShaderProgram::inner) for reading.
+    pub fn snapshot(&self) -> ProgramSnapshot {
+        ProgramSnapshot { id: self.inner.id }
+    }
+}
+
+impl super::GlObject for ShaderProgram {
+    const SHAREABLE: bool = true;
+}
+
+// A linked program owns the program it was linked from, so dropping it deletes the program exactly once through this impl
+impl Drop for ShaderProgram {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteProgram(self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a shader program
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ProgramCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl ShaderProgram {
+    /// Returns a new shader program or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+    pub fn new() -> Result<ShaderProgram, ProgramCreationError> {
+        let id = unsafe { gl::CreateProgram() };
+
+        if id == 0 {
+            return Err(ProgramCreationError::Unknown);
+        }
+
+        Ok(ShaderProgram {
+            inner: ShaderProgramInner { id },
+        })
+    }
+}
+
+/// Error enum for the failed linkage of a shader program
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ProgramLinkError {
+    /// Used if either the program or one of the shaders was not created by OpenGL
+    #[error("The underlying object was not created by OpenGL")]
+    NotAnOpenGLValue,
+    /// Used if the underlying object was not recognised as an OpenGL program
+    #[error("The underlying object was not recognised as an OpenGL program")]
+    NotAProgram,
+    /// Used if a shader couldn't be attached to the program, which happens if it was already attached to it
+    #[error("The shader {0} couldn't be attached to the program")]
+    AttachmentFailed(gl::types::GLuint),
+    /// Used if the program couldn't be linked, e.g. because the interfaces of its stages didn't match
+    #[error("Unable to link program: {0}")]
+    LinkError(String),
+    /// Used if the program couldn't be linked and the error message obtained couldn't be converted to a rust string
+    /// because it was missing a null byte at the end
+    #[error("Program log's error message didn't contain a null byte at the end")]
+    MissingNullByte {
+        #[from]
+        /// The underlying c-string conversion error
+        source: std::ffi::FromVecWithNulError,
+    },
+    /// Used if the program couldn't be linked and the error message obtained couldn't be converted to a rust string
+    /// because it was invalid UTF8
+    #[error("Program log's error message wasn't valid UTF8")]
+    InvalidUTF8LogSource {
+        #[from]
+        /// The underlying c-string conversion error
+        source: std::ffi::IntoStringError,
+    },
+    /// Used if a geometry shader declares more output than the driver supports, see
+    /// [check_geometry_limits](super::shader::check_geometry_limits)
+    #[error("Geometry shader {shader} exceeds the driver's output limits: {source}")]
+    GeometryLimit {
+        /// The id of the offending geometry shader
+        shader: gl::types::GLuint,
+        /// The exceeded limit
+        source: GeometryLimitError,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl ShaderProgram {
+    /// Attaches all passed shaders, links the program and returns a [LinkedProgram](LinkedProgram) that wraps the current object
+    /// or returns an error if the operation fails.
+    /// Failure is realistic in this situation and can happen in a variety of cases:
+    /// 1. The shaders' interfaces don't match or a required stage is missing
+    /// 2. The program is in an invalid state
+    /// 3. An underlying driver issue occurred
+    ///
+    /// The shaders are detached again once linking is done since the program doesn't need them anymore, which allows
+    /// OpenGL to free them. Use [link_returning](ShaderProgram::link_returning) to keep them around, e.g. to link them
+    /// into another program.
+    pub fn link<I: IntoIterator<Item = CompiledShader>>(self, shaders: I) -> Result<LinkedProgram, ProgramLinkError> {
+        self.link_returning(shaders).map(|(linked, _)| linked)
+    }
+
+    /**
+    Links the program like [link](ShaderProgram::link), but returns the detached shaders alongside the linked program
+    instead of dropping them.
+
+    # Example
+    ```ignore
+    let (sprites, shaders) = ShaderProgram::new()
+        .expect("Unable to create shader program")
+        .link_returning([vertex, fragment])
+        .expect("Unable to link shader program");
+    let outlines = ShaderProgram::new()
+        .expect("Unable to create shader program")
+        .link(shaders)
+        .expect("Unable to link shader program");
+    ```
+    */
+    pub fn link_returning<I: IntoIterator<Item = CompiledShader>>(
+        self,
+        shaders: I,
+    ) -> Result<(LinkedProgram, Vec<CompiledShader>), ProgramLinkError> {
+        let shaders = shaders.into_iter().collect::<Vec<_>>();
+
+        if log::log_enabled!(log::Level::Warn) {
+            lint_fragment_shaders(&shaders);
+        }
+
+        check_geometry_shaders(&shaders)?;
+
+        for shader in &shaders {
+            let rc = unsafe {
+                gl::AttachShader(self.inner.id, shader.get_id());
+                super::get_error()
+            };
+
+            match rc {
+                gl::NO_ERROR => {}
+                gl::INVALID_VALUE => return Err(ProgramLinkError::NotAnOpenGLValue),
+                gl::INVALID_OPERATION => return Err(ProgramLinkError::AttachmentFailed(shader.get_id())),
+                _ => return Err(ProgramLinkError::Unknown),
+            }
+        }
+
+        let rc = unsafe {
+            gl::LinkProgram(self.inner.id);
+            super::get_error()
+        };
+
+        for shader in &shaders {
+            unsafe { gl_call!(gl::DetachShader(self.inner.id, shader.get_id())) };
+        }
+
+        match rc {
+            gl::NO_ERROR => {}
+            gl::INVALID_VALUE => return Err(ProgramLinkError::NotAnOpenGLValue),
+            gl::INVALID_OPERATION => return Err(ProgramLinkError::NotAProgram),
+            _ => return Err(ProgramLinkError::Unknown),
+        }
+
+        let mut link_status = 0;
+        unsafe {
+            gl_call!(gl::GetProgramiv(self.inner.id, gl::LINK_STATUS, &mut link_status));
+        }
+
+        if link_status == 0 {
+            let s = program_info_log(self.inner.id)?;
+
+            return Err(ProgramLinkError::LinkError(s));
+        }
+
+        let linked = LinkedProgram {
+            inner: LinkedProgramInner {
+                program: self,
+                uniform_locations: Default::default(),
+                #[cfg(feature = "hot-reload")]
+                watcher: None,
+            },
+        };
+
+        Ok((linked, shaders))
+    }
+}
+
+/// Reads the info log of the program with the passed id, an empty log is returned as an empty string
+fn program_info_log(id: gl::types::GLuint) -> Result<String, ProgramLinkError> {
+    let mut length = 0;
+    unsafe { gl_call!(gl::GetProgramiv(id, gl::INFO_LOG_LENGTH, &mut length)) };
+
+    // the reported length includes the nul-byte, an empty log is reported as 0 though
+    if length <= 0 {
+        return Ok(String::new());
+    }
+
+    let capacity = length as usize;
+    let mut log = Vec::<u8>::with_capacity(capacity);
+    let mut written = 0;
+
+    unsafe {
+        gl_call!(gl::GetProgramInfoLog(
+            id,
+            capacity as i32,
+            &mut written,
+            log.as_mut_ptr() as *mut i8
+        ));
+        log.set_len(((written + 1) as usize).min(capacity));
+    }
+
+    Ok(std::ffi::CString::from_vec_with_nul(log)?.into_string()?)
+}
+
+/// The shader and program parameter of GL_ARB_parallel_shader_compile, which isn't part of the OpenGL 4.5 core bindings
+const COMPLETION_STATUS_ARB: gl::types::GLenum = 0x91B1;
+
+/// Error enum for the failed parallel compilation and linkage of a shader program
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ParallelBuildError {
+    /// Used if one of the shaders couldn't be created
+    #[error("Unable to create shader: {source}")]
+    ShaderCreation {
+        #[from]
+        /// The underlying creation error
+        source: ShaderCreationError,
+    },
+    /// Used if one of the shaders couldn't be compiled
+    #[error("Unable to compile shader: {source}")]
+    Compile {
+        #[from]
+        /// The underlying compilation error
+        source: ShaderCompileError,
+    },
+    /// Used if the shaders didn't finish compiling before the timeout elapsed
+    #[error("The shaders didn't finish compiling within {0:?}")]
+    TimedOut(std::time::Duration),
+    /// Used if the program couldn't be linked
+    #[error("Unable to link program: {source}")]
+    Link {
+        #[from]
+        /// The underlying link error
+        source: ProgramLinkError,
+    },
+}
+
+/// Returns whether or not the current context compiles shaders in the background
+fn supports_parallel_compile() -> bool {
+    // a context whose extensions can't be queried is treated like one without parallel compilation
+    super::context::extensions()
+        .map(|extensions| extensions.contains("GL_ARB_parallel_shader_compile") || extensions.contains("GL_KHR_parallel_shader_compile"))
+        .unwrap_or(false)
+}
+
+impl ShaderProgram {
+    /**
+    Compiles the passed sources as shaders of the types they're paired with and links them like [link](ShaderProgram::link).
+
+    All compilations are started before waiting for any of them, so drivers supporting GL_ARB_parallel_shader_compile
+    compile the stages in the background while graphene polls GL_COMPLETION_STATUS_ARB. Fails with
+    [TimedOut](ParallelBuildError::TimedOut) if they don't finish within `timeout`. Other drivers compile each stage
+    as its result is queried, i.e. sequentially and without a timeout.
+
+    # Example
+    ```ignore
+    let linked = ShaderProgram::new()
+        .expect("Unable to create shader program")
+        .build_parallel(
+            [
+                (ShaderType::Vertex, vertex_src),
+                (ShaderType::GeometryShader, geometry_src),
+                (ShaderType::FragmentShader, fragment_src),
+            ],
+            std::time::Duration::from_secs(5),
+        )
+        .expect("Unable to build shader program");
+    ```
+    */
+    pub fn build_parallel<I, S>(self, stages: I, timeout: std::time::Duration) -> Result<LinkedProgram, ParallelBuildError>
+    where
+        I: IntoIterator<Item = (ShaderType, S)>,
+        S: AsRef<str>,
+    {
+        let start = std::time::Instant::now();
+        let mut pending = Vec::new();
+
+        for (r#type, src) in stages {
+            let mut shader = Shader::new(r#type)?;
+            shader.set_source(src.as_ref())?;
+            shader.start_compile()?;
+            pending.push((shader, src.as_ref().len()));
+        }
+
+        if supports_parallel_compile() {
+            while !pending.iter().all(|(shader, _)| {
+                let mut completed = 0;
+                unsafe { gl_call!(gl::GetShaderiv(shader.get_id(), COMPLETION_STATUS_ARB, &mut completed)) };
+                completed != 0
+            }) {
+                if start.elapsed() >= timeout {
+                    return Err(ParallelBuildError::TimedOut(timeout));
+                }
+
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        }
+
+        let compiled = pending
+            .into_iter()
+            .map(|(shader, source_len)| shader.finish_compile(source_len, start).map(|(compiled, _)| compiled))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self.link(compiled)?)
+    }
+}
+
+/// Error enum for the failed creation of a linked program from shader files
+#[derive(thiserror::Error, Debug)]
+pub enum ProgramFromFilesError {
+    /// Used if the stage of a file couldn't be determined from its extension, see [ShaderType::from_path]
+    #[error("Unable to determine the shader stage of {0} from its extension")]
+    UnknownStage(std::path::PathBuf),
+    /// Used if the shader of a file couldn't be created
+    #[error("Unable to create {stage} shader for {path}: {source}")]
+    ShaderCreation {
+        /// The path of the file the shader was created for
+        path: std::path::PathBuf,
+        /// The stage of the shader
+        stage: ShaderType,
+        /// The underlying creation error
+        source: ShaderCreationError,
+    },
+    /// Used if a file couldn't be read or compiled
+    #[error("Unable to compile {stage} shader {path}: {source}")]
+    Compile {
+        /// The path of the file that failed to compile
+        path: std::path::PathBuf,
+        /// The stage of the shader
+        stage: ShaderType,
+        /// The underlying compilation error
+        source: ShaderCompileFromPathError,
+    },
+    /// Used if the program couldn't be created
+    #[error("Unable to create program: {source}")]
+    ProgramCreation {
+        #[from]
+        /// The underlying creation error
+        source: ProgramCreationError,
+    },
+    /// Used if the program couldn't be linked
+    #[error("Unable to link program: {source}")]
+    Link {
+        #[from]
+        /// The underlying link error
+        source: ProgramLinkError,
+    },
+}
+
+/// Compiles the file at the passed path as a shader of the stage its extension names, see [ShaderType::from_path]
+fn compile_file(path: &std::path::Path) -> Result<CompiledShader, ProgramFromFilesError> {
+    let stage = ShaderType::from_path(path).ok_or_else(|| ProgramFromFilesError::UnknownStage(path.to_owned()))?;
+
+    let shader = Shader::new(stage).map_err(|source| ProgramFromFilesError::ShaderCreation {
+        path: path.to_owned(),
+        stage,
+        source,
+    })?;
+
+    shader.compile_from_path(path).map_err(|source| ProgramFromFilesError::Compile {
+        path: path.to_owned(),
+        stage,
+        source,
+    })
+}
+
+impl LinkedProgram {
+    /**
+    Compiles the shaders stored at the passed paths and links them into a new program.
+
+    The stage of each shader is picked from the extension of its file, see [ShaderType::from_path], and includes are
+    resolved as in [compile_from_path](Shader::compile_from_path). Errors name the file and stage that failed.
+
+    # Example
+    ```ignore
+    let linked = LinkedProgram::from_files("shaders/sprite.vert", "shaders/sprite.frag").expect("Unable to build shader program");
+    ```
+    */
+    pub fn from_files<V, F>(vertex: V, fragment: F) -> Result<LinkedProgram, ProgramFromFilesError>
+    where
+        V: AsRef<std::path::Path>,
+        F: AsRef<std::path::Path>,
+    {
+        let shaders = [compile_file(vertex.as_ref())?, compile_file(fragment.as_ref())?];
+
+        Ok(ShaderProgram::new()?.link(shaders)?)
+    }
+}
+
+/// Logs the early fragment test advisories of all fragment shaders, see [lint_early_fragment_tests]
+fn lint_fragment_shaders(shaders: &[CompiledShader]) {
+    for shader in shaders.iter().filter(|shader| shader.get_type() == ShaderType::FragmentShader) {
+        // the source is only unavailable if the shader is in an invalid state, which linking is going to report
+        if let Ok(src) = shader.get_source() {
+            for advisory in lint_early_fragment_tests(&src) {
+                log::warn!("Fragment shader {}: {}", shader.get_id(), advisory);
+            }
+        }
+    }
+}
+
+/// Checks all geometry shaders against the driver's output limits, see [check_geometry_limits]
+fn check_geometry_shaders(shaders: &[CompiledShader]) -> Result<(), ProgramLinkError> {
+    let mut geometry_shaders = shaders
+        .iter()
+        .filter(|shader| shader.get_type() == ShaderType::GeometryShader)
+        .peekable();
+
+    // the limits are only queried if there's anything to check them against
+    if geometry_shaders.peek().is_none() {
+        return Ok(());
+    }
+
+    let mut max_vertices = 0;
+    let mut max_components = 0;
+    unsafe {
+        gl_call!(gl::GetIntegerv(gl::MAX_GEOMETRY_OUTPUT_VERTICES, &mut max_vertices));
+        gl_call!(gl::GetIntegerv(gl::MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS, &mut max_components));
+    }
+
+    for shader in geometry_shaders {
+        // the source is only unavailable if the shader is in an invalid state, which linking is going to report
+        if let Ok(src) = shader.get_source() {
+            check_geometry_limits(&src, max_vertices.max(0) as u32, max_components.max(0) as u32).map_err(|source| {
+                ProgramLinkError::GeometryLimit {
+                    shader: shader.get_id(),
+                    source,
+                }
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Stores the underlying data of a linked program
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [LinkedProgram](LinkedProgram) struct.
+#[derive(Debug)]
+pub struct LinkedProgramInner {
+    /// The underlying program that is being wrapped after linking
+    pub program: ShaderProgram,
+    /// The uniform locations that were already looked up, -1 for names that weren't found, see [uniform_location](LinkedProgram::uniform_location)
+    pub uniform_locations: std::cell::RefCell<std::collections::HashMap<String, gl::types::GLint>>,
+    /// The watcher the program is reloaded from, see [reload_if_changed](LinkedProgram::reload_if_changed)
+    #[cfg(feature = "hot-reload")]
+    pub watcher: Option<super::hot_reload::ShaderWatcher>,
+}
+
+/**
+A linked program is the second stage that a shader program goes through and the only one that can be used for rendering.
+If linking is successful this struct will be returned and will stand in as the future access point for all interaction with the program.
+
+# Example
+```ignore
+let program = ShaderProgram::new().expect("Unable to create shader program");
+let linked = program.link([vertex, fragment]).expect("Unable to link shader program");
+```
+*/
+pub struct LinkedProgram {
+    inner: LinkedProgramInner,
+}
+
+// the uniform cache and the watcher only clutter the output when logging
+impl std::fmt::Debug for LinkedProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinkedProgram").field("id", &self.get_id()).finish()
+    }
+}
+
+impl super::GlObject for LinkedProgram {
+    const SHAREABLE: bool = true;
+}
+
+impl LinkedProgram {
+    /// Returns a reference to the inner (private) data of the program.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &LinkedProgramInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the program.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut LinkedProgramInner {
+        &mut self.inner
+    }
+
+    /**
+    Retrieves the id of the linked program.
+
+    # Example
+    ```ignore
+    let linked = program.link([vertex, fragment]).expect("Unable to link shader program");
+    assert_eq!(linked.get_id(), 3); // example, YMMV
+    ```
+    */
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.program.inner.id
+    }
+
+    /// Returns a copy of the program's id, which is the safe alternative to [inner](LinkedProgram::inner) for reading.
+    pub fn snapshot(&self) -> ProgramSnapshot {
+        self.inner.program.snapshot()
+    }
+
+    /// Retrieves the info log of the last link, which may contain warnings or performance hints of the driver even
+    /// though linking succeeded. Returns an empty string if the driver didn't log anything.
+    pub fn get_info_log(&self) -> Result<String, ProgramLinkError> {
+        program_info_log(self.get_id())
+    }
+}
+
+impl LinkedProgram {
+    /**
+    Binds the program and returns a guard that restores the previously bound program once it's dropped.
+
+    Setting uniforms without direct state access requires the program to be bound, the guard makes sure this is the case.
+    It dereferences to the program itself so everything available on the program is available on the guard as well.
+    Bindings nest, dropping an inner guard restores the program bound by the outer one.
+
+    # Example
+    ```ignore
+    {
+        let outer = linked.bind();
+        {
+            let inner = other.bind();
+            // draw using the other program
+        }
+        // linked is bound again
+    }
+    // the previously bound program is bound again
+    ```
+    */
+    pub fn bind(&self) -> ProgramBinding<'_> {
+        let mut previous = 0;
+
+        unsafe {
+            gl_call!(gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut previous));
+            gl_call!(gl::UseProgram(self.get_id()));
+        }
+
+        ProgramBinding {
+            program: self,
+            previous: previous as gl::types::GLuint,
+        }
+    }
+}
+
+/// A guard that keeps a [LinkedProgram](LinkedProgram) bound for its lifetime, see [LinkedProgram::bind](LinkedProgram::bind)
+#[derive(Debug)]
+pub struct ProgramBinding<'a> {
+    program: &'a LinkedProgram,
+    previous: gl::types::GLuint,
+}
+
+impl std::ops::Deref for ProgramBinding<'_> {
+    type Target = LinkedProgram;
+
+    fn deref(&self) -> &Self::Target {
+        self.program
+    }
+}
+
+impl Drop for ProgramBinding<'_> {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::UseProgram(self.previous)) };
+    }
+}
+
+/// The location of a uniform within a [LinkedProgram](LinkedProgram), see [LinkedProgram::uniform_location](LinkedProgram::uniform_location)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct UniformLocation(gl::types::GLint);
+
+impl UniformLocation {
+    /// Returns the raw location as reported by OpenGL
+    pub fn get_raw(&self) -> gl::types::GLint {
+        self.0
+    }
+}
+
+/// Error enum for the failed lookup or modification of a uniform
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum UniformError {
+    /// Used if the program has no active uniform with the passed name. Note that uniforms which aren't used by any stage
+    /// are usually optimised out by the driver
+    #[error("No active uniform named {0}")]
+    NotFound(String),
+    /// Used if a uniform was set while the program wasn't bound, see [LinkedProgram::bind](LinkedProgram::bind)
+    #[error("The program has to be bound to set its uniforms")]
+    NotBound,
+    /// Used if a uniform was set using a setter that doesn't match the uniform's type or size
+    #[error("The value's type doesn't match the uniform's type")]
+    TypeMismatch,
+    /// Used if the uniform's name couldn't be converted to a c-string because it contained a nul-byte
+    #[error("The uniform's name couldn't be converted to a CString: {source}")]
+    InvalidName {
+        #[from]
+        /// The underlying c-string conversion error
+        source: std::ffi::NulError,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl LinkedProgram {
+    /**
+    Returns the location of the uniform with the passed name.
+
+    Locations are cached per program, so only the first lookup of each name reaches OpenGL.
+    This includes names that weren't found, repeatedly looking up a uniform that was optimised out is cheap as well.
+    The cache is cleared whenever the program is relinked, which graphene only does when reloading a program with the
+    `hot-reload` feature enabled, so cached locations never outlive the program they were looked up in.
+
+    # Example
+    ```ignore
+    let location = linked.uniform_location("u_color").expect("Unable to find uniform");
+    assert_eq!(linked.uniform_location("u_color"), Ok(location));
+    ```
+    */
+    pub fn uniform_location(&self, name: &str) -> Result<UniformLocation, UniformError> {
+        if let Some(&location) = self.inner.uniform_locations.borrow().get(name) {
+            return match location {
+                -1 => Err(UniformError::NotFound(name.to_owned())),
+                _ => Ok(UniformLocation(location)),
+            };
+        }
+
+        let cstr = std::ffi::CString::new(name)?;
+
+        let (location, rc) = unsafe {
+            let location = gl::GetUniformLocation(self.get_id(), cstr.as_ptr());
+            (location, super::get_error())
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            _ => return Err(UniformError::Unknown),
+        }
+
+        self.inner.uniform_locations.borrow_mut().insert(name.to_owned(), location);
+
+        if location == -1 {
+            return Err(UniformError::NotFound(name.to_owned()));
+        }
+
+        Ok(UniformLocation(location))
+    }
+
+    /// Clears the cached uniform locations including the names that weren't found, so the next lookup of each name reaches OpenGL again.
+    pub fn clear_uniform_cache(&self) {
+        self.inner.uniform_locations.borrow_mut().clear();
+    }
+}
+
+impl LinkedProgram {
+    /// Maps the errors reported by the glUniform* functions
+    fn uniform_result(&self, rc: gl::types::GLenum) -> Result<(), UniformError> {
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_OPERATION => {
+                let mut current = 0;
+                unsafe { gl_call!(gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut current)) };
+
+                if current as gl::types::GLuint != self.get_id() {
+                    Err(UniformError::NotBound)
+                } else {
+                    Err(UniformError::TypeMismatch)
+                }
+            }
+            _ => Err(UniformError::Unknown),
+        }
+    }
+
+    /**
+    Sets a `float` uniform of the program, which has to be bound.
+
+    # Example
+    ```ignore
+    let binding = linked.bind();
+    let location = binding.uniform_location("u_time").expect("Unable to find uniform");
+    binding.set_uniform_f32(location, 1.5).expect("Unable to set uniform");
+    ```
+    */
+    pub fn set_uniform_f32(&self, location: UniformLocation, value: f32) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform1f(location.0, value);
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets an `int` (or sampler) uniform of the program, which has to be bound.
+    pub fn set_uniform_i32(&self, location: UniformLocation, value: i32) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform1i(location.0, value);
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `vec2` uniform of the program, which has to be bound.
+    pub fn set_uniform_vec2(&self, location: UniformLocation, value: [f32; 2]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform2fv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `vec3` uniform of the program, which has to be bound.
+    pub fn set_uniform_vec3(&self, location: UniformLocation, value: [f32; 3]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform3fv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `vec4` uniform of the program, which has to be bound.
+    pub fn set_uniform_vec4(&self, location: UniformLocation, value: [f32; 4]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform4fv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `mat3` uniform of the program, which has to be bound.
+    /// The values are expected in column-major order unless `transpose` is set.
+    pub fn set_uniform_mat3(&self, location: UniformLocation, value: &[f32; 9], transpose: bool) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::UniformMatrix3fv(location.0, 1, transpose as gl::types::GLboolean, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `mat4` uniform of the program, which has to be bound.
+    /// The values are expected in column-major order unless `transpose` is set.
+    pub fn set_uniform_mat4(&self, location: UniformLocation, value: &[f32; 16], transpose: bool) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::UniformMatrix4fv(location.0, 1, transpose as gl::types::GLboolean, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `uint` uniform of the program, which has to be bound.
+    pub fn set_uniform_u32(&self, location: UniformLocation, value: u32) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform1ui(location.0, value);
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `uvec2` uniform of the program, which has to be bound.
+    pub fn set_uniform_uvec2(&self, location: UniformLocation, value: [u32; 2]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform2uiv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `uvec3` uniform of the program, which has to be bound.
+    pub fn set_uniform_uvec3(&self, location: UniformLocation, value: [u32; 3]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform3uiv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `uvec4` uniform of the program, which has to be bound.
+    pub fn set_uniform_uvec4(&self, location: UniformLocation, value: [u32; 4]) -> Result<(), UniformError> {
+        let rc = unsafe {
+            gl::Uniform4uiv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /**
+    Sets a `bool` uniform of the program, which has to be bound.
+
+    GLSL booleans are set via their integer representation, this takes care of passing 0 or 1 respectively.
+    */
+    pub fn set_uniform_bool(&self, location: UniformLocation, value: bool) -> Result<(), UniformError> {
+        self.set_uniform_i32(location, value as i32)
+    }
+
+    /// Sets a `bvec2` uniform of the program, which has to be bound.
+    pub fn set_uniform_bvec2(&self, location: UniformLocation, value: [bool; 2]) -> Result<(), UniformError> {
+        let value = value.map(i32::from);
+
+        let rc = unsafe {
+            gl::Uniform2iv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `bvec3` uniform of the program, which has to be bound.
+    pub fn set_uniform_bvec3(&self, location: UniformLocation, value: [bool; 3]) -> Result<(), UniformError> {
+        let value = value.map(i32::from);
+
+        let rc = unsafe {
+            gl::Uniform3iv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /// Sets a `bvec4` uniform of the program, which has to be bound.
+    pub fn set_uniform_bvec4(&self, location: UniformLocation, value: [bool; 4]) -> Result<(), UniformError> {
+        let value = value.map(i32::from);
+
+        let rc = unsafe {
+            gl::Uniform4iv(location.0, 1, value.as_ptr());
+            super::get_error()
+        };
+
+        self.uniform_result(rc)
+    }
+
+    /**
+    Sets a uniform of the program, which has to be bound, using the setter matching the value's type.
+
+    # Example
+    ```ignore
+    let binding = linked.bind();
+    let location = binding.uniform_location("u_flags").expect("Unable to find uniform");
+    binding.set_uniform(location, 0b101u32).expect("Unable to set uniform");
+    ```
+    */
+    pub fn set_uniform<V: UniformValue>(&self, location: UniformLocation, value: V) -> Result<(), UniformError> {
+        value.set_uniform(self, location)
+    }
+}
+
+/// A value that can be assigned to a uniform via [LinkedProgram::set_uniform](LinkedProgram::set_uniform)
+pub trait UniformValue {
+    /// Assigns the value to the uniform at the passed location of the program, which has to be bound
+    fn set_uniform(&self, program: &LinkedProgram, location: UniformLocation) -> Result<(), UniformError>;
+}
+
+/// Implements [UniformValue] for a type by delegating to one of the typed setters of [LinkedProgram]
+macro_rules! uniform_value {
+    ($($type:ty => $setter:ident),* $(,)?) => {
+        $(
+            impl UniformValue for $type {
+                fn set_uniform(&self, program: &LinkedProgram, location: UniformLocation) -> Result<(), UniformError> {
+                    program.$setter(location, *self)
+                }
+            }
+        )*
+    };
+}
+
+uniform_value! {
+    f32 => set_uniform_f32,
+    i32 => set_uniform_i32,
+    u32 => set_uniform_u32,
+    bool => set_uniform_bool,
+    [f32; 2] => set_uniform_vec2,
+    [f32; 3] => set_uniform_vec3,
+    [f32; 4] => set_uniform_vec4,
+    [u32; 2] => set_uniform_uvec2,
+    [u32; 3] => set_uniform_uvec3,
+    [u32; 4] => set_uniform_uvec4,
+    [bool; 2] => set_uniform_bvec2,
+    [bool; 3] => set_uniform_bvec3,
+    [bool; 4] => set_uniform_bvec4,
+}
+
+/**
+A uniform value of any of the types supported by [UniformValue], so uniform values can be stored as data, e.g. by a
+material system, and applied in a loop through [LinkedProgram::set_uniforms](LinkedProgram::set_uniforms).
+
+Matrices are column-major, as GLSL expects them.
+*/
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UniformVariant {
+    /// `float`
+    F32(f32),
+    /// `int`, also used for samplers
+    I32(i32),
+    /// `uint`
+    U32(u32),
+    /// `bool`
+    Bool(bool),
+    /// `vec2`
+    Vec2([f32; 2]),
+    /// `vec3`
+    Vec3([f32; 3]),
+    /// `vec4`
+    Vec4([f32; 4]),
+    /// `uvec2`
+    UVec2([u32; 2]),
+    /// `uvec3`
+    UVec3([u32; 3]),
+    /// `uvec4`
+    UVec4([u32; 4]),
+    /// `bvec2`
+    BVec2([bool; 2]),
+    /// `bvec3`
+    BVec3([bool; 3]),
+    /// `bvec4`
+    BVec4([bool; 4]),
+    /// `mat3`
+    Mat3([f32; 9]),
+    /// `mat4`
+    Mat4([f32; 16]),
+}
+
+impl UniformVariant {
+    /// Returns the GLSL type of a uniform the value can be assigned to. [I32](UniformVariant::I32) maps to
+    /// [Int](UniformKind::Int) even though it can be assigned to samplers as well.
+    pub fn kind(&self) -> UniformKind {
+        match self {
+            UniformVariant::F32(_) => UniformKind::Float,
+            UniformVariant::I32(_) => UniformKind::Int,
+            UniformVariant::U32(_) => UniformKind::UnsignedInt,
+            UniformVariant::Bool(_) => UniformKind::Bool,
+            UniformVariant::Vec2(_) => UniformKind::FloatVec2,
+            UniformVariant::Vec3(_) => UniformKind::FloatVec3,
+            UniformVariant::Vec4(_) => UniformKind::FloatVec4,
+            UniformVariant::UVec2(_) => UniformKind::UnsignedIntVec2,
+            UniformVariant::UVec3(_) => UniformKind::UnsignedIntVec3,
+            UniformVariant::UVec4(_) => UniformKind::UnsignedIntVec4,
+            UniformVariant::BVec2(_) => UniformKind::BoolVec2,
+            UniformVariant::BVec3(_) => UniformKind::BoolVec3,
+            UniformVariant::BVec4(_) => UniformKind::BoolVec4,
+            UniformVariant::Mat3(_) => UniformKind::FloatMat3,
+            UniformVariant::Mat4(_) => UniformKind::FloatMat4,
+        }
+    }
+}
+
+impl UniformValue for UniformVariant {
+    fn set_uniform(&self, program: &LinkedProgram, location: UniformLocation) -> Result<(), UniformError> {
+        match self {
+            UniformVariant::F32(value) => program.set_uniform_f32(location, *value),
+            UniformVariant::I32(value) => program.set_uniform_i32(location, *value),
+            UniformVariant::U32(value) => program.set_uniform_u32(location, *value),
+            UniformVariant::Bool(value) => program.set_uniform_bool(location, *value),
+            UniformVariant::Vec2(value) => program.set_uniform_vec2(location, *value),
+            UniformVariant::Vec3(value) => program.set_uniform_vec3(location, *value),
+            UniformVariant::Vec4(value) => program.set_uniform_vec4(location, *value),
+            UniformVariant::UVec2(value) => program.set_uniform_uvec2(location, *value),
+            UniformVariant::UVec3(value) => program.set_uniform_uvec3(location, *value),
+            UniformVariant::UVec4(value) => program.set_uniform_uvec4(location, *value),
+            UniformVariant::BVec2(value) => program.set_uniform_bvec2(location, *value),
+            UniformVariant::BVec3(value) => program.set_uniform_bvec3(location, *value),
+            UniformVariant::BVec4(value) => program.set_uniform_bvec4(location, *value),
+            UniformVariant::Mat3(value) => program.set_uniform_mat3(location, value, false),
+            UniformVariant::Mat4(value) => program.set_uniform_mat4(location, value, false),
+        }
+    }
+}
+
+impl LinkedProgram {
+    /**
+    Sets the uniforms with the passed names of the program, which has to be bound, to the paired values in order.
+
+    Locations are looked up through the cache of [uniform_location](LinkedProgram::uniform_location). Stops at the first
+    uniform that can't be found or set, the uniforms before it keep their new values.
+
+    # Example
+    ```ignore
+    let material = [
+        ("u_roughness", UniformVariant::F32(0.5)),
+        ("u_albedo", UniformVariant::Vec3([1.0, 0.5, 0.25])),
+        ("u_model", UniformVariant::Mat4(model)),
+    ];
+
+    let binding = linked.bind();
+    binding.set_uniforms(&material).expect("Unable to apply material");
+    ```
+    */
+    pub fn set_uniforms(&self, values: &[(&str, UniformVariant)]) -> Result<(), UniformError> {
+        for (name, value) in values {
+            let location = self.uniform_location(name)?;
+            self.set_uniform(location, *value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error enum for the failed readback of a uniform
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum UniformReadError {
+    /// Used if the glGetnUniform* functions aren't available, they require OpenGL 4.5 or `GL_KHR_robustness`
+    #[error("Sized uniform readback requires OpenGL 4.5 or GL_KHR_robustness")]
+    Unsupported,
+    /// Used if the location doesn't belong to the program or the uniform doesn't fit into the requested value
+    #[error("The uniform can't be read into a value of the requested size")]
+    InvalidOperation,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// The signature shared by glGetnUniformfv, glGetnUniformiv and glGetnUniformuiv
+type ReadUniform<T> = unsafe fn(gl::types::GLuint, gl::types::GLint, gl::types::GLsizei, *mut T);
+
+impl LinkedProgram {
+    /// Reads `N` values of the uniform at the passed location, telling OpenGL the size of the buffer so a uniform that's
+    /// larger than expected is rejected instead of overflowing it
+    fn read_uniform<T: Copy + Default, const N: usize>(
+        &self,
+        location: UniformLocation,
+        loaded: bool,
+        read: ReadUniform<T>,
+    ) -> Result<[T; N], UniformReadError> {
+        if !loaded {
+            return Err(UniformReadError::Unsupported);
+        }
+
+        let mut values = [T::default(); N];
+
+        let rc = unsafe {
+            read(
+                self.get_id(),
+                location.0,
+                size_of::<[T; N]>() as gl::types::GLsizei,
+                values.as_mut_ptr(),
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(values),
+            gl::INVALID_OPERATION => Err(UniformReadError::InvalidOperation),
+            _ => Err(UniformReadError::Unknown),
+        }
+    }
+
+    /// Reads `N` floats of the uniform at the passed location via glGetnUniformfv
+    fn read_uniform_f32<const N: usize>(&self, location: UniformLocation) -> Result<[f32; N], UniformReadError> {
+        self.read_uniform(location, gl::GetnUniformfv::is_loaded(), gl::GetnUniformfv)
+    }
+
+    /**
+    Reads back a `float` uniform of the program, the program doesn't have to be bound.
+
+    Like all `get_uniform_*` functions this uses the sized glGetnUniform* variants, which require OpenGL 4.5 or
+    `GL_KHR_robustness`, so a uniform that's larger than the requested value is rejected instead of overflowing it.
+
+    # Example
+    ```ignore
+    let binding = linked.bind();
+    let location = binding.uniform_location("u_color").expect("Unable to find uniform");
+    binding.set_uniform_vec4(location, [1.0, 0.5, 0.25, 1.0]).expect("Unable to set uniform");
+    assert_eq!(binding.get_uniform_vec4(location), Ok([1.0, 0.5, 0.25, 1.0]));
+    ```
+    */
+    pub fn get_uniform_f32(&self, location: UniformLocation) -> Result<f32, UniformReadError> {
+        self.read_uniform_f32::<1>(location).map(|[value]| value)
+    }
+
+    /// Reads back a `vec2` uniform of the program.
+    pub fn get_uniform_vec2(&self, location: UniformLocation) -> Result<[f32; 2], UniformReadError> {
+        self.read_uniform_f32(location)
+    }
+
+    /// Reads back a `vec3` uniform of the program.
+    pub fn get_uniform_vec3(&self, location: UniformLocation) -> Result<[f32; 3], UniformReadError> {
+        self.read_uniform_f32(location)
+    }
+
+    /// Reads back a `vec4` uniform of the program.
+    pub fn get_uniform_vec4(&self, location: UniformLocation) -> Result<[f32; 4], UniformReadError> {
+        self.read_uniform_f32(location)
+    }
+
+    /// Reads back a `mat3` uniform of the program in column-major order.
+    pub fn get_uniform_mat3(&self, location: UniformLocation) -> Result<[f32; 9], UniformReadError> {
+        self.read_uniform_f32(location)
+    }
+
+    /// Reads back a `mat4` uniform of the program in column-major order.
+    pub fn get_uniform_mat4(&self, location: UniformLocation) -> Result<[f32; 16], UniformReadError> {
+        self.read_uniform_f32(location)
+    }
+
+    /// Reads back an `int` (or sampler) uniform of the program.
+    pub fn get_uniform_i32(&self, location: UniformLocation) -> Result<i32, UniformReadError> {
+        self.read_uniform::<_, 1>(location, gl::GetnUniformiv::is_loaded(), gl::GetnUniformiv)
+            .map(|[value]| value)
+    }
+
+    /// Reads back a `uint` uniform of the program.
+    pub fn get_uniform_u32(&self, location: UniformLocation) -> Result<u32, UniformReadError> {
+        self.read_uniform::<_, 1>(location, gl::GetnUniformuiv::is_loaded(), gl::GetnUniformuiv)
+            .map(|[value]| value)
+    }
+}
+
+/// Error enum for the failed introspection of a linked program
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum IntrospectionError {
+    /// Used if the underlying object was not created by OpenGL
+    #[error("The underlying object was not created by OpenGL")]
+    NotAnOpenGLValue,
+    /// Used if the underlying object was not recognised as an OpenGL program
+    #[error("The underlying object was not recognised as an OpenGL program")]
+    NotAProgram,
+    /// Used if an active uniform or attribute has a type that isn't part of GLSL 4.5, e.g. one introduced by an extension
+    #[error("OpenGL reported an unknown uniform type: {source}")]
+    UnknownType {
+        #[from]
+        /// The underlying enum conversion error
+        source: super::InvalidEnumValue,
+    },
+    /// Used if the driver doesn't provide the introspection functions, e.g. subroutines are part of OpenGL 4.0
+    #[error("The introspection functions are not supported by the driver")]
+    Unsupported,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// Maps the errors reported by the program introspection functions of OpenGL
+fn introspection_result(rc: gl::types::GLenum) -> Result<(), IntrospectionError> {
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_VALUE => Err(IntrospectionError::NotAnOpenGLValue),
+        gl::INVALID_OPERATION => Err(IntrospectionError::NotAProgram),
+        _ => Err(IntrospectionError::Unknown),
+    }
+}
+
+/// Returns the number of scalar components a uniform of the passed GL type occupies, counting samplers and images as one
+fn uniform_type_components(r#type: gl::types::GLenum) -> u32 {
+    match r#type {
+        gl::FLOAT_VEC2 | gl::INT_VEC2 | gl::UNSIGNED_INT_VEC2 | gl::BOOL_VEC2 | gl::DOUBLE_VEC2 => 2,
+        gl::FLOAT_VEC3 | gl::INT_VEC3 | gl::UNSIGNED_INT_VEC3 | gl::BOOL_VEC3 | gl::DOUBLE_VEC3 => 3,
+        gl::FLOAT_VEC4 | gl::INT_VEC4 | gl::UNSIGNED_INT_VEC4 | gl::BOOL_VEC4 | gl::DOUBLE_VEC4 | gl::FLOAT_MAT2 | gl::DOUBLE_MAT2 => 4,
+        gl::FLOAT_MAT2x3 | gl::FLOAT_MAT3x2 | gl::DOUBLE_MAT2x3 | gl::DOUBLE_MAT3x2 => 6,
+        gl::FLOAT_MAT2x4 | gl::FLOAT_MAT4x2 | gl::DOUBLE_MAT2x4 | gl::DOUBLE_MAT4x2 => 8,
+        gl::FLOAT_MAT3 | gl::DOUBLE_MAT3 => 9,
+        gl::FLOAT_MAT3x4 | gl::FLOAT_MAT4x3 | gl::DOUBLE_MAT3x4 | gl::DOUBLE_MAT4x3 => 12,
+        gl::FLOAT_MAT4 | gl::DOUBLE_MAT4 => 16,
+        _ => 1,
+    }
+}
+
+/// Returns the number of consecutive locations a vertex attribute of the passed GL type occupies, i.e. one per matrix column
+fn attribute_location_count(r#type: gl::types::GLenum) -> u32 {
+    match r#type {
+        gl::FLOAT_MAT2 | gl::FLOAT_MAT2x3 | gl::FLOAT_MAT2x4 | gl::DOUBLE_MAT2 | gl::DOUBLE_MAT2x3 | gl::DOUBLE_MAT2x4 => 2,
+        gl::FLOAT_MAT3 | gl::FLOAT_MAT3x2 | gl::FLOAT_MAT3x4 | gl::DOUBLE_MAT3 | gl::DOUBLE_MAT3x2 | gl::DOUBLE_MAT3x4 => 3,
+        gl::FLOAT_MAT4 | gl::FLOAT_MAT4x2 | gl::FLOAT_MAT4x3 | gl::DOUBLE_MAT4 | gl::DOUBLE_MAT4x2 | gl::DOUBLE_MAT4x3 => 4,
+        _ => 1,
+    }
+}
+
+impl LinkedProgram {
+    /// Queries a single parameter of the program via glGetProgramiv
+    fn get_parameter(&self, pname: gl::types::GLenum) -> Result<gl::types::GLint, IntrospectionError> {
+        let mut value = 0;
+
+        let rc = unsafe {
+            gl::GetProgramiv(self.get_id(), pname, &mut value);
+            super::get_error()
+        };
+
+        introspection_result(rc)?;
+
+        Ok(value)
+    }
+
+    /**
+    Returns the length of the longest active uniform name including the nul-byte.
+
+    This is necessary to size the buffers that OpenGL writes uniform names into without truncating long names.
+    */
+    pub fn active_uniform_max_length(&self) -> Result<usize, IntrospectionError> {
+        Ok(self.get_parameter(gl::ACTIVE_UNIFORM_MAX_LENGTH)? as usize)
+    }
+
+    /**
+    Returns the length of the longest active attribute name including the nul-byte.
+
+    Like [active_uniform_max_length](LinkedProgram::active_uniform_max_length) this is used to size name buffers.
+    */
+    pub fn active_attribute_max_length(&self) -> Result<usize, IntrospectionError> {
+        Ok(self.get_parameter(gl::ACTIVE_ATTRIBUTE_MAX_LENGTH)? as usize)
+    }
+
+    /**
+    Returns the length in bytes of the program's binary representation as it would be returned by glGetProgramBinary.
+
+    This is 0 if the driver doesn't support retrieving the binary of this program, e.g. because
+    GL_PROGRAM_BINARY_RETRIEVABLE_HINT wasn't set before linking.
+    */
+    pub fn binary_length(&self) -> Result<usize, IntrospectionError> {
+        Ok(self.get_parameter(gl::PROGRAM_BINARY_LENGTH)? as usize)
+    }
+
+    /**
+    Returns the number of scalar components occupied by all active uniforms of the program, which can be used to
+    estimate its uniform footprint. Arrays count once per element, samplers and images count as a single component.
+
+    # Example
+    ```ignore
+    // uniform mat4 u_transform; uniform vec4 u_colors[2];
+    assert_eq!(linked.uniform_component_count(), Ok(16 + 2 * 4));
+    ```
+    */
+    pub fn uniform_component_count(&self) -> Result<u32, IntrospectionError> {
+        let count = self.get_parameter(gl::ACTIVE_UNIFORMS)?;
+
+        if count <= 0 {
+            return Ok(0);
+        }
+
+        let indices = (0..count as gl::types::GLuint).collect::<Vec<_>>();
+        let mut types = vec![0; indices.len()];
+        let mut sizes = vec![0; indices.len()];
+
+        let rc = unsafe {
+            gl::GetActiveUniformsiv(self.get_id(), count, indices.as_ptr(), gl::UNIFORM_TYPE, types.as_mut_ptr());
+            gl::GetActiveUniformsiv(self.get_id(), count, indices.as_ptr(), gl::UNIFORM_SIZE, sizes.as_mut_ptr());
+            super::get_error()
+        };
+
+        introspection_result(rc)?;
+
+        Ok(types
+            .iter()
+            .zip(&sizes)
+            .map(|(r#type, size)| uniform_type_components(*r#type as gl::types::GLenum) * (*size).max(0) as u32)
+            .sum())
+    }
+}
+
+/// An active uniform or attribute as reported by OpenGL, before its type is converted into a [UniformKind]
+struct ActiveResource {
+    name: String,
+    location: i32,
+    r#type: gl::types::GLenum,
+    size: i32,
+}
+
+/// Turns a name written by OpenGL into a rust string, stripping the `[0]` suffix OpenGL reports for arrays
+fn resource_name(mut name: Vec<u8>, length: gl::types::GLsizei) -> String {
+    name.truncate(length.max(0) as usize);
+
+    let name = String::from_utf8_lossy(&name);
+    name.strip_suffix("[0]").unwrap_or(&name).to_owned()
+}
+
+/// The kinds of resources [query_active_resources](LinkedProgram::query_active_resources) enumerates
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ResourceKind {
+    Uniform,
+    Attribute,
+}
+
+impl LinkedProgram {
+    /// Queries all active uniforms or vertex attributes, the latter including built-ins such as gl_VertexID with a location of -1
+    fn query_active_resources(&self, kind: ResourceKind) -> Result<Vec<ActiveResource>, IntrospectionError> {
+        let (count, capacity) = match kind {
+            ResourceKind::Uniform => (self.get_parameter(gl::ACTIVE_UNIFORMS)?, self.active_uniform_max_length()?),
+            ResourceKind::Attribute => (self.get_parameter(gl::ACTIVE_ATTRIBUTES)?, self.active_attribute_max_length()?),
+        };
+        let capacity = capacity.max(1);
+
+        let mut resources = Vec::with_capacity(count.max(0) as usize);
+
+        for index in 0..count.max(0) as gl::types::GLuint {
+            let mut name = vec![0u8; capacity];
+            let mut length = 0;
+            let mut size = 0;
+            let mut r#type = 0;
+
+            let (location, rc) = unsafe {
+                let get_active = match kind {
+                    ResourceKind::Uniform => gl::GetActiveUniform,
+                    ResourceKind::Attribute => gl::GetActiveAttrib,
+                };
+                get_active(
+                    self.get_id(),
+                    index,
+                    capacity as gl::types::GLsizei,
+                    &mut length,
+                    &mut size,
+                    &mut r#type,
+                    name.as_mut_ptr() as *mut gl::types::GLchar,
+                );
+
+                let name = name.as_ptr() as *const gl::types::GLchar;
+                let location = match kind {
+                    ResourceKind::Uniform => gl::GetUniformLocation(self.get_id(), name),
+                    ResourceKind::Attribute => gl::GetAttribLocation(self.get_id(), name),
+                };
+                (location, super::get_error())
+            };
+
+            introspection_result(rc)?;
+
+            resources.push(ActiveResource {
+                name: resource_name(name, length),
+                location,
+                r#type,
+                size,
+            });
+        }
+
+        Ok(resources)
+    }
+}
+
+/// The differences between the attributes a program consumes and the ones a vertex array provides
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct VaoMismatch {
+    /// The name and location of every attribute location the program consumes that isn't enabled in the vertex array.
+    /// Matrix and array attributes are reported once for each of their locations
+    pub missing: Vec<(String, u32)>,
+    /// The index of every attribute that is enabled in the vertex array but not consumed by the program
+    pub extra: Vec<u32>,
+}
+
+impl std::fmt::Display for VaoMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing attributes: [")?;
+        for (i, (name, location)) in self.missing.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} at {}", name, location)?;
+        }
+        write!(f, "], unused attributes: {:?}", self.extra)
+    }
+}
+
+/// Compares the enabled attribute indices of a vertex array with the locations the program's attributes consume
+fn vao_mismatch(enabled: &[u32], attributes: &[ActiveResource]) -> VaoMismatch {
+    // matrices occupy one location per column and arrays one set of locations per element
+    let consumed = attributes
+        .iter()
+        .filter(|attribute| attribute.location >= 0)
+        .flat_map(|attribute| {
+            let start = attribute.location as u32;
+            let count = attribute_location_count(attribute.r#type) * attribute.size.max(1) as u32;
+            (start..start + count).map(move |location| (attribute.name.clone(), location))
+        })
+        .collect::<Vec<_>>();
+
+    VaoMismatch {
+        missing: consumed
+            .iter()
+            .filter(|(_, location)| !enabled.contains(location))
+            .cloned()
+            .collect(),
+        extra: enabled
+            .iter()
+            .filter(|index| !consumed.iter().any(|(_, location)| location == *index))
+            .copied()
+            .collect(),
+    }
+}
+
+/// Error enum for the failed or unsuccessful compatibility check between a program and a vertex array
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum VaoCompatibilityError {
+    /// Used if the vertex array doesn't match the program's attributes
+    #[error("The vertex array doesn't match the program's attributes, {0}")]
+    Mismatch(VaoMismatch),
+    /// Used if the program's attributes couldn't be queried
+    #[error("Unable to query the program's attributes: {source}")]
+    Introspection {
+        #[from]
+        /// The underlying introspection error
+        source: IntrospectionError,
+    },
+}
+
+impl LinkedProgram {
+    /**
+    Checks whether the enabled attributes of the passed vertex array match the locations of the program's active attributes.
+
+    A vertex array that doesn't provide an attribute the program consumes is one of the most common reasons for nothing being rendered.
+    Attributes that are enabled but not consumed are reported as well since they usually point towards mismatched locations.
+
+    # Example
+    ```ignore
+    if let Err(VaoCompatibilityError::Mismatch(mismatch)) = linked.check_vao_compatibility(&vao) {
+        log::warn!("{}", mismatch);
+    }
+    ```
+    */
+    pub fn check_vao_compatibility(&self, vao: &VertexArray) -> Result<(), VaoCompatibilityError> {
+        let attributes = self.query_active_resources(ResourceKind::Attribute)?;
+        let mismatch = vao_mismatch(&vao.enabled_attributes(), &attributes);
+
+        if mismatch.missing.is_empty() && mismatch.extra.is_empty() {
+            Ok(())
+        } else {
+            Err(VaoCompatibilityError::Mismatch(mismatch))
+        }
+    }
+}
+
+impl ShaderProgram {
+    /**
+    Hints to the driver whether or not the program's binary will be retrieved via [get_binary](LinkedProgram::get_binary).
+
+    The hint only takes effect if it is set before linking, some drivers don't provide binaries without it.
+    */
+    pub fn set_binary_retrievable_hint(&self, retrievable: bool) {
+        unsafe {
+            gl_call!(gl::ProgramParameteri(
+                self.inner.id,
+                gl::PROGRAM_BINARY_RETRIEVABLE_HINT,
+                retrievable as gl::types::GLint
+            ))
+        };
+    }
+}
+
+/// The driver-specific binary representation of a linked program, see [LinkedProgram::get_binary](LinkedProgram::get_binary)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ProgramBinary {
+    /// The driver-specific format of the binary, which has to be passed alongside the data when loading it
+    pub format: gl::types::GLenum,
+    /// The binary itself
+    pub data: Vec<u8>,
+}
+
+/// Error enum for the failed retrieval or loading of a program binary
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum ProgramBinaryError {
+    /// Used if the driver doesn't provide a binary for the program, e.g. because the retrievable hint wasn't set before linking
+    #[error("The driver doesn't provide a binary for the program")]
+    Unavailable,
+    /// Used if the format of a binary isn't supported by the driver
+    #[error("The binary format {0:#X} isn't supported by the driver")]
+    UnsupportedFormat(gl::types::GLenum),
+    /// Used if the driver rejected a binary, e.g. because it was created by a different driver version.
+    /// The program has to be recompiled from its sources in this case
+    #[error("The driver rejected the binary, the program has to be recompiled")]
+    Rejected,
+    /// Used if the program's binary length couldn't be queried
+    #[error("Unable to query the program's binary length: {source}")]
+    Introspection {
+        #[from]
+        /// The underlying introspection error
+        source: IntrospectionError,
+    },
+    /// Used if the program to load the binary into couldn't be created
+    #[error("Unable to create the program to load the binary into: {source}")]
+    Creation {
+        #[from]
+        /// The underlying creation error
+        source: ProgramCreationError,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl LinkedProgram {
+    /**
+    Retrieves the program's binary, which can be cached to skip compiling and linking on subsequent runs.
+
+    Set the [retrievable hint](ShaderProgram::set_binary_retrievable_hint) before linking, otherwise the driver might not
+    provide a binary.
+
+    # Example
+    ```ignore
+    let program = ShaderProgram::new().expect("Unable to create shader program");
+    program.set_binary_retrievable_hint(true);
+    let linked = program.link([vertex, fragment]).expect("Unable to link shader program");
+    let binary = linked.get_binary().expect("Unable to retrieve program binary");
+    std::fs::write("program.bin", &binary.data).expect("Unable to cache program binary");
+    ```
+    */
+    pub fn get_binary(&self) -> Result<ProgramBinary, ProgramBinaryError> {
+        let length = self.binary_length()?;
+
+        if length == 0 {
+            return Err(ProgramBinaryError::Unavailable);
+        }
+
+        let mut data = vec![0u8; length];
+        let mut written = 0;
+        let mut format = 0;
+
+        let rc = unsafe {
+            gl::GetProgramBinary(
+                self.get_id(),
+                length as gl::types::GLsizei,
+                &mut written,
+                &mut format,
+                data.as_mut_ptr() as *mut _,
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            gl::INVALID_OPERATION => return Err(ProgramBinaryError::Unavailable),
+            _ => return Err(ProgramBinaryError::Unknown),
+        }
+
+        data.truncate(written.max(0) as usize);
+
+        Ok(ProgramBinary { format, data })
+    }
+}
+
+impl ShaderProgram {
+    /**
+    Creates a program from a binary previously retrieved via [get_binary](LinkedProgram::get_binary).
+
+    Loading a binary can fail even if it was retrieved from the same machine, e.g. after a driver update.
+    Always keep the sources around to recompile the program if [Rejected](ProgramBinaryError::Rejected) is returned.
+
+    # Example
+    ```ignore
+    let linked = match ShaderProgram::from_binary(&cached, format) {
+        Ok(linked) => linked,
+        Err(ProgramBinaryError::Rejected) => ShaderProgram::new()?.link([vertex, fragment])?,
+        Err(e) => return Err(e.into()),
+    };
+    ```
+    */
+    pub fn from_binary(bytes: &[u8], format: u32) -> Result<LinkedProgram, ProgramBinaryError> {
+        let program = ShaderProgram::new()?;
+
+        let rc = unsafe {
+            gl::ProgramBinary(
+                program.inner.id,
+                format,
+                bytes.as_ptr() as *const _,
+                bytes.len() as gl::types::GLsizei,
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            gl::INVALID_ENUM => return Err(ProgramBinaryError::UnsupportedFormat(format)),
+            _ => return Err(ProgramBinaryError::Unknown),
+        }
+
+        let mut link_status = 0;
+        unsafe {
+            gl_call!(gl::GetProgramiv(program.inner.id, gl::LINK_STATUS, &mut link_status));
+        }
+
+        if link_status == 0 {
+            return Err(ProgramBinaryError::Rejected);
+        }
+
+        Ok(LinkedProgram {
+            inner: LinkedProgramInner {
+                program,
+                uniform_locations: Default::default(),
+                #[cfg(feature = "hot-reload")]
+                watcher: None,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "hot-reload")]
+impl LinkedProgram {
+    /// Makes the program reloadable from the passed watcher
+    pub(crate) fn set_watcher(&mut self, watcher: super::hot_reload::ShaderWatcher) {
+        self.inner.watcher = Some(watcher);
+    }
+
+    /**
+    Recompiles and relinks the program if any of its watched source files changed and returns whether or not it was reloaded.
+
+    The underlying program is only replaced if the new one links successfully, otherwise the previous one is kept and the
+    error is passed to the watcher's [on_error](super::hot_reload::ShaderWatcher::on_error) callback and returned. The uniform cache is cleared on reload since locations may have changed.
+    Always returns `false` if the program wasn't linked through a [ShaderWatcher](super::hot_reload::ShaderWatcher).
+    */
+    pub fn reload_if_changed(&mut self) -> Result<bool, super::hot_reload::ReloadError> {
+        let watcher = match &self.inner.watcher {
+            Some(watcher) => watcher,
+            None => return Ok(false),
+        };
+
+        if !watcher.has_changed().map_err(|e| watcher.report(e))? {
+            return Ok(false);
+        }
+
+        let reloaded = watcher.build().map_err(|e| watcher.report(e))?;
+        self.replace_program(reloaded);
+
+        Ok(true)
+    }
+
+    /// Replaces the underlying program with the passed one, clearing the uniform cache since its locations belong to the previous program.
+    /// Every path that relinks a program has to go through this
+    fn replace_program(&mut self, mut relinked: LinkedProgram) {
+        // the previous program ends up in the relinked one and is deleted alongside it
+        std::mem::swap(&mut self.inner.program, &mut relinked.inner.program);
+        self.clear_uniform_cache();
+    }
+}
+
+gl_enum! {
+    /// The GLSL 4.5 types of a program's active uniforms and attributes
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum UniformKind {
+        /// `float`
+        Float = gl::FLOAT,
+        /// `vec2`
+        FloatVec2 = gl::FLOAT_VEC2,
+        /// `vec3`
+        FloatVec3 = gl::FLOAT_VEC3,
+        /// `vec4`
+        FloatVec4 = gl::FLOAT_VEC4,
+        /// `double`
+        Double = gl::DOUBLE,
+        /// `dvec2`
+        DoubleVec2 = gl::DOUBLE_VEC2,
+        /// `dvec3`
+        DoubleVec3 = gl::DOUBLE_VEC3,
+        /// `dvec4`
+        DoubleVec4 = gl::DOUBLE_VEC4,
+        /// `int`
+        Int = gl::INT,
+        /// `ivec2`
+        IntVec2 = gl::INT_VEC2,
+        /// `ivec3`
+        IntVec3 = gl::INT_VEC3,
+        /// `ivec4`
+        IntVec4 = gl::INT_VEC4,
+        /// `uint`
+        UnsignedInt = gl::UNSIGNED_INT,
+        /// `uvec2`
+        UnsignedIntVec2 = gl::UNSIGNED_INT_VEC2,
+        /// `uvec3`
+        UnsignedIntVec3 = gl::UNSIGNED_INT_VEC3,
+        /// `uvec4`
+        UnsignedIntVec4 = gl::UNSIGNED_INT_VEC4,
+        /// `bool`
+        Bool = gl::BOOL,
+        /// `bvec2`
+        BoolVec2 = gl::BOOL_VEC2,
+        /// `bvec3`
+        BoolVec3 = gl::BOOL_VEC3,
+        /// `bvec4`
+        BoolVec4 = gl::BOOL_VEC4,
+        /// `mat2`
+        FloatMat2 = gl::FLOAT_MAT2,
+        /// `mat3`
+        FloatMat3 = gl::FLOAT_MAT3,
+        /// `mat4`
+        FloatMat4 = gl::FLOAT_MAT4,
+        /// `mat2x3`
+        FloatMat2x3 = gl::FLOAT_MAT2x3,
+        /// `mat2x4`
+        FloatMat2x4 = gl::FLOAT_MAT2x4,
+        /// `mat3x2`
+        FloatMat3x2 = gl::FLOAT_MAT3x2,
+        /// `mat3x4`
+        FloatMat3x4 = gl::FLOAT_MAT3x4,
+        /// `mat4x2`
+        FloatMat4x2 = gl::FLOAT_MAT4x2,
+        /// `mat4x3`
+        FloatMat4x3 = gl::FLOAT_MAT4x3,
+        /// `dmat2`
+        DoubleMat2 = gl::DOUBLE_MAT2,
+        /// `dmat3`
+        DoubleMat3 = gl::DOUBLE_MAT3,
+        /// `dmat4`
+        DoubleMat4 = gl::DOUBLE_MAT4,
+        /// `dmat2x3`
+        DoubleMat2x3 = gl::DOUBLE_MAT2x3,
+        /// `dmat2x4`
+        DoubleMat2x4 = gl::DOUBLE_MAT2x4,
+        /// `dmat3x2`
+        DoubleMat3x2 = gl::DOUBLE_MAT3x2,
+        /// `dmat3x4`
+        DoubleMat3x4 = gl::DOUBLE_MAT3x4,
+        /// `dmat4x2`
+        DoubleMat4x2 = gl::DOUBLE_MAT4x2,
+        /// `dmat4x3`
+        DoubleMat4x3 = gl::DOUBLE_MAT4x3,
+        /// `sampler1D`
+        Sampler1D = gl::SAMPLER_1D,
+        /// `sampler2D`
+        Sampler2D = gl::SAMPLER_2D,
+        /// `sampler3D`
+        Sampler3D = gl::SAMPLER_3D,
+        /// `samplerCube`
+        SamplerCube = gl::SAMPLER_CUBE,
+        /// `sampler1DArray`
+        Sampler1DArray = gl::SAMPLER_1D_ARRAY,
+        /// `sampler2DArray`
+        Sampler2DArray = gl::SAMPLER_2D_ARRAY,
+        /// `samplerCubeArray`
+        SamplerCubeArray = gl::SAMPLER_CUBE_MAP_ARRAY,
+        /// `sampler2DMS`
+        Sampler2DMultisample = gl::SAMPLER_2D_MULTISAMPLE,
+        /// `sampler2DMSArray`
+        Sampler2DMultisampleArray = gl::SAMPLER_2D_MULTISAMPLE_ARRAY,
+        /// `samplerBuffer`
+        SamplerBuffer = gl::SAMPLER_BUFFER,
+        /// `sampler2DRect`
+        Sampler2DRect = gl::SAMPLER_2D_RECT,
+        /// `sampler1DShadow`
+        Sampler1DShadow = gl::SAMPLER_1D_SHADOW,
+        /// `sampler2DShadow`
+        Sampler2DShadow = gl::SAMPLER_2D_SHADOW,
+        /// `samplerCubeShadow`
+        SamplerCubeShadow = gl::SAMPLER_CUBE_SHADOW,
+        /// `sampler1DArrayShadow`
+        Sampler1DArrayShadow = gl::SAMPLER_1D_ARRAY_SHADOW,
+        /// `sampler2DArrayShadow`
+        Sampler2DArrayShadow = gl::SAMPLER_2D_ARRAY_SHADOW,
+        /// `samplerCubeArrayShadow`
+        SamplerCubeArrayShadow = gl::SAMPLER_CUBE_MAP_ARRAY_SHADOW,
+        /// `sampler2DRectShadow`
+        Sampler2DRectShadow = gl::SAMPLER_2D_RECT_SHADOW,
+        /// `isampler1D`
+        IntSampler1D = gl::INT_SAMPLER_1D,
+        /// `isampler2D`
+        IntSampler2D = gl::INT_SAMPLER_2D,
+        /// `isampler3D`
+        IntSampler3D = gl::INT_SAMPLER_3D,
+        /// `isamplerCube`
+        IntSamplerCube = gl::INT_SAMPLER_CUBE,
+        /// `isampler1DArray`
+        IntSampler1DArray = gl::INT_SAMPLER_1D_ARRAY,
+        /// `isampler2DArray`
+        IntSampler2DArray = gl::INT_SAMPLER_2D_ARRAY,
+        /// `isamplerCubeArray`
+        IntSamplerCubeArray = gl::INT_SAMPLER_CUBE_MAP_ARRAY,
+        /// `isampler2DMS`
+        IntSampler2DMultisample = gl::INT_SAMPLER_2D_MULTISAMPLE,
+        /// `isampler2DMSArray`
+        IntSampler2DMultisampleArray = gl::INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+        /// `isamplerBuffer`
+        IntSamplerBuffer = gl::INT_SAMPLER_BUFFER,
+        /// `isampler2DRect`
+        IntSampler2DRect = gl::INT_SAMPLER_2D_RECT,
+        /// `usampler1D`
+        UnsignedIntSampler1D = gl::UNSIGNED_INT_SAMPLER_1D,
+        /// `usampler2D`
+        UnsignedIntSampler2D = gl::UNSIGNED_INT_SAMPLER_2D,
+        /// `usampler3D`
+        UnsignedIntSampler3D = gl::UNSIGNED_INT_SAMPLER_3D,
+        /// `usamplerCube`
+        UnsignedIntSamplerCube = gl::UNSIGNED_INT_SAMPLER_CUBE,
+        /// `usampler1DArray`
+        UnsignedIntSampler1DArray = gl::UNSIGNED_INT_SAMPLER_1D_ARRAY,
+        /// `usampler2DArray`
+        UnsignedIntSampler2DArray = gl::UNSIGNED_INT_SAMPLER_2D_ARRAY,
+        /// `usamplerCubeArray`
+        UnsignedIntSamplerCubeArray = gl::UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY,
+        /// `usampler2DMS`
+        UnsignedIntSampler2DMultisample = gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE,
+        /// `usampler2DMSArray`
+        UnsignedIntSampler2DMultisampleArray = gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+        /// `usamplerBuffer`
+        UnsignedIntSamplerBuffer = gl::UNSIGNED_INT_SAMPLER_BUFFER,
+        /// `usampler2DRect`
+        UnsignedIntSampler2DRect = gl::UNSIGNED_INT_SAMPLER_2D_RECT,
+        /// `image1D`
+        Image1D = gl::IMAGE_1D,
+        /// `image2D`
+        Image2D = gl::IMAGE_2D,
+        /// `image3D`
+        Image3D = gl::IMAGE_3D,
+        /// `imageCube`
+        ImageCube = gl::IMAGE_CUBE,
+        /// `image1DArray`
+        Image1DArray = gl::IMAGE_1D_ARRAY,
+        /// `image2DArray`
+        Image2DArray = gl::IMAGE_2D_ARRAY,
+        /// `imageCubeArray`
+        ImageCubeArray = gl::IMAGE_CUBE_MAP_ARRAY,
+        /// `image2DMS`
+        Image2DMultisample = gl::IMAGE_2D_MULTISAMPLE,
+        /// `image2DMSArray`
+        Image2DMultisampleArray = gl::IMAGE_2D_MULTISAMPLE_ARRAY,
+        /// `imageBuffer`
+        ImageBuffer = gl::IMAGE_BUFFER,
+        /// `image2DRect`
+        Image2DRect = gl::IMAGE_2D_RECT,
+        /// `iimage1D`
+        IntImage1D = gl::INT_IMAGE_1D,
+        /// `iimage2D`
+        IntImage2D = gl::INT_IMAGE_2D,
+        /// `iimage3D`
+        IntImage3D = gl::INT_IMAGE_3D,
+        /// `iimageCube`
+        IntImageCube = gl::INT_IMAGE_CUBE,
+        /// `iimage1DArray`
+        IntImage1DArray = gl::INT_IMAGE_1D_ARRAY,
+        /// `iimage2DArray`
+        IntImage2DArray = gl::INT_IMAGE_2D_ARRAY,
+        /// `iimageCubeArray`
+        IntImageCubeArray = gl::INT_IMAGE_CUBE_MAP_ARRAY,
+        /// `iimage2DMS`
+        IntImage2DMultisample = gl::INT_IMAGE_2D_MULTISAMPLE,
+        /// `iimage2DMSArray`
+        IntImage2DMultisampleArray = gl::INT_IMAGE_2D_MULTISAMPLE_ARRAY,
+        /// `iimageBuffer`
+        IntImageBuffer = gl::INT_IMAGE_BUFFER,
+        /// `iimage2DRect`
+        IntImage2DRect = gl::INT_IMAGE_2D_RECT,
+        /// `uimage1D`
+        UnsignedIntImage1D = gl::UNSIGNED_INT_IMAGE_1D,
+        /// `uimage2D`
+        UnsignedIntImage2D = gl::UNSIGNED_INT_IMAGE_2D,
+        /// `uimage3D`
+        UnsignedIntImage3D = gl::UNSIGNED_INT_IMAGE_3D,
+        /// `uimageCube`
+        UnsignedIntImageCube = gl::UNSIGNED_INT_IMAGE_CUBE,
+        /// `uimage1DArray`
+        UnsignedIntImage1DArray = gl::UNSIGNED_INT_IMAGE_1D_ARRAY,
+        /// `uimage2DArray`
+        UnsignedIntImage2DArray = gl::UNSIGNED_INT_IMAGE_2D_ARRAY,
+        /// `uimageCubeArray`
+        UnsignedIntImageCubeArray = gl::UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY,
+        /// `uimage2DMS`
+        UnsignedIntImage2DMultisample = gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE,
+        /// `uimage2DMSArray`
+        UnsignedIntImage2DMultisampleArray = gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY,
+        /// `uimageBuffer`
+        UnsignedIntImageBuffer = gl::UNSIGNED_INT_IMAGE_BUFFER,
+        /// `uimage2DRect`
+        UnsignedIntImage2DRect = gl::UNSIGNED_INT_IMAGE_2D_RECT,
+        /// `atomic_uint`
+        AtomicCounter = gl::UNSIGNED_INT_ATOMIC_COUNTER,
+    }
+}
+
+/// An active uniform of a linked program, see [LinkedProgram::active_uniforms](LinkedProgram::active_uniforms)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ActiveUniform {
+    /// The name of the uniform, without the `[0]` suffix OpenGL reports for arrays
+    pub name: String,
+    /// The location of the uniform, -1 for uniforms that are part of a uniform block
+    pub location: i32,
+    /// The type of the uniform, or of its elements for arrays
+    pub ty: UniformKind,
+    /// The number of elements for arrays, 1 otherwise
+    pub size: i32,
+}
+
+/// An active vertex attribute of a linked program, see [LinkedProgram::active_attributes](LinkedProgram::active_attributes)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ActiveAttribute {
+    /// The name of the attribute, without the `[0]` suffix OpenGL reports for arrays
+    pub name: String,
+    /// The location of the attribute, -1 for built-ins such as gl_VertexID
+    pub location: i32,
+    /// The type of the attribute, or of its elements for arrays
+    pub ty: UniformKind,
+    /// The number of elements for arrays, 1 otherwise
+    pub size: i32,
+}
+
+impl LinkedProgram {
+    /**
+    Enumerates all active uniforms of the program, i.e. the ones that weren't optimised out by the driver.
+
+    # Example
+    ```ignore
+    for uniform in linked.active_uniforms().expect("Unable to introspect program") {
+        println!("{} {}[{}] at {}", uniform.ty, uniform.name, uniform.size, uniform.location);
+    }
+    ```
+    */
+    pub fn active_uniforms(&self) -> Result<Vec<ActiveUniform>, IntrospectionError> {
+        self.query_active_resources(ResourceKind::Uniform)?
+            .into_iter()
+            .map(|uniform| {
+                Ok(ActiveUniform {
+                    ty: UniformKind::try_from(uniform.r#type)?,
+                    name: uniform.name,
+                    location: uniform.location,
+                    size: uniform.size,
+                })
+            })
+            .collect()
+    }
+
+    /// Enumerates all active vertex attributes of the program including built-ins, see [active_uniforms](LinkedProgram::active_uniforms)
+    pub fn active_attributes(&self) -> Result<Vec<ActiveAttribute>, IntrospectionError> {
+        self.query_active_resources(ResourceKind::Attribute)?
+            .into_iter()
+            .map(|attribute| {
+                Ok(ActiveAttribute {
+                    ty: UniformKind::try_from(attribute.r#type)?,
+                    name: attribute.name,
+                    location: attribute.location,
+                    size: attribute.size,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A subroutine that can be selected for a [SubroutineUniform]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Subroutine {
+    /// The index of the subroutine within its stage, which is what selects it for a subroutine uniform
+    pub index: u32,
+    /// The name of the subroutine function
+    pub name: String,
+}
+
+/// An active subroutine uniform of a single stage, see [LinkedProgram::active_subroutine_uniforms](LinkedProgram::active_subroutine_uniforms)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SubroutineUniform {
+    /// The name of the subroutine uniform, without the `[0]` suffix OpenGL reports for arrays
+    pub name: String,
+    /// The location of the subroutine uniform within its stage
+    pub location: i32,
+    /// The number of elements for arrays, 1 otherwise
+    pub size: i32,
+    /// The subroutines that can be selected for the uniform
+    pub compatible: Vec<Subroutine>,
+}
+
+impl LinkedProgram {
+    /// Queries a single parameter of the passed stage of the program via glGetProgramStageiv
+    fn get_stage_parameter(&self, stage: ShaderType, pname: gl::types::GLenum) -> Result<gl::types::GLint, IntrospectionError> {
+        let mut value = 0;
+
+        let rc = unsafe {
+            gl::GetProgramStageiv(self.get_id(), stage.as_glenum(), pname, &mut value);
+            super::get_error()
+        };
+
+        introspection_result(rc)?;
+
+        Ok(value)
+    }
+
+    /**
+    Enumerates the active subroutine uniforms of the passed stage of the program with the subroutines that can be
+    selected for each of them, e.g. to present the choices in an editor.
+
+    Requires OpenGL 4.0, fails with [Unsupported](IntrospectionError::Unsupported) otherwise.
+
+    # Example
+    ```ignore
+    // subroutine vec4 Shade(); subroutine uniform Shade u_shade; with the implementations flat and lit
+    let uniforms = linked.active_subroutine_uniforms(ShaderType::FragmentShader).expect("Unable to introspect program");
+    let names = uniforms[0].compatible.iter().map(|subroutine| subroutine.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"flat") && names.contains(&"lit"));
+    ```
+    */
+    pub fn active_subroutine_uniforms(&self, stage: ShaderType) -> Result<Vec<SubroutineUniform>, IntrospectionError> {
+        if !gl::GetActiveSubroutineUniformName::is_loaded() {
+            return Err(IntrospectionError::Unsupported);
+        }
+
+        let count = self.get_stage_parameter(stage, gl::ACTIVE_SUBROUTINE_UNIFORMS)?;
+        let uniform_capacity = self.get_stage_parameter(stage, gl::ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH)?.max(1);
+        let subroutine_capacity = self.get_stage_parameter(stage, gl::ACTIVE_SUBROUTINE_MAX_LENGTH)?.max(1);
+
+        let mut uniforms = Vec::with_capacity(count.max(0) as usize);
+
+        for index in 0..count.max(0) as gl::types::GLuint {
+            let mut name = vec![0u8; uniform_capacity as usize];
+            let mut length = 0;
+            let mut size = 0;
+            let mut compatible_count = 0;
+
+            let (location, rc) = unsafe {
+                gl::GetActiveSubroutineUniformName(
+                    self.get_id(),
+                    stage.as_glenum(),
+                    index,
+                    uniform_capacity,
+                    &mut length,
+                    name.as_mut_ptr() as *mut gl::types::GLchar,
+                );
+                gl::GetActiveSubroutineUniformiv(self.get_id(), stage.as_glenum(), index, gl::UNIFORM_SIZE, &mut size);
+                gl::GetActiveSubroutineUniformiv(
+                    self.get_id(),
+                    stage.as_glenum(),
+                    index,
+                    gl::NUM_COMPATIBLE_SUBROUTINES,
+                    &mut compatible_count,
+                );
+                let location =
+                    gl::GetSubroutineUniformLocation(self.get_id(), stage.as_glenum(), name.as_ptr() as *const gl::types::GLchar);
+                (location, super::get_error())
+            };
+
+            introspection_result(rc)?;
+
+            let mut indices = vec![0; compatible_count.max(0) as usize];
+
+            let rc = unsafe {
+                if !indices.is_empty() {
+                    gl::GetActiveSubroutineUniformiv(
+                        self.get_id(),
+                        stage.as_glenum(),
+                        index,
+                        gl::COMPATIBLE_SUBROUTINES,
+                        indices.as_mut_ptr(),
+                    );
+                }
+                super::get_error()
+            };
+
+            introspection_result(rc)?;
+
+            let compatible = indices
+                .into_iter()
+                .map(|subroutine| {
+                    let mut name = vec![0u8; subroutine_capacity as usize];
+                    let mut length = 0;
+
+                    let rc = unsafe {
+                        gl::GetActiveSubroutineName(
+                            self.get_id(),
+                            stage.as_glenum(),
+                            subroutine as gl::types::GLuint,
+                            subroutine_capacity,
+                            &mut length,
+                            name.as_mut_ptr() as *mut gl::types::GLchar,
+                        );
+                        super::get_error()
+                    };
+
+                    introspection_result(rc)?;
+
+                    Ok(Subroutine {
+                        index: subroutine as u32,
+                        name: resource_name(name, length),
+                    })
+                })
+                .collect::<Result<Vec<_>, IntrospectionError>>()?;
+
+            uniforms.push(SubroutineUniform {
+                name: resource_name(name, length),
+                location,
+                size,
+                compatible,
+            });
+        }
+
+        Ok(uniforms)
+    }
+}
+
+/// A member of a uniform block, see [UniformBlockReflection]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UniformBlockMember {
+    /// The name of the member as reported by OpenGL, without the `[0]` suffix of arrays
+    pub name: String,
+    /// The type of the member, or of its elements for arrays
+    pub ty: UniformKind,
+    /// The offset in bytes of the member from the start of the block
+    pub offset: u32,
+    /// The number of elements for arrays, 1 otherwise
+    pub array_size: i32,
+    /// The distance in bytes between two consecutive elements of arrays, 0 otherwise
+    pub array_stride: i32,
+    /// The distance in bytes between two consecutive columns (or rows for row-major layouts) of matrices, 0 otherwise
+    pub matrix_stride: i32,
+}
+
+/// The layout of a uniform block, see [LinkedProgram::reflect_uniform_block](LinkedProgram::reflect_uniform_block)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UniformBlockReflection {
+    /// The index of the block within the program
+    pub index: u32,
+    /// The uniform buffer binding point the block is sourced from
+    pub binding: u32,
+    /// The minimum size in bytes of a buffer backing the block
+    pub data_size: usize,
+    /// The active members of the block in the order of their offsets
+    pub members: Vec<UniformBlockMember>,
+}
+
+/// Error enum for the failed reflection of a uniform block
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum UniformBlockError {
+    /// Used if the program has no active uniform block with the passed name
+    #[error("No active uniform block named {0}")]
+    NotFound(String),
+    /// Used if the block's name couldn't be converted to a c-string because it contained a nul-byte
+    #[error("The block's name couldn't be converted to a CString: {source}")]
+    InvalidName {
+        #[from]
+        /// The underlying c-string conversion error
+        source: std::ffi::NulError,
+    },
+    /// Used if the block or its members couldn't be queried
+    #[error("Unable to query the uniform block: {source}")]
+    Introspection {
+        #[from]
+        /// The underlying introspection error
+        source: IntrospectionError,
+    },
+}
+
+/// The parameters queried for the members of a uniform block, in the order [block_members] expects their values
+const BLOCK_MEMBER_PARAMS: [gl::types::GLenum; 5] = [
+    gl::UNIFORM_TYPE,
+    gl::UNIFORM_OFFSET,
+    gl::UNIFORM_SIZE,
+    gl::UNIFORM_ARRAY_STRIDE,
+    gl::UNIFORM_MATRIX_STRIDE,
+];
+
+/// Builds the members of a uniform block from their names and the values of [BLOCK_MEMBER_PARAMS], one per member
+/// each, sorted by their offsets
+fn block_members(names: Vec<String>, params: &[Vec<gl::types::GLint>; 5]) -> Result<Vec<UniformBlockMember>, super::InvalidEnumValue> {
+    let [types, offsets, sizes, array_strides, matrix_strides] = params;
+
+    let mut members = names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            Ok(UniformBlockMember {
+                name,
+                ty: UniformKind::try_from(types[i] as gl::types::GLenum)?,
+                offset: offsets[i].max(0) as u32,
+                array_size: sizes[i],
+                array_stride: array_strides[i],
+                matrix_stride: matrix_strides[i],
+            })
+        })
+        .collect::<Result<Vec<_>, super::InvalidEnumValue>>()?;
+
+    members.sort_by_key(|member| member.offset);
+
+    Ok(members)
+}
+
+impl LinkedProgram {
+    /// Queries a single parameter of the uniform block at the passed index via glGetActiveUniformBlockiv
+    fn get_block_parameter(&self, index: gl::types::GLuint, pname: gl::types::GLenum) -> Result<gl::types::GLint, IntrospectionError> {
+        let mut value = 0;
+
+        let rc = unsafe {
+            gl::GetActiveUniformBlockiv(self.get_id(), index, pname, &mut value);
+            super::get_error()
+        };
+
+        introspection_result(rc)?;
+
+        Ok(value)
+    }
+
+    /**
+    Reflects the layout of the uniform block with the passed name, including the types, offsets and strides of its
+    members, e.g. to fill a uniform buffer from a material editor.
+
+    Members of blocks declared with an instance name are reported with the block's name as prefix, e.g. `Material.color`.
+
+    # Example
+    ```ignore
+    // layout(std140) uniform Material { vec4 color; float roughness; };
+    let block = linked.reflect_uniform_block("Material").expect("Unable to reflect block");
+    assert_eq!(block.members[0].offset, 0);
+    assert_eq!(block.members[1].offset, 16);
+    assert_eq!(block.members[1].ty, UniformKind::Float);
+    ```
+    */
+    pub fn reflect_uniform_block(&self, name: &str) -> Result<UniformBlockReflection, UniformBlockError> {
+        let cstr = std::ffi::CString::new(name)?;
+
+        let (index, rc) = unsafe {
+            let index = gl::GetUniformBlockIndex(self.get_id(), cstr.as_ptr());
+            (index, super::get_error())
+        };
+
+        introspection_result(rc)?;
+
+        if index == gl::INVALID_INDEX {
+            return Err(UniformBlockError::NotFound(name.to_owned()));
+        }
+
+        let binding = self.get_block_parameter(index, gl::UNIFORM_BLOCK_BINDING)?;
+        let data_size = self.get_block_parameter(index, gl::UNIFORM_BLOCK_DATA_SIZE)?;
+        let count = self.get_block_parameter(index, gl::UNIFORM_BLOCK_ACTIVE_UNIFORMS)?.max(0);
+        let capacity = self.active_uniform_max_length()?.max(1);
+
+        let mut indices = vec![0; count as usize];
+        let mut params: [Vec<gl::types::GLint>; 5] = Default::default();
+
+        let rc = unsafe {
+            if count > 0 {
+                gl::GetActiveUniformBlockiv(self.get_id(), index, gl::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES, indices.as_mut_ptr());
+            }
+            super::get_error()
+        };
+
+        introspection_result(rc)?;
+
+        let indices = indices.into_iter().map(|index| index as gl::types::GLuint).collect::<Vec<_>>();
+
+        for (pname, values) in BLOCK_MEMBER_PARAMS.iter().zip(params.iter_mut()) {
+            *values = vec![0; indices.len()];
+
+            let rc = unsafe {
+                if count > 0 {
+                    gl::GetActiveUniformsiv(self.get_id(), count, indices.as_ptr(), *pname, values.as_mut_ptr());
+                }
+                super::get_error()
+            };
+
+            introspection_result(rc)?;
+        }
+
+        let names = indices
+            .iter()
+            .map(|index| {
+                let mut name = vec![0u8; capacity];
+                let mut length = 0;
+
+                let rc = unsafe {
+                    gl::GetActiveUniformName(
+                        self.get_id(),
+                        *index,
+                        capacity as gl::types::GLsizei,
+                        &mut length,
+                        name.as_mut_ptr() as *mut gl::types::GLchar,
+                    );
+                    super::get_error()
+                };
+
+                introspection_result(rc)?;
+
+                Ok(resource_name(name, length))
+            })
+            .collect::<Result<Vec<_>, IntrospectionError>>()?;
+
+        Ok(UniformBlockReflection {
+            index,
+            binding: binding.max(0) as u32,
+            data_size: data_size.max(0) as usize,
+            members: block_members(names, &params).map_err(IntrospectionError::from)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a program that must not be dropped since that would delete it through the unloaded function
+    fn unloaded_program() -> LinkedProgram {
+        LinkedProgram {
+            inner: LinkedProgramInner {
+                program: ShaderProgram {
+                    inner: ShaderProgramInner { id: 3 },
+                },
+                uniform_locations: Default::default(),
+                #[cfg(feature = "hot-reload")]
+                watcher: None,
+            },
+        }
+    }
+
+    #[test]
+    fn debug_shows_only_the_id() {
+        let linked = unloaded_program();
+
+        assert_eq!(format!("{linked:?}"), "LinkedProgram { id: 3 }");
+        std::mem::forget(linked);
+    }
+
+    #[test]
+    fn snapshots_match_the_getters() {
+        // dropping would delete the program through the unloaded function
+        let program = ShaderProgram {
+            inner: ShaderProgramInner { id: 3 },
+        };
+
+        assert_eq!(program.snapshot().id, program.get_id());
+        std::mem::forget(program);
+    }
+
+    #[test]
+    fn uniform_variants_map_to_their_glsl_types() {
+        assert_eq!(UniformVariant::F32(0.5).kind(), UniformKind::Float);
+        assert_eq!(UniformVariant::Vec3([1.0; 3]).kind(), UniformKind::FloatVec3);
+        assert_eq!(UniformVariant::UVec2([1; 2]).kind(), UniformKind::UnsignedIntVec2);
+        assert_eq!(UniformVariant::BVec4([true; 4]).kind(), UniformKind::BoolVec4);
+        assert_eq!(UniformVariant::Mat4([0.0; 16]).kind(), UniformKind::FloatMat4);
+    }
+
+    #[test]
+    fn setting_uniforms_stops_at_missing_names() {
+        let linked = unloaded_program();
+        // a cached -1 is answered without reaching OpenGL
+        linked.inner.uniform_locations.borrow_mut().insert("u_missing".to_owned(), -1);
+
+        assert_eq!(
+            linked.set_uniforms(&[("u_missing", UniformVariant::F32(1.0))]),
+            Err(UniformError::NotFound("u_missing".to_owned()))
+        );
+        std::mem::forget(linked);
+    }
+
+    #[test]
+    fn subroutine_introspection_requires_gl_4_0() {
+        let linked = unloaded_program();
+
+        assert_eq!(
+            linked.active_subroutine_uniforms(ShaderType::FragmentShader),
+            Err(IntrospectionError::Unsupported)
+        );
+        std::mem::forget(linked);
+    }
+
+    #[test]
+    fn block_members_are_sorted_by_offset() {
+        // layout(std140) uniform Material { vec4 color; float roughness; mat4 transform; float weights[2]; }
+        // reported in a different order than they're declared in
+        let names = ["roughness", "weights", "color", "transform"].map(str::to_owned).to_vec();
+        let params = [
+            [gl::FLOAT, gl::FLOAT, gl::FLOAT_VEC4, gl::FLOAT_MAT4]
+                .map(|ty| ty as gl::types::GLint)
+                .to_vec(),
+            vec![16, 96, 0, 32],
+            vec![1, 2, 1, 1],
+            vec![0, 16, 0, 0],
+            vec![0, 0, 0, 16],
+        ];
+        let members = block_members(names, &params).unwrap();
+
+        let layout = members
+            .iter()
+            .map(|member| (member.name.as_str(), member.ty, member.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            layout,
+            [
+                ("color", UniformKind::FloatVec4, 0),
+                ("roughness", UniformKind::Float, 16),
+                ("transform", UniformKind::FloatMat4, 32),
+                ("weights", UniformKind::Float, 96),
+            ]
+        );
+        assert_eq!((members[3].array_size, members[3].array_stride), (2, 16));
+        assert_eq!(members[2].matrix_stride, 16);
+    }
+
+    #[test]
+    fn block_members_of_unknown_types_are_rejected() {
+        let params = [vec![gl::RGBA as gl::types::GLint], vec![0], vec![1], vec![0], vec![0]];
+
+        assert!(block_members(vec!["color".to_owned()], &params).is_err());
+    }
+
+    #[test]
+    fn uniform_readback_requires_the_sized_variants() {
+        // dropping would delete the program through the unloaded function
+        let linked = unloaded_program();
+
+        assert_eq!(linked.get_uniform_vec4(UniformLocation(0)), Err(UniformReadError::Unsupported));
+        assert_eq!(linked.get_uniform_i32(UniformLocation(0)), Err(UniformReadError::Unsupported));
+        assert_eq!(linked.get_uniform_u32(UniformLocation(0)), Err(UniformReadError::Unsupported));
+        std::mem::forget(linked);
+    }
+
+    /// Returns an active attribute at the passed location
+    fn attribute(name: &str, location: i32, r#type: gl::types::GLenum, size: i32) -> ActiveResource {
+        ActiveResource {
+            name: name.to_owned(),
+            location,
+            r#type,
+            size,
+        }
+    }
+
+    #[test]
+    fn matrix_attributes_consume_a_location_per_column() {
+        let attributes = [
+            attribute("position", 0, gl::FLOAT_VEC3, 1),
+            attribute("model", 1, gl::FLOAT_MAT4, 1),
+        ];
+
+        assert_eq!(vao_mismatch(&[0, 1, 2, 3, 4], &attributes), VaoMismatch::default());
+        // built-ins like gl_VertexID don't have a location
+        assert_eq!(
+            vao_mismatch(
+                &[0],
+                &[
+                    attribute("position", 0, gl::FLOAT_VEC3, 1),
+                    attribute("gl_VertexID", -1, gl::INT, 1)
+                ]
+            ),
+            VaoMismatch::default()
+        );
+    }
+
+    #[test]
+    fn missing_and_extra_locations_are_reported() {
+        let attributes = [attribute("position", 0, gl::FLOAT_VEC3, 1), attribute("weights", 2, gl::FLOAT, 2)];
+        let mismatch = vao_mismatch(&[0, 2, 5], &attributes);
+
+        assert_eq!(mismatch.missing, [("weights".to_owned(), 3)]);
+        assert_eq!(mismatch.extra, [5]);
+    }
+
+    #[test]
+    fn resource_names_strip_the_array_suffix() {
+        assert_eq!(resource_name(b"lights[0]".to_vec(), 9), "lights");
+        assert_eq!(resource_name(b"u_color".to_vec(), 7), "u_color");
+        // only the reported length is part of the name, the rest of the buffer is left over capacity
+        assert_eq!(resource_name(b"u_color\0\0\0".to_vec(), 7), "u_color");
+        assert_eq!(resource_name(b"lights[0]".to_vec(), 6), "lights");
+        // only the suffix of the outermost array is stripped
+        assert_eq!(resource_name(b"lights[1].color[0]".to_vec(), 18), "lights[1].color");
+    }
+
+    #[test]
+    fn uniform_kinds_round_trip() {
+        for kind in [
+            UniformKind::Float,
+            UniformKind::FloatVec4,
+            UniformKind::FloatMat4,
+            UniformKind::Int,
+            UniformKind::UnsignedInt,
+            UniformKind::Bool,
+            UniformKind::Sampler2D,
+        ] {
+            assert_eq!(UniformKind::try_from(kind.as_glenum()), Ok(kind));
+        }
+
+        assert!(UniformKind::try_from(gl::RGBA).is_err());
+    }
+}
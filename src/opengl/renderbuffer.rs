@@ -0,0 +1,252 @@
+/*!
+The opengl renderbuffer module provides idiomatic bindings to OpenGL renderbuffer objects.
+
+Renderbuffers are images that can only be rendered into and not be sampled, which makes them a good fit for depth and
+stencil attachments of framebuffers that are only needed while rendering.
+You can read more about renderbuffers here: <https://www.khronos.org/opengl/wiki/Renderbuffer_Object>
+*/
+
+gl_enum! {
+    /// The formats of renderbuffers used as depth and/or stencil attachments
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum RenderbufferFormat {
+        /// A 24-bit depth component and an 8-bit stencil index per pixel
+        Depth24Stencil8 = gl::DEPTH24_STENCIL8,
+        /// A 32-bit floating point depth component per pixel
+        Depth32F = gl::DEPTH_COMPONENT32F,
+        /// An 8-bit stencil index per pixel
+        Stencil8 = gl::STENCIL_INDEX8,
+    }
+}
+
+impl RenderbufferFormat {
+    /// Returns the framebuffer attachment point a renderbuffer of this format has to be attached to
+    pub fn attachment_point(self) -> gl::types::GLenum {
+        match self {
+            RenderbufferFormat::Depth24Stencil8 => gl::DEPTH_STENCIL_ATTACHMENT,
+            RenderbufferFormat::Depth32F => gl::DEPTH_ATTACHMENT,
+            RenderbufferFormat::Stencil8 => gl::STENCIL_ATTACHMENT,
+        }
+    }
+}
+
+/// Stores the underlying data of a renderbuffer
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [Renderbuffer](Renderbuffer) struct.
+#[derive(Debug)]
+pub struct RenderbufferInner {
+    /// The id of the renderbuffer, generated by OpenGL and valid for the lifetime of the renderbuffer
+    pub id: gl::types::GLuint,
+}
+
+/// A copy of the publicly relevant data of a renderbuffer, see [Renderbuffer::snapshot](Renderbuffer::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct RenderbufferSnapshot {
+    /// The id of the renderbuffer, generated by OpenGL. Only valid as long as the renderbuffer it was taken from is alive
+    pub id: gl::types::GLuint,
+}
+
+impl PartialEq for RenderbufferInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for RenderbufferInner {}
+
+impl std::hash::Hash for RenderbufferInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for renderbuffers to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for RenderbufferInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RenderbufferInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A renderbuffer is an image that can be attached to a framebuffer and rendered into, but not sampled.
+
+# Example
+```ignore
+let depth_stencil = Renderbuffer::new().expect("Unable to create renderbuffer");
+depth_stencil.storage(RenderbufferFormat::Depth24Stencil8, 256, 256).expect("Unable to allocate renderbuffer");
+framebuffer.attach_depth_stencil(&depth_stencil).expect("Unable to attach renderbuffer");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Renderbuffer {
+    inner: RenderbufferInner,
+}
+
+impl std::fmt::Debug for Renderbuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Renderbuffer").field("id", &self.inner.id).finish()
+    }
+}
+
+impl Renderbuffer {
+    /// Returns a reference to the inner (private) data of the renderbuffer.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &RenderbufferInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the renderbuffer.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut RenderbufferInner {
+        &mut self.inner
+    }
+
+    /// Retrieves the id of the renderbuffer.
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the renderbuffer's id, which is the safe alternative to [inner](Renderbuffer::inner) for reading.
+    pub fn snapshot(&self) -> RenderbufferSnapshot {
+        RenderbufferSnapshot { id: self.inner.id }
+    }
+
+    /// Binds the renderbuffer to GL_RENDERBUFFER
+    fn bind(&self) {
+        unsafe { gl_call!(gl::BindRenderbuffer(gl::RENDERBUFFER, self.inner.id)) };
+    }
+
+    /// Retrieves the format of the renderbuffer's storage, which is `Ok(None)` if no storage was allocated yet.
+    pub fn get_format(&self) -> Result<Option<RenderbufferFormat>, super::InvalidEnumValue> {
+        let mut format = 0;
+        let mut width = 0;
+
+        self.bind();
+
+        unsafe {
+            gl_call!(gl::GetRenderbufferParameteriv(
+                gl::RENDERBUFFER,
+                gl::RENDERBUFFER_INTERNAL_FORMAT,
+                &mut format
+            ));
+            gl_call!(gl::GetRenderbufferParameteriv(gl::RENDERBUFFER, gl::RENDERBUFFER_WIDTH, &mut width));
+        }
+
+        // without storage the internal format is reported as GL_RGBA
+        if width == 0 {
+            return Ok(None);
+        }
+
+        RenderbufferFormat::try_from(format as gl::types::GLenum).map(Some)
+    }
+}
+
+impl super::GlObject for Renderbuffer {
+    const SHAREABLE: bool = true;
+}
+
+impl Drop for Renderbuffer {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteRenderbuffers(1, &self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a renderbuffer
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum RenderbufferCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Renderbuffer {
+    /// Returns a new renderbuffer or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+    pub fn new() -> Result<Renderbuffer, RenderbufferCreationError> {
+        let mut id = 0;
+
+        unsafe { gl_call!(gl::GenRenderbuffers(1, &mut id)) };
+
+        if id == 0 {
+            return Err(RenderbufferCreationError::Unknown);
+        }
+
+        Ok(Renderbuffer {
+            inner: RenderbufferInner { id },
+        })
+    }
+}
+
+/// Error enum for the failed allocation of a renderbuffer's storage
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum RenderbufferStorageError {
+    /// Used if the dimensions exceed GL_MAX_RENDERBUFFER_SIZE
+    #[error("The renderbuffer's dimensions exceed the maximum supported renderbuffer size")]
+    TooLarge,
+    /// Used if OpenGL was unable to allocate the memory for the storage
+    #[error("OpenGL ran out of memory while allocating the renderbuffer's storage")]
+    OutOfMemory,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Renderbuffer {
+    /// Allocates the renderbuffer's storage with the passed format and dimensions, replacing any previous storage.
+    pub fn storage(&self, format: RenderbufferFormat, width: u32, height: u32) -> Result<(), RenderbufferStorageError> {
+        let width = gl::types::GLsizei::try_from(width).map_err(|_| RenderbufferStorageError::TooLarge)?;
+        let height = gl::types::GLsizei::try_from(height).map_err(|_| RenderbufferStorageError::TooLarge)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::RenderbufferStorage(gl::RENDERBUFFER, format.as_glenum(), width, height);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_VALUE => Err(RenderbufferStorageError::TooLarge),
+            gl::OUT_OF_MEMORY => Err(RenderbufferStorageError::OutOfMemory),
+            _ => Err(RenderbufferStorageError::Unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_shows_the_id() {
+        // dropping would delete the renderbuffer through the unloaded function
+        let renderbuffer = Renderbuffer {
+            inner: RenderbufferInner { id: 6 },
+        };
+
+        assert_eq!(format!("{renderbuffer:?}"), "Renderbuffer { id: 6 }");
+        std::mem::forget(renderbuffer);
+    }
+
+    #[test]
+    fn snapshot_matches_the_getters() {
+        // dropping would delete the renderbuffer through the unloaded function
+        let renderbuffer = Renderbuffer {
+            inner: RenderbufferInner { id: 6 },
+        };
+
+        assert_eq!(renderbuffer.snapshot().id, renderbuffer.get_id());
+        std::mem::forget(renderbuffer);
+    }
+}
@@ -0,0 +1,296 @@
+/*!
+The opengl resource module provides a central place to own OpenGL objects and refer to them by lightweight handles.
+
+A [ResourceManager] stores objects of one type and hands out generational [Handle]s for them. Once an object is removed
+its slot is reused with a new generation, so handles to the removed object fail to look anything up instead of silently
+referring to whatever object took its place, which is what happens to raw OpenGL ids.
+*/
+
+use super::GlObject;
+use std::marker::PhantomData;
+
+/**
+A lightweight reference to an object stored in a [ResourceManager].
+
+Handles are only meaningful for the manager that returned them and stay valid until their object is removed.
+
+# Example
+```ignore
+let mut textures = ResourceManager::new();
+let handle = textures.insert(Texture2D::new().expect("Unable to create texture"));
+let copy = handle;
+assert_eq!(textures.get(copy).map(Texture2D::get_id), textures.get(handle).map(Texture2D::get_id));
+```
+*/
+pub struct Handle<T> {
+    index: u32,
+    generation: u32,
+    // fn() -> T keeps the handle Send and Sync regardless of T, it never owns a T
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Handle<T> {
+    /// Returns the index of the slot the object is stored in
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the generation of the slot at the time the object was inserted
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+// implemented by hand since deriving would require T to implement the traits as well
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.index, self.generation) == (other.index, other.generation)
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> std::hash::Hash for Handle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.index, self.generation).hash(state)
+    }
+}
+
+impl<T> std::fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Handle")
+            .field("index", &self.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+/// A slot of a [ResourceManager], the generation is bumped whenever its object is removed
+#[derive(Debug)]
+struct Slot<T> {
+    generation: u32,
+    object: Option<T>,
+}
+
+/**
+Owns OpenGL objects of a single type and refers to them through generational [Handle]s.
+
+Objects are deleted once they're dropped, either after being [removed](ResourceManager::remove) or together with the
+manager.
+
+# Example
+```ignore
+let mut textures = ResourceManager::new();
+let handle = textures.insert(Texture2D::new().expect("Unable to create texture"));
+
+textures.get(handle).expect("The texture was removed").bind_unit(0).expect("Unable to bind texture");
+
+// dropping the removed texture deletes it
+drop(textures.remove(handle));
+assert!(textures.get(handle).is_none());
+```
+*/
+#[derive(Debug)]
+pub struct ResourceManager<T: GlObject> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+}
+
+impl<T: GlObject> Default for ResourceManager<T> {
+    fn default() -> Self {
+        ResourceManager {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+}
+
+impl<T: GlObject> ResourceManager<T> {
+    /// Returns an empty manager
+    pub fn new() -> ResourceManager<T> {
+        ResourceManager::default()
+    }
+
+    /// Stores the object and returns a handle to it, reusing the slot of a removed object if there is one.
+    pub fn insert(&mut self, object: T) -> Handle<T> {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    object: None,
+                });
+                (self.slots.len() - 1) as u32
+            }
+        };
+
+        let slot = &mut self.slots[index as usize];
+        slot.object = Some(object);
+
+        Handle {
+            index,
+            generation: slot.generation,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the slot the handle refers to if its object is still stored
+    fn slot(&self, handle: Handle<T>) -> Option<&Slot<T>> {
+        self.slots
+            .get(handle.index as usize)
+            .filter(|slot| slot.generation == handle.generation && slot.object.is_some())
+    }
+
+    /// Returns the object the handle refers to, `None` if it was removed.
+    pub fn get(&self, handle: Handle<T>) -> Option<&T> {
+        self.slot(handle).and_then(|slot| slot.object.as_ref())
+    }
+
+    /// Returns the object the handle refers to mutably, `None` if it was removed.
+    pub fn get_mut(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        self.slot(handle)?;
+        self.slots[handle.index as usize].object.as_mut()
+    }
+
+    /// Returns whether or not the object the handle refers to is still stored
+    pub fn contains(&self, handle: Handle<T>) -> bool {
+        self.slot(handle).is_some()
+    }
+
+    /// Removes the object the handle refers to and returns it, `None` if it was already removed.
+    /// Dropping the returned object deletes it, all handles to it fail to look anything up from now on.
+    pub fn remove(&mut self, handle: Handle<T>) -> Option<T> {
+        self.slot(handle)?;
+
+        let slot = &mut self.slots[handle.index as usize];
+        // the generation only wraps after 2^32 removals from the same slot
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(handle.index);
+
+        slot.object.take()
+    }
+
+    /// Returns the number of stored objects
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns whether or not no objects are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the handles and objects of all stored objects in the order of their slots
+    pub fn iter(&self) -> impl Iterator<Item = (Handle<T>, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.object.as_ref().map(|object| {
+                (
+                    Handle {
+                        index: index as u32,
+                        generation: slot.generation,
+                        marker: PhantomData,
+                    },
+                    object,
+                )
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Stands in for an OpenGL object, counting how often objects were deleted
+    struct Dummy {
+        id: u32,
+        deleted: Rc<Cell<u32>>,
+    }
+
+    impl GlObject for Dummy {
+        const SHAREABLE: bool = true;
+    }
+
+    impl Drop for Dummy {
+        fn drop(&mut self) {
+            self.deleted.set(self.deleted.get() + 1);
+        }
+    }
+
+    #[test]
+    fn objects_are_looked_up_by_handle() {
+        let deleted = Rc::new(Cell::new(0));
+        let mut manager = ResourceManager::new();
+
+        let first = manager.insert(Dummy {
+            id: 1,
+            deleted: deleted.clone(),
+        });
+        let second = manager.insert(Dummy {
+            id: 2,
+            deleted: deleted.clone(),
+        });
+
+        assert_eq!(manager.get(first).map(|dummy| dummy.id), Some(1));
+        assert_eq!(manager.get(second).map(|dummy| dummy.id), Some(2));
+        assert_eq!(manager.iter().map(|(handle, _)| handle).collect::<Vec<_>>(), [first, second]);
+        assert_eq!(manager.len(), 2);
+    }
+
+    #[test]
+    fn removed_objects_are_deleted() {
+        let deleted = Rc::new(Cell::new(0));
+        let mut manager = ResourceManager::new();
+        let handle = manager.insert(Dummy {
+            id: 1,
+            deleted: deleted.clone(),
+        });
+
+        drop(manager.remove(handle));
+
+        assert_eq!(deleted.get(), 1);
+        assert!(manager.is_empty());
+        assert!(manager.remove(handle).is_none());
+
+        manager.insert(Dummy {
+            id: 2,
+            deleted: deleted.clone(),
+        });
+        drop(manager);
+
+        assert_eq!(deleted.get(), 2);
+    }
+
+    #[test]
+    fn stale_handles_fail_to_look_up_the_reused_slot() {
+        let deleted = Rc::new(Cell::new(0));
+        let mut manager = ResourceManager::new();
+
+        let stale = manager.insert(Dummy {
+            id: 1,
+            deleted: deleted.clone(),
+        });
+        drop(manager.remove(stale));
+        let fresh = manager.insert(Dummy {
+            id: 2,
+            deleted: deleted.clone(),
+        });
+
+        assert_eq!(fresh.get_index(), stale.get_index());
+        assert_ne!(fresh, stale);
+        assert!(manager.get(stale).is_none());
+        assert!(manager.get_mut(stale).is_none());
+        assert!(!manager.contains(stale));
+        assert_eq!(manager.get(fresh).map(|dummy| dummy.id), Some(2));
+    }
+}
@@ -13,38 +13,62 @@ We do want to model this by providing both a Shader and a CompiledShader type wh
 or not a shader was compiled before being attached to a ShaderProgram.
 */
 
-/**
-Wraps the different OpenGL shader types.
+gl_enum! {
+    /**
+    Wraps the different OpenGL shader types.
 
-OpenGL supports 6 different types of shaders for varying stages of the pipeline.
-You can read more about shaders here: <https://www.khronos.org/opengl/wiki/Shader>
-And more about the pipeline here: <https://www.khronos.org/opengl/wiki/Rendering_Pipeline_Overview>
+    OpenGL supports 6 different types of shaders for varying stages of the pipeline.
+    You can read more about shaders here: <https://www.khronos.org/opengl/wiki/Shader>
+    And more about the pipeline here: <https://www.khronos.org/opengl/wiki/Rendering_Pipeline_Overview>
 
-# Examples
-```
-// creates a compute-shader
-let r#type = ShaderType::Compute;
-let shader = Shader::new(r#type).expect("Unable to create shader");
-```
-*/
+    # Examples
+    ```ignore
+    // creates a compute-shader
+    let r#type = ShaderType::Compute;
+    let shader = Shader::new(r#type).expect("Unable to create shader");
+    ```
+    */
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum ShaderType {
+        /// Used for [OpenGL compute shaders](https://www.khronos.org/opengl/wiki/Compute_Shader)
+        Compute = gl::COMPUTE_SHADER,
+        /// Used for [OpenGL fragment shaders](https://www.khronos.org/opengl/wiki/Fragment_Shader)
+        FragmentShader = gl::FRAGMENT_SHADER,
+        /// Used for [OpenGL geometry shaders](https://www.khronos.org/opengl/wiki/Geometry_Shader)
+        GeometryShader = gl::GEOMETRY_SHADER,
+        /// Used for [OpenGL tesselation control shaders](https://www.khronos.org/opengl/wiki/Tessellation_Control_Shader)
+        TessControl = gl::TESS_CONTROL_SHADER,
+        /// Used for [OpenGL tesselation evaluation shaders](https://www.khronos.org/opengl/wiki/Tessellation_Evaluation_Shader)
+        TessEvaluation = gl::TESS_EVALUATION_SHADER,
+        /// Used for [OpenGL vertex shaders](https://www.khronos.org/opengl/wiki/Vertex_Shader)
+        Vertex = gl::VERTEX_SHADER,
+    }
+}
 
-// sadly you cannot use repr with type aliases, so we cannot use repr(gl::types::GLenum)
-// it might be safer to generate to & from versions of this, although I doubt the OpenGL type for GLenum will ever change
-#[repr(u32)]
-#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
-pub enum ShaderType {
-    /// Used for [OpenGL compute shaders](https://www.khronos.org/opengl/wiki/Compute_Shader)
-    Compute = gl::COMPUTE_SHADER,
-    /// Used for [OpenGL fragment shaders](https://www.khronos.org/opengl/wiki/Fragment_Shader)
-    FragmentShader = gl::FRAGMENT_SHADER,
-    /// Used for [OpenGL geometry shaders](https://www.khronos.org/opengl/wiki/Geometry_Shader)
-    GeometryShader = gl::GEOMETRY_SHADER,
-    /// Used for [OpenGL tesselation control shaders](https://www.khronos.org/opengl/wiki/Tessellation_Control_Shader)
-    TessControl = gl::TESS_CONTROL_SHADER,
-    /// Used for [OpenGL tesselation evaluation shaders](https://www.khronos.org/opengl/wiki/Tessellation_Evaluation_Shader)
-    TessEvaluation = gl::TESS_EVALUATION_SHADER,
-    /// Used for [OpenGL vertex shaders](https://www.khronos.org/opengl/wiki/Vertex_Shader)
-    Vertex = gl::VERTEX_SHADER,
+impl ShaderType {
+    /**
+    Returns the type of a shader stored at the passed path according to its extension, i.e. `.vert`, `.tesc`, `.tese`,
+    `.geom`, `.frag` or `.comp`, or None if the extension is none of them.
+
+    # Example
+    ```
+    use alloy_graphene::opengl::shader::ShaderType;
+
+    assert_eq!(ShaderType::from_path("shaders/sprite.frag"), Some(ShaderType::FragmentShader));
+    assert_eq!(ShaderType::from_path("shaders/common.glsl"), None);
+    ```
+    */
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> Option<ShaderType> {
+        match path.as_ref().extension()?.to_str()? {
+            "vert" => Some(ShaderType::Vertex),
+            "tesc" => Some(ShaderType::TessControl),
+            "tese" => Some(ShaderType::TessEvaluation),
+            "geom" => Some(ShaderType::GeometryShader),
+            "frag" => Some(ShaderType::FragmentShader),
+            "comp" => Some(ShaderType::Compute),
+            _ => None,
+        }
+    }
 }
 
 /// Stores the underlying data of a shader
@@ -58,6 +82,15 @@ pub struct ShaderInner {
     pub r#type: ShaderType,
 }
 
+/// A copy of the publicly relevant data of a shader, see [Shader::snapshot](Shader::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ShaderSnapshot {
+    /// The id of the shader, generated by OpenGL. Only valid as long as the shader it was taken from is alive
+    pub id: gl::types::GLuint,
+    /// The type of the shader. See [ShaderType](ShaderType) for more information
+    pub r#type: ShaderType,
+}
+
 impl PartialEq for ShaderInner {
     fn eq(&self, other: &Self) -> bool {
         self.id.eq(&other.id)
@@ -95,27 +128,42 @@ For our purposes a shader has to be setup, compiled and is then attached to a sh
 We model this with the newtype patern by having both a Shader class and a [CompiledShader](CompiledShader) class.
 
 # Example
-```
+```ignore
 let shader = Shader::new(ShaderType::Vertex).expect("Unable to create vertex shader");
 let compiled = shader.compile(shader_src).expect("Unable to compile vertex shader");
 let program = ShaderProgram::new().expect("Unable to create shader program");
-let linked = program.link(IntoIter::new(&[shader])).expect("Unable to link shader program");
+let linked = program.link([compiled]).expect("Unable to link shader program");
 ```
 */
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Shader {
     inner: ShaderInner,
 }
 
+impl std::fmt::Debug for Shader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Shader")
+            .field("id", &self.inner.id)
+            .field("type", &self.inner.r#type)
+            .finish()
+    }
+}
+
 impl Shader {
     /// Returns a reference to the inner (private) data of the shader.
     /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
     pub unsafe fn inner(&self) -> &ShaderInner {
         &self.inner
     }
 
     /// Returns a reference to the inner (private) data of the shader.
     /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
     pub unsafe fn inner_mut(&mut self) -> &mut ShaderInner {
         &mut self.inner
     }
@@ -126,7 +174,7 @@ impl Shader {
     Retrieves the id of the shader.
 
     # Example
-    ```
+    ```ignore
     let shader = Shader::new(ShaderType::Compute).expect("Unable to create compute shader");
     assert_eq!(shader.get_id(), 1); // example, YMMV
     ```
@@ -139,7 +187,7 @@ impl Shader {
     Retrieves the type of the shader.
 
     # Example
-    ```
+    ```ignore
     let shader = Shader::new(ShaderType::Compute).expect("Unable to create compute shader");
     assert_eq!(shader.get_type(), ShaderType::Compute);
     ```
@@ -147,6 +195,35 @@ impl Shader {
     pub fn get_type(&self) -> ShaderType {
         self.inner.r#type
     }
+
+    /**
+    Returns a copy of the shader's id and type, which is the safe alternative to [inner](Shader::inner) for reading.
+
+    # Example
+    ```ignore
+    let shader = Shader::new(ShaderType::Compute).expect("Unable to create compute shader");
+    let snapshot = shader.snapshot();
+    assert_eq!(snapshot.id, shader.get_id());
+    assert_eq!(snapshot.r#type, shader.get_type());
+    ```
+    */
+    pub fn snapshot(&self) -> ShaderSnapshot {
+        ShaderSnapshot {
+            id: self.inner.id,
+            r#type: self.inner.r#type,
+        }
+    }
+}
+
+impl super::GlObject for Shader {
+    const SHAREABLE: bool = true;
+}
+
+// A compiled shader owns the shader it was compiled from, so dropping it deletes the shader exactly once through this impl
+impl Drop for Shader {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteShader(self.inner.id)) };
+    }
 }
 
 /// Error enum for the failed creation of a shader
@@ -164,10 +241,10 @@ pub enum ShaderCreationError {
 impl Shader {
     /// Returns a new shader or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
     pub fn new(r#type: ShaderType) -> Result<Shader, ShaderCreationError> {
-        let id = unsafe { gl::CreateShader(r#type as _) };
+        let id = unsafe { gl::CreateShader(r#type.as_glenum()) };
 
         if id == 0 {
-            return Err(match unsafe { gl::GetError() } {
+            return Err(match super::get_error() {
                 gl::INVALID_ENUM => ShaderCreationError::InvalidEnum(r#type),
                 _ => ShaderCreationError::Unknown,
             });
@@ -214,11 +291,193 @@ pub enum ShaderCompileError {
     /// Used if the underlying object was not recognised as an OpenGL shader
     #[error("The underlying object was not recognised as an OpenGL shader")]
     NotAShader,
+    /// Used if the name of a define passed to [compile_with_defines](Shader::compile_with_defines) isn't a valid GLSL identifier
+    #[error("Invalid define name: {0}")]
+    InvalidDefineName(String),
     /// Used if the underlying OpenGL error is unknown to graphene
     #[error("Unknown Error")]
     Unknown,
 }
 
+impl Shader {
+    /**
+    Compiles the passed source as a throwaway shader of the passed type and returns the driver's diagnostics.
+
+    Meant for linters and CI, the shader is deleted before returning. Warnings reported for valid sources are returned
+    as diagnostics, a log whose format isn't recognised as a single [Info](Severity::Info) diagnostic. A failed compilation
+    is returned as an error whose [diagnostics](ShaderCompileError::diagnostics) contain the reported errors.
+
+    # Example
+    ```ignore
+    match Shader::check(ShaderType::FragmentShader, shader_src) {
+        Ok(warnings) => warnings.iter().for_each(|w| log::warn!("{}: {}", w.line, w.message)),
+        Err(e) => log::error!("{:?}", e.diagnostics()),
+    }
+    ```
+    */
+    pub fn check<S: AsRef<str>>(type_: ShaderType, src: S) -> Result<Vec<ShaderDiagnostic>, ShaderCompileError> {
+        // creation only fails for reasons unknown to graphene
+        let shader = Shader::new(type_).map_err(|_| ShaderCompileError::Unknown)?;
+        let (_, log) = shader.compile_with_log(src)?;
+
+        // some drivers report a log consisting of nothing but whitespace for clean compilations
+        if log.iter().all(|line| line.trim().is_empty()) {
+            return Ok(Vec::new());
+        }
+
+        // logs of successful compilations that aren't recognised are usually status messages such as
+        // "Vertex shader was successfully compiled to run on hardware" rather than errors
+        Ok(parse_diagnostics_with(&log.join("\n"), Severity::Info))
+    }
+}
+
+impl ShaderCompileError {
+    /**
+    Parses the driver's message into structured diagnostics if this is a [CompilationError](ShaderCompileError::CompilationError),
+    see [parse_diagnostics] for the supported formats.
+
+    # Example
+    ```ignore
+    if let Err(e) = shader.compile(src) {
+        for diagnostic in e.diagnostics().unwrap_or_default() {
+            println!("{}: {}", diagnostic.line, diagnostic.message);
+        }
+    }
+    ```
+    */
+    pub fn diagnostics(&self) -> Option<Vec<ShaderDiagnostic>> {
+        match self {
+            ShaderCompileError::CompilationError(log) => Some(parse_diagnostics(log)),
+            _ => None,
+        }
+    }
+}
+
+/// The severity of a single compiler diagnostic
+#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The diagnostic caused the compilation to fail
+    #[strum(serialize = "error")]
+    Error,
+    /// The diagnostic is a warning that didn't prevent the compilation
+    #[strum(serialize = "warning")]
+    Warning,
+    /// The diagnostic is purely informational
+    #[strum(serialize = "info")]
+    Info,
+}
+
+/// A single message of a shader's compilation log
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ShaderDiagnostic {
+    /// The (1-based) source line the diagnostic refers to, 0 if the driver's format wasn't recognised
+    pub line: u32,
+    /// The (1-based) column the diagnostic refers to, if reported by the driver
+    pub column: Option<u32>,
+    /// The severity of the diagnostic
+    pub severity: Severity,
+    /// The driver's message with the location and severity stripped
+    pub message: String,
+}
+
+/**
+Parses a shader's compilation log into structured diagnostics.
+
+Recognises the formats used by NVIDIA (`0(12) : error C0000: ...`) and Mesa/Intel (`0:12(4): error: ...`).
+Lines that don't match either format are appended to the preceding diagnostic's message.
+If no line is recognised at all, the complete log is returned as a single error diagnostic on line 0.
+
+# Example
+```
+use alloy_graphene::opengl::shader::{parse_diagnostics, Severity};
+
+let nvidia = parse_diagnostics("0(12) : error C0000: syntax error, unexpected '}'\n0(3) : warning C7022: unrecognized profile\n");
+assert_eq!(nvidia.len(), 2);
+assert_eq!((nvidia[0].line, nvidia[0].column, nvidia[0].severity), (12, None, Severity::Error));
+assert_eq!(nvidia[0].message, "C0000: syntax error, unexpected '}'");
+assert_eq!(nvidia[1].severity, Severity::Warning);
+
+let mesa = parse_diagnostics("0:12(4): error: `foo' undeclared\n");
+assert_eq!((mesa[0].line, mesa[0].column, mesa[0].severity), (12, Some(4), Severity::Error));
+assert_eq!(mesa[0].message, "`foo' undeclared");
+
+let unknown = parse_diagnostics("something went wrong");
+assert_eq!((unknown[0].line, unknown[0].column), (0, None));
+assert_eq!(unknown[0].message, "something went wrong");
+```
+*/
+pub fn parse_diagnostics(log: &str) -> Vec<ShaderDiagnostic> {
+    parse_diagnostics_with(log, Severity::Error)
+}
+
+/// Parses a compilation log like [parse_diagnostics], reporting an unrecognised log with the passed severity
+fn parse_diagnostics_with(log: &str, unrecognised: Severity) -> Vec<ShaderDiagnostic> {
+    let mut diagnostics: Vec<ShaderDiagnostic> = Vec::new();
+
+    for line in log.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match parse_nvidia_diagnostic(line).or_else(|| parse_mesa_diagnostic(line)) {
+            Some(diagnostic) => diagnostics.push(diagnostic),
+            None => {
+                if let Some(last) = diagnostics.last_mut() {
+                    last.message.push('\n');
+                    last.message.push_str(line);
+                }
+            }
+        }
+    }
+
+    if diagnostics.is_empty() {
+        diagnostics.push(ShaderDiagnostic {
+            line: 0,
+            column: None,
+            severity: unrecognised,
+            message: log.trim().to_string(),
+        });
+    }
+
+    diagnostics
+}
+
+fn parse_severity(s: &str) -> Option<Severity> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "error" | "fatal error" => Some(Severity::Error),
+        "warning" => Some(Severity::Warning),
+        "info" | "note" => Some(Severity::Info),
+        _ => None,
+    }
+}
+
+/// Parses NVIDIA's `<string>(<line>) : <severity> <message>` format
+fn parse_nvidia_diagnostic(line: &str) -> Option<ShaderDiagnostic> {
+    let (location, rest) = line.split_once(" : ")?;
+    let (string, line_number) = location.strip_suffix(')')?.split_once('(')?;
+    string.parse::<u32>().ok()?;
+    let (severity, message) = rest.split_once(' ')?;
+
+    Some(ShaderDiagnostic {
+        line: line_number.parse().ok()?,
+        column: None,
+        severity: parse_severity(severity)?,
+        message: message.trim().to_string(),
+    })
+}
+
+/// Parses Mesa's `<string>:<line>(<column>): <severity>: <message>` format
+fn parse_mesa_diagnostic(line: &str) -> Option<ShaderDiagnostic> {
+    let (location, rest) = line.split_once("): ")?;
+    let (position, column) = location.split_once('(')?;
+    let (string, line_number) = position.split_once(':')?;
+    string.parse::<u32>().ok()?;
+    let (severity, message) = rest.split_once(':')?;
+
+    Some(ShaderDiagnostic {
+        line: line_number.parse().ok()?,
+        column: Some(column.parse().ok()?),
+        severity: parse_severity(severity)?,
+        message: message.trim().to_string(),
+    })
+}
+
 impl Shader {
     /// Compiles the shader and returns a [CompiledShader](CompiledShader) that wraps the current object or returns an error if the operation fails.
     /// Failure is realistic in this situation and can happen in a variety of cases:
@@ -226,29 +485,72 @@ impl Shader {
     /// 2. The shader is in an invalid state
     /// 3. An underlying driver issue occurred
     pub fn compile<S: AsRef<str>>(self, src: S) -> Result<CompiledShader, ShaderCompileError> {
+        self.compile_with_log(src).map(|(compiled, _)| compiled)
+    }
+
+    /**
+    Compiles the shader like [compile](Shader::compile) but additionally returns the shader's info log split into lines.
+
+    Some drivers emit useful warnings even if compilation succeeds, which [compile](Shader::compile) discards.
+    The log is empty if the driver had nothing to report.
+
+    # Example
+    ```ignore
+    let (compiled, warnings) = shader.compile_with_log(shader_src).expect("Unable to compile shader");
+    for warning in warnings {
+        log::warn!("{}", warning);
+    }
+    ```
+    */
+    pub fn compile_with_log<S: AsRef<str>>(mut self, src: S) -> Result<(CompiledShader, Vec<String>), ShaderCompileError> {
+        let start = std::time::Instant::now();
+
+        self.set_source(src.as_ref())?;
+
+        self.compile_source(src.as_ref().len(), start)
+    }
+
+    /**
+    Replaces the shader's source without compiling it.
+
+    This is useful for tooling that wants to defer compilation, [compile](Shader::compile) sets the source itself.
+
+    # Example
+    ```ignore
+    let mut shader = Shader::new(ShaderType::Vertex).expect("Unable to create vertex shader");
+    shader.set_source(shader_src).expect("Unable to set the vertex shader's source");
+    ```
+    */
+    pub fn set_source<S: AsRef<str>>(&mut self, src: S) -> Result<(), ShaderCompileError> {
         let cstr = std::ffi::CString::new(src.as_ref().as_bytes())?;
+        let length = cstr.as_bytes().len() as gl::types::GLint;
 
         let rc = unsafe {
-            let count = 1;
-            gl::ShaderSource(self.inner.id, 1, &cstr.as_ptr(), &count);
-            gl::GetError()
+            gl::ShaderSource(self.inner.id, 1, &cstr.as_ptr(), &length);
+            super::get_error()
         };
 
         match rc {
-            gl::NO_ERROR => {}
-            gl::INVALID_VALUE => {
-                return Err(ShaderCompileError::NotAnOpenGLValue);
-            }
-            gl::INVALID_OPERATION => {
-                return Err(ShaderCompileError::NotAShader);
-            }
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_VALUE => Err(ShaderCompileError::NotAnOpenGLValue),
+            gl::INVALID_OPERATION => Err(ShaderCompileError::NotAShader),
             // ignoring "INVALID_VALUE if count is less than 0" since we literally hard-coded count to be 1
-            _ => return Err(ShaderCompileError::Unknown),
+            _ => Err(ShaderCompileError::Unknown),
         }
+    }
 
+    /// Compiles the source previously passed to OpenGL, `source_len` and `start` are only used for the [CompileRecord]
+    fn compile_source(self, source_len: usize, start: std::time::Instant) -> Result<(CompiledShader, Vec<String>), ShaderCompileError> {
+        self.start_compile()?;
+        self.finish_compile(source_len, start)
+    }
+
+    /// Kicks off the compilation of the source previously passed to OpenGL, which may happen asynchronously
+    /// in drivers supporting GL_ARB_parallel_shader_compile
+    pub(crate) fn start_compile(&self) -> Result<(), ShaderCompileError> {
         let rc = unsafe {
             gl::CompileShader(self.inner.id);
-            gl::GetError()
+            super::get_error()
         };
 
         match rc {
@@ -264,32 +566,528 @@ impl Shader {
             }
         }
 
+        Ok(())
+    }
+
+    /// Waits for the compilation started by [start_compile](Shader::start_compile) to finish and returns its result
+    pub(crate) fn finish_compile(
+        self,
+        source_len: usize,
+        start: std::time::Instant,
+    ) -> Result<(CompiledShader, Vec<String>), ShaderCompileError> {
         let mut compile_status = 0;
         unsafe {
-            gl::GetShaderiv(self.inner.id, gl::COMPILE_STATUS, &mut compile_status);
+            gl_call!(gl::GetShaderiv(self.inner.id, gl::COMPILE_STATUS, &mut compile_status));
         }
 
+        record_compile(CompileRecord {
+            r#type: self.inner.r#type,
+            source_len,
+            duration: start.elapsed(),
+            succeeded: compile_status != 0,
+        });
+
+        let log = self.info_log()?;
+
         if compile_status == 0 {
-            const CAPACITY: usize = 1024;
-            let mut log = Vec::<u8>::with_capacity(CAPACITY);
-            let mut length = 0;
-            unsafe { gl::GetShaderInfoLog(self.inner.id, CAPACITY as i32, &mut length, log.as_mut_ptr() as *mut i8) };
+            return Err(ShaderCompileError::CompilationError(log));
+        }
 
-            unsafe {
-                log.set_len(((length + 1) as usize).min(CAPACITY));
-            }
+        let compiled = CompiledShader {
+            inner: CompiledShaderInner { shader: self },
+        };
+
+        Ok((compiled, log.lines().map(str::to_owned).collect()))
+    }
 
-            let s = std::ffi::CString::from_vec_with_nul(log)?.into_string()?;
+    /// Reads the shader's complete info log, which is empty if the driver had nothing to report
+    fn info_log(&self) -> Result<String, ShaderCompileError> {
+        let mut length = 0;
+        unsafe { gl_call!(gl::GetShaderiv(self.inner.id, gl::INFO_LOG_LENGTH, &mut length)) };
 
-            return Err(ShaderCompileError::CompilationError(s));
+        // the reported length includes the nul-byte, an empty log is reported as 0 though
+        let capacity = (length as usize).max(1);
+        let mut log = Vec::<u8>::with_capacity(capacity);
+        let mut written = 0;
+
+        unsafe {
+            gl_call!(gl::GetShaderInfoLog(
+                self.inner.id,
+                capacity as i32,
+                &mut written,
+                log.as_mut_ptr() as *mut i8
+            ));
+            log.set_len(((written + 1) as usize).min(capacity));
         }
 
-        Ok(CompiledShader {
-            inner: CompiledShaderInner { shader: self },
+        Ok(std::ffi::CString::from_vec_with_nul(log)?.into_string()?)
+    }
+}
+
+/**
+Accumulates multiple source chunks which are passed to OpenGL separately when compiling a shader.
+
+OpenGL concatenates the chunks in the order they were pushed, which allows sharing e.g. a `#version` prelude or a set of
+defines between shaders without concatenating the strings yourself.
+
+# Example
+```ignore
+let mut builder = ShaderBuilder::new();
+builder
+    .push_source("#version 330 core\n")
+    .push_source("#define INTENSITY 0.5\n")
+    .push_source("out vec4 color; void main() { color = vec4(INTENSITY); }");
+
+let shader = Shader::new(ShaderType::FragmentShader).expect("Unable to create fragment shader");
+let compiled = builder.build(shader).expect("Unable to compile fragment shader");
+```
+*/
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ShaderBuilder {
+    sources: Vec<String>,
+    debug: bool,
+}
+
+impl ShaderBuilder {
+    /// Creates a builder without any source chunks
+    pub fn new() -> ShaderBuilder {
+        ShaderBuilder::default()
+    }
+
+    /// Appends a source chunk after all previously pushed ones
+    pub fn push_source(&mut self, s: impl Into<String>) -> &mut ShaderBuilder {
+        self.sources.push(s.into());
+        self
+    }
+
+    /**
+    Enables or disables debugging aids for GPU debuggers, disabled by default.
+
+    When enabled `#pragma debug(on)` and `#pragma optimize(off)` are inserted directly after the `#version` directive of
+    the first chunk that has one, or before all chunks if none does. When disabled line and block comments are stripped
+    from every chunk instead, which leaves less for the driver to parse.
+    */
+    pub fn debug_shaders(&mut self, enabled: bool) -> &mut ShaderBuilder {
+        self.debug = enabled;
+        self
+    }
+
+    /// Returns the source chunks as they are passed to OpenGL, i.e. including the debug pragmas if enabled and without
+    /// comments otherwise
+    fn chunks(&self) -> Vec<std::borrow::Cow<'_, str>> {
+        const DEBUG_PRAGMAS: &str = "#pragma debug(on)\n#pragma optimize(off)\n";
+
+        if !self.debug {
+            return self.sources.iter().map(|s| strip_comments(s).into()).collect();
+        }
+
+        let mut chunks = self
+            .sources
+            .iter()
+            .map(|s| std::borrow::Cow::Borrowed(s.as_str()))
+            .collect::<Vec<_>>();
+
+        match self.sources.iter().position(|s| version_directive_end(s).is_some()) {
+            Some(index) => chunks[index] = insert_after_version(&self.sources[index], DEBUG_PRAGMAS).into(),
+            None => chunks.insert(0, DEBUG_PRAGMAS.into()),
+        }
+
+        chunks
+    }
+
+    /// Passes all source chunks to the shader and compiles it, see [compile](Shader::compile) for possible failures
+    pub fn build(self, shader: Shader) -> Result<CompiledShader, ShaderCompileError> {
+        let chunks = self.chunks();
+        let ptrs = chunks.iter().map(|s| s.as_ptr() as *const gl::types::GLchar).collect::<Vec<_>>();
+        let lengths = chunks.iter().map(|s| s.len() as gl::types::GLint).collect::<Vec<_>>();
+
+        let start = std::time::Instant::now();
+
+        let rc = unsafe {
+            gl::ShaderSource(shader.inner.id, ptrs.len() as gl::types::GLsizei, ptrs.as_ptr(), lengths.as_ptr());
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            gl::INVALID_VALUE => {
+                return Err(ShaderCompileError::NotAnOpenGLValue);
+            }
+            gl::INVALID_OPERATION => {
+                return Err(ShaderCompileError::NotAShader);
+            }
+            _ => return Err(ShaderCompileError::Unknown),
+        }
+
+        let source_len = lengths.iter().map(|&len| len as usize).sum();
+
+        shader.compile_source(source_len, start).map(|(compiled, _)| compiled)
+    }
+}
+
+/// A record of a single shader compilation, collected while compile recording is enabled.
+/// See [set_compile_recording](set_compile_recording) for more information.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CompileRecord {
+    /// The type of the compiled shader
+    pub r#type: ShaderType,
+    /// The length of the source passed to the compilation in bytes
+    pub source_len: usize,
+    /// The wall-clock duration from passing the source to OpenGL until the compile status was available
+    pub duration: std::time::Duration,
+    /// Whether or not the compilation was successful
+    pub succeeded: bool,
+}
+
+thread_local! {
+    static COMPILE_RECORDING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    static COMPILE_RECORDS: std::cell::RefCell<Vec<CompileRecord>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/**
+Enables or disables the recording of shader compilations for the current thread. Recording is disabled by default.
+
+While enabled every call to [compile](Shader::compile) (and the functions built on top of it) stores a [CompileRecord](CompileRecord)
+which can be retrieved using [take_compile_records](take_compile_records), e.g. to report the slowest shaders of a build.
+Since OpenGL contexts are bound to a thread the records are stored per thread as well.
+
+# Example
+```ignore
+set_compile_recording(true);
+let vertex = Shader::new(ShaderType::Vertex)?.compile(vertex_src)?;
+let fragment = Shader::new(ShaderType::FragmentShader)?.compile(fragment_src)?;
+let slowest = take_compile_records().into_iter().max_by_key(|r| r.duration);
+```
+*/
+pub fn set_compile_recording(enabled: bool) {
+    COMPILE_RECORDING.with(|r| r.set(enabled));
+}
+
+/// Returns all compile records collected on the current thread so far and clears them.
+pub fn take_compile_records() -> Vec<CompileRecord> {
+    COMPILE_RECORDS.with(|r| std::mem::take(&mut *r.borrow_mut()))
+}
+
+fn record_compile(record: CompileRecord) {
+    if COMPILE_RECORDING.with(|r| r.get()) {
+        COMPILE_RECORDS.with(|r| r.borrow_mut().push(record));
+    }
+}
+
+impl Shader {
+    /**
+    Behaves exactly like [compile](Shader::compile) but additionally logs the complete source with prepended line numbers
+    alongside the driver's error message via `log::error!` if compilation fails.
+
+    This is meant for debugging (generated) shaders whose error messages only refer to line numbers.
+    Use [compile](Shader::compile) if you don't want failed compilations to show up in your logs.
+
+    # Example
+    ```ignore
+    let shader = Shader::new(ShaderType::FragmentShader).expect("Unable to create fragment shader");
+    let compiled = shader.compile_verbose(shader_src).expect("Unable to compile fragment shader");
+    ```
+    */
+    pub fn compile_verbose<S: AsRef<str>>(self, src: S) -> Result<CompiledShader, ShaderCompileError> {
+        let r#type = self.get_type();
+
+        self.compile(src.as_ref()).inspect_err(|e| {
+            if let ShaderCompileError::CompilationError(msg) = e {
+                log::error!("Unable to compile {} shader: {}\n{}", r#type, msg, number_lines(src.as_ref()));
+            }
         })
     }
 }
 
+/// Prepends each line of the source with its (1-based) line number, as used by the drivers' error messages
+fn number_lines(src: &str) -> String {
+    let width = src.lines().count().to_string().len();
+
+    src.lines()
+        .enumerate()
+        .map(|(i, line)| format!("{:>width$} | {}", i + 1, line, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Error enum for the failed compilation of a shader from a file
+#[derive(thiserror::Error, Debug)]
+pub enum ShaderCompileFromPathError {
+    /// Used if a source file couldn't be read
+    #[error("Unable to read shader source {path}: {source}")]
+    Io {
+        /// The path of the file that couldn't be read
+        path: std::path::PathBuf,
+        /// The underlying IO error
+        source: std::io::Error,
+    },
+    /// Used if an include directive isn't of the form `#include "path"`
+    #[error("Malformed include directive in {path} at line {line}")]
+    MalformedInclude {
+        /// The path of the file containing the directive
+        path: std::path::PathBuf,
+        /// The (1-based) line of the directive
+        line: usize,
+    },
+    /// Used if a file (transitively) includes itself
+    #[error("Include cycle detected at {0}")]
+    CycleDetected(std::path::PathBuf),
+    /// Used if the merged source couldn't be compiled
+    #[error("Unable to compile the merged shader source: {source}")]
+    Compile {
+        #[from]
+        /// The underlying compilation error
+        source: ShaderCompileError,
+    },
+}
+
+/// Reads the file at the passed path and recursively replaces its include directives with the included files' contents.
+/// `chain` contains the files that are currently being resolved and is used to detect cycles.
+fn resolve_includes(path: &std::path::Path, chain: &mut Vec<std::path::PathBuf>) -> Result<String, ShaderCompileFromPathError> {
+    let io_error = |source| ShaderCompileFromPathError::Io {
+        path: path.to_owned(),
+        source,
+    };
+
+    let canonical = path.canonicalize().map_err(io_error)?;
+
+    if chain.contains(&canonical) {
+        return Err(ShaderCompileFromPathError::CycleDetected(canonical));
+    }
+
+    let src = std::fs::read_to_string(path).map_err(io_error)?;
+    let dir = path.parent().unwrap_or_else(|| std::path::Path::new(""));
+
+    chain.push(canonical);
+
+    let mut resolved = String::with_capacity(src.len());
+
+    for (i, line) in src.lines().enumerate() {
+        match line.trim_start().strip_prefix("#include") {
+            Some(rest) => {
+                let included = rest
+                    .trim()
+                    .strip_prefix('"')
+                    .and_then(|rest| rest.strip_suffix('"'))
+                    .ok_or_else(|| ShaderCompileFromPathError::MalformedInclude {
+                        path: path.to_owned(),
+                        line: i + 1,
+                    })?;
+
+                resolved.push_str(&resolve_includes(&dir.join(included), chain)?);
+            }
+            None => resolved.push_str(line),
+        }
+
+        resolved.push('\n');
+    }
+
+    chain.pop();
+
+    Ok(resolved)
+}
+
+impl Shader {
+    /**
+    Reads the shader's source from the file at the passed path and compiles it.
+
+    Lines of the form `#include "other.glsl"` are replaced with the contents of the referenced file, which is resolved
+    relative to the directory of the including file. Includes are resolved recursively, a file including itself
+    (transitively) is reported as an error. The same file may be included multiple times though, there are no include guards.
+
+    # Example
+    ```ignore
+    let shader = Shader::new(ShaderType::FragmentShader).expect("Unable to create fragment shader");
+    let compiled = shader.compile_from_path("shaders/sprite.frag").expect("Unable to compile fragment shader");
+    ```
+    */
+    pub fn compile_from_path<P: AsRef<std::path::Path>>(self, path: P) -> Result<CompiledShader, ShaderCompileFromPathError> {
+        let src = resolve_includes(path.as_ref(), &mut Vec::new())?;
+
+        Ok(self.compile(src)?)
+    }
+}
+
+/// The profile a GLSL version directive targets
+#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum GlslProfile {
+    /// The core profile, the default for desktop OpenGL 3.2 and above
+    #[strum(serialize = "core")]
+    Core,
+    /// The compatibility profile, which retains the deprecated fixed-function functionality
+    #[strum(serialize = "compatibility")]
+    Compatibility,
+    /// The profile used by OpenGL ES shaders
+    #[strum(serialize = "es")]
+    Es,
+}
+
+/**
+A GLSL version as used in a shader's `#version` directive.
+
+The minor version is stored the way GLSL spells it, i.e. GLSL 3.30 has a major version of 3 and a minor version of 30.
+Displaying a GlslVersion yields the complete directive without a trailing newline.
+The profile is omitted for desktop versions below 1.50 and for GLSL ES 1.00 since those don't accept one.
+
+# Example
+```
+use alloy_graphene::opengl::shader::{GlslProfile, GlslVersion};
+
+let version = GlslVersion { major: 3, minor: 30, profile: GlslProfile::Core };
+assert_eq!(version.to_string(), "#version 330 core");
+
+let legacy = GlslVersion { major: 1, minor: 20, profile: GlslProfile::Compatibility };
+assert_eq!(legacy.to_string(), "#version 120");
+
+let es = GlslVersion { major: 3, minor: 0, profile: GlslProfile::Es };
+assert_eq!(es.to_string(), "#version 300 es");
+```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct GlslVersion {
+    /// The major version, e.g. 3 for GLSL 3.30
+    pub major: u32,
+    /// The minor version, e.g. 30 for GLSL 3.30
+    pub minor: u32,
+    /// The profile the version targets
+    pub profile: GlslProfile,
+}
+
+impl GlslVersion {
+    /// Returns the version number as it appears in the directive, e.g. 330 for GLSL 3.30
+    pub fn number(&self) -> u32 {
+        self.major * 100 + self.minor
+    }
+}
+
+impl std::fmt::Display for GlslVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.profile {
+            // GLSL ES 1.00 predates the profile suffix
+            GlslProfile::Es if self.number() == 100 => write!(f, "#version 100"),
+            GlslProfile::Es => write!(f, "#version {} es", self.number()),
+            // profiles were introduced in GLSL 1.50, earlier versions have to omit them
+            _ if self.number() < 150 => write!(f, "#version {}", self.number()),
+            _ => write!(f, "#version {} {}", self.number(), self.profile),
+        }
+    }
+}
+
+impl Shader {
+    /**
+    Prepends the `#version` directive of the passed version to the body and compiles the result.
+    See [compile](Shader::compile) for the possible failures.
+
+    # Example
+    ```ignore
+    let shader = Shader::new(ShaderType::Vertex).expect("Unable to create vertex shader");
+    let version = GlslVersion { major: 3, minor: 30, profile: GlslProfile::Core };
+    let compiled = shader.compile_with_version(version, body).expect("Unable to compile vertex shader");
+    ```
+    */
+    pub fn compile_with_version(self, version: GlslVersion, body: &str) -> Result<CompiledShader, ShaderCompileError> {
+        self.compile(format!("{}\n{}", version, body))
+    }
+}
+
+impl Shader {
+    /**
+    Inserts a `#define KEY VALUE` line for each of the passed defines and compiles the result,
+    see [insert_defines] for where the defines end up.
+
+    # Example
+    ```ignore
+    let shader = Shader::new(ShaderType::FragmentShader).expect("Unable to create fragment shader");
+    let compiled = shader
+        .compile_with_defines(shader_src, &[("USE_LIGHTING", "1"), ("MAX_LIGHTS", "8")])
+        .expect("Unable to compile fragment shader");
+    ```
+    */
+    pub fn compile_with_defines(self, src: &str, defines: &[(&str, &str)]) -> Result<CompiledShader, ShaderCompileError> {
+        self.compile(insert_defines(src, defines)?)
+    }
+}
+
+/**
+Inserts a `#define KEY VALUE` line for each of the passed defines directly after the source's `#version` directive,
+or at the very top if the source doesn't have one.
+
+Only whitespace and comments may precede the `#version` directive, so anything else ends the search for it.
+Returns [InvalidDefineName](ShaderCompileError::InvalidDefineName) if a name isn't a valid GLSL identifier or is
+reserved, i.e. starts with `GL_` or contains `__`. Values are inserted verbatim.
+
+# Example
+```
+use alloy_graphene::opengl::shader::insert_defines;
+
+let src = "// lighting\n#version 330 core\nvoid main() {}";
+assert_eq!(
+    insert_defines(src, &[("LIGHTS", "4")]).unwrap(),
+    "// lighting\n#version 330 core\n#define LIGHTS 4\nvoid main() {}"
+);
+
+assert_eq!(insert_defines("void main() {}", &[("DEBUG", "")]).unwrap(), "#define DEBUG \nvoid main() {}");
+assert!(insert_defines("void main() {}", &[("1NVALID", "1")]).is_err());
+```
+*/
+pub fn insert_defines(src: &str, defines: &[(&str, &str)]) -> Result<String, ShaderCompileError> {
+    let mut lines = String::new();
+
+    for (name, value) in defines {
+        if !is_valid_define_name(name) {
+            return Err(ShaderCompileError::InvalidDefineName(name.to_string()));
+        }
+
+        lines.push_str(&format!("#define {} {}\n", name, value));
+    }
+
+    Ok(insert_after_version(src, &lines))
+}
+
+/// Inserts the passed lines directly after the source's `#version` directive or at the very top if it doesn't have one
+fn insert_after_version(src: &str, lines: &str) -> String {
+    let (head, tail) = src.split_at(version_directive_end(src).unwrap_or(0));
+    let separator = if head.is_empty() || head.ends_with('\n') { "" } else { "\n" };
+
+    format!("{}{}{}{}", head, separator, lines, tail)
+}
+
+/// Returns whether or not the passed name is a GLSL identifier that isn't reserved for the implementation
+fn is_valid_define_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("GL_")
+        && !name.contains("__")
+}
+
+/// Returns the byte offset directly after the line containing the `#version` directive if the source has one,
+/// skipping leading whitespace and comments
+fn version_directive_end(src: &str) -> Option<usize> {
+    let mut rest = src;
+
+    loop {
+        rest = rest.trim_start();
+
+        if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.split_once('\n').map_or("", |(_, after)| after);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/")?.1;
+        } else {
+            break;
+        }
+    }
+
+    if !rest.strip_prefix('#')?.trim_start_matches([' ', '\t']).starts_with("version") {
+        return None;
+    }
+
+    let start = src.len() - rest.len();
+
+    Some(rest.find('\n').map_or(src.len(), |newline| start + newline + 1))
+}
+
 /// Stores the underlying data of a compiled shader
 ///
 /// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [CompiledShader](CompiledShader) struct.
@@ -305,27 +1103,45 @@ If compilation is successful this struct will be returned and will stand in as t
 Only compiled shaders can be attached to programs
 
 # Example
-```
+```ignore
 let shader = Shader::new(ShaderType::Vertex).expect("Unable to create vertex shader");
 let compiled = shader.compile(shader_src).expect("Unable to compile vertex shader");
 let program = ShaderProgram::new().expect("Unable to create shader program");
-let linked = program.link(IntoIter::new(&[shader])).expect("Unable to link shader program");
+let linked = program.link([compiled]).expect("Unable to link shader program");
 ```
 */
-#[derive(Debug)]
 pub struct CompiledShader {
     inner: CompiledShaderInner,
 }
 
+impl std::fmt::Debug for CompiledShader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledShader")
+            .field("id", &self.get_id())
+            .field("type", &self.get_type())
+            .finish()
+    }
+}
+
+impl super::GlObject for CompiledShader {
+    const SHAREABLE: bool = true;
+}
+
 impl CompiledShader {
     /// Returns a reference to the inner (private) data of the shader.
     /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
     pub unsafe fn inner(&self) -> &CompiledShaderInner {
         &self.inner
     }
 
     /// Returns a reference to the inner (private) data of the shader.
     /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
     pub unsafe fn inner_mut(&mut self) -> &mut CompiledShaderInner {
         &mut self.inner
     }
@@ -334,7 +1150,7 @@ impl CompiledShader {
     Retrieves the id of the compiled shader.
 
     # Example
-    ```
+    ```ignore
     let shader = Shader::new(ShaderType::Compute).expect("Unable to create compute shader");
     let shader = shader.compile(&shader_src).expect("Unable to compile shader");
     assert_eq!(shader.get_id(), 1); // example, YMMV
@@ -348,7 +1164,7 @@ impl CompiledShader {
     Retrieves the type of the compiled shader.
 
     # Example
-    ```
+    ```ignore
     let shader = Shader::new(ShaderType::Compute).expect("Unable to create compute shader");
     let shader = shader.compile(&shader_src).expect("Unable to compile shader");
     assert_eq!(shader.get_type(), ShaderType::Compute);
@@ -357,6 +1173,11 @@ impl CompiledShader {
     pub fn get_type(&self) -> ShaderType {
         self.inner.shader.inner.r#type
     }
+
+    /// Returns a copy of the compiled shader's id and type, which is the safe alternative to [inner](CompiledShader::inner) for reading.
+    pub fn snapshot(&self) -> ShaderSnapshot {
+        self.inner.shader.snapshot()
+    }
 }
 
 /// Error enum for the failed retrieval of a compiled shader's source's len
@@ -383,7 +1204,7 @@ impl CompiledShader {
 
         let rc = unsafe {
             gl::GetShaderiv(self.get_id(), gl::SHADER_SOURCE_LENGTH, &mut iv);
-            gl::GetError()
+            super::get_error()
         };
 
         match rc {
@@ -446,7 +1267,7 @@ impl CompiledShader {
         let rc = unsafe {
             gl::GetShaderSource(self.get_id(), len as i32, std::ptr::null_mut(), buffer.as_mut_ptr() as *mut i8);
             buffer.set_len(len);
-            gl::GetError()
+            super::get_error()
         };
 
         match rc {
@@ -459,3 +1280,521 @@ impl CompiledShader {
         Ok(std::ffi::CString::from_vec_with_nul(buffer)?.into_string()?)
     }
 }
+
+/// GL_SPIR_V_BINARY, which was introduced in OpenGL 4.6 and isn't part of the OpenGL 4.5 core bindings
+const SPIR_V_BINARY: gl::types::GLenum = 0x9552;
+
+/// Error enum for the failed query of whether a compiled shader is a SPIR-V shader
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum SpirvQueryError {
+    /// Used if the underlying object was not created by OpenGL
+    #[error("The underlying object was not created by OpenGL")]
+    NotAnOpenGLValue,
+    /// Used if the underlying object was not recognised as an OpenGL shader
+    #[error("The underlying object was not recognised as an OpenGL shader")]
+    NotAShader,
+    /// Used if the context doesn't support SPIR-V shaders, i.e. is neither OpenGL 4.6 nor supports GL_ARB_gl_spirv
+    #[error("GL_SPIR_V_BINARY was not recognised as a valid enum, SPIR-V shaders are unsupported")]
+    Unsupported,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl CompiledShader {
+    /// Returns whether or not the shader was created from a SPIR-V binary rather than compiled from GLSL source
+    pub fn is_spirv(&self) -> Result<bool, SpirvQueryError> {
+        let mut iv = 0;
+
+        let rc = unsafe {
+            gl::GetShaderiv(self.get_id(), SPIR_V_BINARY, &mut iv);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            gl::INVALID_VALUE => return Err(SpirvQueryError::NotAnOpenGLValue),
+            gl::INVALID_OPERATION => return Err(SpirvQueryError::NotAShader),
+            gl::INVALID_ENUM => return Err(SpirvQueryError::Unsupported),
+            _ => return Err(SpirvQueryError::Unknown),
+        }
+
+        Ok(iv != 0)
+    }
+}
+
+/// The interactions between a fragment shader and early fragment tests that [lint_early_fragment_tests] reports
+#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum EarlyFragmentTestsAdvisory {
+    /// The shader uses `discard` without declaring early fragment tests, which makes most drivers run the depth and
+    /// stencil tests after the shader, so occluded fragments are shaded as well
+    #[strum(serialize = "discard disables implicit early fragment tests")]
+    DiscardWithoutEarlyTests,
+    /// The shader declares early fragment tests and uses `discard`, so discarded fragments still update the depth and
+    /// stencil buffers
+    #[strum(serialize = "discarded fragments still write depth and stencil with early fragment tests")]
+    DiscardWithEarlyTests,
+    /// The shader declares early fragment tests and writes `gl_FragDepth`, which is ignored since the depth test already ran
+    #[strum(serialize = "writes to gl_FragDepth are ignored with early fragment tests")]
+    DepthWriteWithEarlyTests,
+}
+
+/// Removes line and block comments, both to search the source for keywords and to pass less of it to the driver
+fn strip_comments(src: &str) -> String {
+    let mut stripped = String::with_capacity(src.len());
+    let mut rest = src;
+
+    while let Some(start) = rest.find("//").into_iter().chain(rest.find("/*")).min() {
+        stripped.push_str(&rest[..start]);
+
+        rest = if rest[start..].starts_with("//") {
+            rest[start..].find('\n').map_or("", |end| &rest[start + end..])
+        } else {
+            // a block comment separates tokens just like whitespace does
+            stripped.push(' ');
+            rest[start + 2..].find("*/").map_or("", |end| &rest[start + 2 + end + 2..])
+        };
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Returns whether or not the identifier occurs in the source as a whole word
+fn contains_word(src: &str, word: &str) -> bool {
+    let is_identifier = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    src.match_indices(word)
+        .any(|(i, _)| !src[..i].ends_with(is_identifier) && !src[i + word.len()..].starts_with(is_identifier))
+}
+
+/**
+Checks a fragment shader's source for `discard` and `gl_FragDepth` and reports how they interact with early fragment
+tests, i.e. the `layout(early_fragment_tests) in;` declaration.
+
+Comments are ignored, but the check is purely textual, so e.g. code that is removed by the preprocessor is still reported.
+[ShaderProgram::link](super::program::ShaderProgram::link) logs the advisories of its fragment shaders as warnings.
+
+# Example
+```
+use alloy_graphene::opengl::shader::{lint_early_fragment_tests, EarlyFragmentTestsAdvisory};
+
+let src = "void main() { if (alpha < 0.5) discard; }";
+assert_eq!(lint_early_fragment_tests(src), vec![EarlyFragmentTestsAdvisory::DiscardWithoutEarlyTests]);
+
+let src = "layout(early_fragment_tests) in;\nvoid main() { gl_FragDepth = 0.5; }";
+assert_eq!(lint_early_fragment_tests(src), vec![EarlyFragmentTestsAdvisory::DepthWriteWithEarlyTests]);
+
+assert!(lint_early_fragment_tests("void main() {} // discard").is_empty());
+```
+*/
+pub fn lint_early_fragment_tests(src: &str) -> Vec<EarlyFragmentTestsAdvisory> {
+    let src = strip_comments(src);
+
+    let early_tests = contains_word(&src, "early_fragment_tests");
+    let discards = contains_word(&src, "discard");
+    let writes_depth = contains_word(&src, "gl_FragDepth");
+
+    let mut advisories = Vec::new();
+
+    match (early_tests, discards) {
+        (false, true) => advisories.push(EarlyFragmentTestsAdvisory::DiscardWithoutEarlyTests),
+        (true, true) => advisories.push(EarlyFragmentTestsAdvisory::DiscardWithEarlyTests),
+        _ => {}
+    }
+
+    if early_tests && writes_depth {
+        advisories.push(EarlyFragmentTestsAdvisory::DepthWriteWithEarlyTests);
+    }
+
+    advisories
+}
+
+/// Returns the `max_vertices` a geometry shader declares in its output layout, `None` if it declares none
+pub fn parse_max_vertices(src: &str) -> Option<u32> {
+    let src = strip_comments(src);
+    let is_identifier = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    src.match_indices("max_vertices")
+        .filter(|(i, _)| !src[..*i].ends_with(is_identifier) && !src[i + "max_vertices".len()..].starts_with(is_identifier))
+        .find_map(|(i, word)| {
+            let value = src[i + word.len()..].trim_start().strip_prefix('=')?.trim_start();
+            let end = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+
+            value[..end].parse().ok()
+        })
+}
+
+/// Returns the number of components of a GLSL type, `None` for types that can't be counted like structs
+fn type_components(ty: &str) -> Option<u32> {
+    match ty {
+        "float" | "int" | "uint" | "bool" => return Some(1),
+        "double" => return Some(2),
+        _ => {}
+    }
+
+    // doubles take up two components each
+    let (base, scale) = match ty.strip_prefix('d') {
+        Some(base) => (base, 2),
+        None => (ty.strip_prefix(['i', 'u', 'b']).unwrap_or(ty), 1),
+    };
+
+    let components = match base {
+        "vec2" => 2,
+        "vec3" => 3,
+        "vec4" => 4,
+        "mat2" | "mat2x2" => 4,
+        "mat3" | "mat3x3" => 9,
+        "mat4" | "mat4x4" => 16,
+        "mat2x3" | "mat3x2" => 6,
+        "mat2x4" | "mat4x2" => 8,
+        "mat3x4" | "mat4x3" => 12,
+        _ => return None,
+    };
+
+    Some(components * scale)
+}
+
+/// The qualifiers that may precede or follow `out` in a declaration
+const DECLARATION_QUALIFIERS: [&str; 10] = [
+    "flat",
+    "smooth",
+    "noperspective",
+    "centroid",
+    "sample",
+    "invariant",
+    "precise",
+    "highp",
+    "mediump",
+    "lowp",
+];
+
+/// Returns the number of components per vertex the top-level `out` declarations of a shader add up to.
+///
+/// This is a lower bound since interface blocks, structs and built-in outputs like `gl_Position` aren't counted.
+fn output_components(src: &str) -> u32 {
+    let src = strip_comments(src);
+    let src = src
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut statements = Vec::new();
+    let mut statement = String::new();
+    let mut depth = 0usize;
+
+    for c in src.chars() {
+        match c {
+            // function bodies and interface blocks are skipped entirely, including the header that preceded them
+            '{' => {
+                depth += 1;
+                statement.clear();
+            }
+            '}' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => statements.push(std::mem::take(&mut statement)),
+            _ if depth == 0 => statement.push(c),
+            _ => {}
+        }
+    }
+
+    statements
+        .iter()
+        .map(|statement| {
+            let mut statement = statement.trim();
+
+            if let Some(layout) = statement.strip_prefix("layout") {
+                statement = layout.find(')').map_or("", |end| &layout[end + 1..]);
+            }
+
+            let mut tokens = statement
+                .split_whitespace()
+                .skip_while(|token| DECLARATION_QUALIFIERS.contains(token));
+
+            if tokens.next() != Some("out") {
+                return 0;
+            }
+
+            let mut tokens = tokens.skip_while(|token| DECLARATION_QUALIFIERS.contains(token));
+            let Some(per_element) = tokens.next().and_then(type_components) else {
+                return 0;
+            };
+
+            tokens
+                .collect::<Vec<_>>()
+                .join(" ")
+                .split(',')
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| {
+                    let elements = name
+                        .split_once('[')
+                        .and_then(|(_, size)| size.trim_end().strip_suffix(']')?.trim().parse().ok())
+                        .unwrap_or(1);
+
+                    per_element * elements
+                })
+                .sum()
+        })
+        .sum()
+}
+
+/// Error enum for a geometry shader whose declared output exceeds the driver's limits, see [check_geometry_limits]
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GeometryLimitError {
+    /// Used if `max_vertices` exceeds `GL_MAX_GEOMETRY_OUTPUT_VERTICES`
+    #[error("The geometry shader declares max_vertices = {max_vertices}, but the driver supports at most {limit}")]
+    TooManyVertices {
+        /// The `max_vertices` declared by the shader
+        max_vertices: u32,
+        /// The driver's `GL_MAX_GEOMETRY_OUTPUT_VERTICES`
+        limit: u32,
+    },
+    /// Used if `max_vertices` times the components per vertex exceeds `GL_MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS`
+    #[error("The geometry shader emits up to {components} output components, but the driver supports at most {limit}")]
+    TooManyComponents {
+        /// The components the shader emits at most, at least as far as graphene can tell
+        components: u32,
+        /// The driver's `GL_MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS`
+        limit: u32,
+    },
+}
+
+/**
+Checks a geometry shader's declared `max_vertices` and the components it emits at most against the driver's limits.
+
+Drivers only report exceeded limits when linking, often with a cryptic message, so
+[ShaderProgram::link](super::program::ShaderProgram::link) runs this check on its geometry shaders beforehand.
+Shaders without a `max_vertices` declaration pass. The components per vertex are counted from the top-level `out`
+declarations, interface blocks, structs and built-in outputs aren't counted, so passing doesn't guarantee the shader fits.
+
+# Example
+```
+use alloy_graphene::opengl::shader::{check_geometry_limits, GeometryLimitError};
+
+let src = "layout(points) in;\nlayout(triangle_strip, max_vertices = 512) out;\nout vec4 color;\nvoid main() {}";
+assert_eq!(
+    check_geometry_limits(src, 256, 1024),
+    Err(GeometryLimitError::TooManyVertices { max_vertices: 512, limit: 256 })
+);
+assert_eq!(
+    check_geometry_limits(src, 1024, 1024),
+    Err(GeometryLimitError::TooManyComponents { components: 2048, limit: 1024 })
+);
+```
+*/
+pub fn check_geometry_limits(src: &str, max_vertices_limit: u32, max_components_limit: u32) -> Result<(), GeometryLimitError> {
+    let Some(max_vertices) = parse_max_vertices(src) else {
+        return Ok(());
+    };
+
+    if max_vertices > max_vertices_limit {
+        return Err(GeometryLimitError::TooManyVertices {
+            max_vertices,
+            limit: max_vertices_limit,
+        });
+    }
+
+    let components = max_vertices.saturating_mul(output_components(src));
+
+    if components > max_components_limit {
+        return Err(GeometryLimitError::TooManyComponents {
+            components,
+            limit: max_components_limit,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an empty directory for a test's shader sources, unique per test and process
+    fn source_dir(test: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("graphene-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("common")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn includes_are_resolved_recursively() {
+        let dir = source_dir("nested-includes");
+        std::fs::write(
+            dir.join("sprite.frag"),
+            "#version 330 core\n#include \"common/color.glsl\"\nvoid main() {}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("common/color.glsl"), "#include \"srgb.glsl\"\nvec4 tint;\n").unwrap();
+        std::fs::write(dir.join("common/srgb.glsl"), "float to_linear(float c);\n").unwrap();
+
+        assert_eq!(
+            resolve_includes(&dir.join("sprite.frag"), &mut Vec::new()).unwrap(),
+            "#version 330 core\nfloat to_linear(float c);\n\nvec4 tint;\n\nvoid main() {}\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn include_cycles_and_missing_files_fail() {
+        let dir = source_dir("failing-includes");
+        std::fs::write(dir.join("a.glsl"), "#include \"common/b.glsl\"\n").unwrap();
+        std::fs::write(dir.join("common/b.glsl"), "#include \"../a.glsl\"\n").unwrap();
+        std::fs::write(dir.join("missing.glsl"), "#include \"common/nothing.glsl\"\n").unwrap();
+        std::fs::write(dir.join("malformed.glsl"), "void main() {}\n#include <common/b.glsl>\n").unwrap();
+
+        assert!(matches!(
+            resolve_includes(&dir.join("a.glsl"), &mut Vec::new()),
+            Err(ShaderCompileFromPathError::CycleDetected(path)) if path.ends_with("a.glsl")
+        ));
+        assert!(matches!(
+            resolve_includes(&dir.join("missing.glsl"), &mut Vec::new()),
+            Err(ShaderCompileFromPathError::Io { path, .. }) if path.ends_with("nothing.glsl")
+        ));
+        assert!(matches!(
+            resolve_includes(&dir.join("malformed.glsl"), &mut Vec::new()),
+            Err(ShaderCompileFromPathError::MalformedInclude { line: 2, .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unrecognised_logs_of_successful_compilations_are_info() {
+        let diagnostics = parse_diagnostics_with("Fragment shader was successfully compiled to run on hardware.", Severity::Info);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert_eq!(parse_diagnostics("0:3(1): warning: unused variable")[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn shader_types_follow_the_extension() {
+        assert_eq!(ShaderType::from_path("sprite.vert"), Some(ShaderType::Vertex));
+        assert_eq!(ShaderType::from_path("shaders/sprite.frag"), Some(ShaderType::FragmentShader));
+        assert_eq!(ShaderType::from_path("outline.geom"), Some(ShaderType::GeometryShader));
+        assert_eq!(ShaderType::from_path("patch.tesc"), Some(ShaderType::TessControl));
+        assert_eq!(ShaderType::from_path("patch.tese"), Some(ShaderType::TessEvaluation));
+        assert_eq!(ShaderType::from_path("cull.comp"), Some(ShaderType::Compute));
+        assert_eq!(ShaderType::from_path("sprite.frag.glsl"), None);
+        assert_eq!(ShaderType::from_path("sprite"), None);
+    }
+
+    #[test]
+    fn debug_pragmas_follow_the_version_directive() {
+        let mut builder = ShaderBuilder::new();
+        builder
+            .push_source("// sprite shader\n#version 330 core\n")
+            .push_source("void main() {}")
+            .debug_shaders(true);
+
+        assert_eq!(
+            builder.chunks(),
+            [
+                "// sprite shader\n#version 330 core\n#pragma debug(on)\n#pragma optimize(off)\n",
+                "void main() {}"
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_pragmas_lead_sources_without_version_directive() {
+        let mut builder = ShaderBuilder::new();
+        builder.push_source("void main() {}").debug_shaders(true);
+
+        assert_eq!(builder.chunks(), ["#pragma debug(on)\n#pragma optimize(off)\n", "void main() {}"]);
+    }
+
+    #[test]
+    fn sources_are_stripped_without_debug() {
+        let mut builder = ShaderBuilder::new();
+        builder
+            .push_source("#version 330 core\n// sprite shader\n")
+            .push_source("void main() { /* nothing */ }");
+
+        assert_eq!(builder.chunks(), ["#version 330 core\n\n", "void main() {   }"]);
+    }
+
+    #[test]
+    fn comments_are_kept_with_debug() {
+        let mut builder = ShaderBuilder::new();
+        builder.push_source("void main() { /* nothing */ }").debug_shaders(true);
+
+        assert_eq!(builder.chunks()[1], "void main() { /* nothing */ }");
+    }
+
+    #[test]
+    fn debug_shows_the_id_and_type() {
+        // dropping would delete the shader through the unloaded function
+        let shader = Shader {
+            inner: ShaderInner {
+                id: 4,
+                r#type: ShaderType::FragmentShader,
+            },
+        };
+
+        assert_eq!(format!("{shader:?}"), "Shader { id: 4, type: FragmentShader }");
+        std::mem::forget(shader);
+    }
+
+    /// A geometry shader emitting a strip of quads with a colour and texture coordinates per vertex
+    const GEOMETRY_SRC: &str = "#version 330 core
+layout(points) in;
+layout(triangle_strip, max_vertices = 64) out; // max_vertices = 4096
+flat out vec4 color;
+out vec2 uv, corners[2];
+out VertexData { vec3 normal; } vs_out;
+void emit(out vec4 position) { position = vec4(0.0); }
+void main() {}
+";
+
+    #[test]
+    fn max_vertices_are_parsed_from_the_output_layout() {
+        assert_eq!(parse_max_vertices(GEOMETRY_SRC), Some(64));
+        assert_eq!(parse_max_vertices("layout(line_strip,max_vertices=2)out;"), Some(2));
+        assert_eq!(parse_max_vertices("layout(points) in;\nvoid main() {}"), None);
+        assert_eq!(parse_max_vertices("const int my_max_vertices = 3;"), None);
+    }
+
+    #[test]
+    fn top_level_outputs_are_counted() {
+        // color, uv and both corners, the block and the function parameter aren't counted
+        assert_eq!(output_components(GEOMETRY_SRC), 4 + 2 + 2 * 2);
+        assert_eq!(output_components("layout(location = 0) out mat4 model;\nout dvec3 normal;"), 16 + 6);
+    }
+
+    #[test]
+    fn geometry_shaders_exceeding_the_limits_are_rejected() {
+        assert_eq!(check_geometry_limits(GEOMETRY_SRC, 256, 1024), Ok(()));
+        assert_eq!(
+            check_geometry_limits(GEOMETRY_SRC, 32, 1024),
+            Err(GeometryLimitError::TooManyVertices {
+                max_vertices: 64,
+                limit: 32
+            })
+        );
+        assert_eq!(
+            check_geometry_limits(GEOMETRY_SRC, 256, 512),
+            Err(GeometryLimitError::TooManyComponents {
+                components: 640,
+                limit: 512
+            })
+        );
+        assert_eq!(check_geometry_limits("void main() {}", 0, 0), Ok(()));
+    }
+
+    #[test]
+    fn line_numbers_are_right_aligned() {
+        let src = (1..=10).map(|i| format!("line {i}")).collect::<Vec<_>>().join("\n");
+        let numbered = number_lines(&src);
+        let lines = numbered.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], " 1 | line 1");
+        assert_eq!(lines[8], " 9 | line 9");
+        assert_eq!(lines[9], "10 | line 10");
+        assert_eq!(number_lines("void main() {}"), "1 | void main() {}");
+    }
+}
@@ -0,0 +1,513 @@
+/*!
+The opengl state module provides idiomatic bindings to OpenGL's global pipeline state.
+
+OpenGL keeps most of its configuration as global state of the current context rather than on objects.
+The functions in this module wrap the parts of that state that are relevant to 2D applications.
+
+Functionality that is only available in more recent OpenGL versions or via extensions is checked for before use and
+reported as an error instead of calling into a function that wasn't loaded.
+*/
+
+gl_enum! {
+    /// The origin of the window coordinates used by OpenGL's clip control.
+    /// See <https://www.khronos.org/opengl/wiki/Vertex_Post-Processing#Clip_control> for more information.
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum ClipOrigin {
+        /// The origin of the window coordinates is in the lower left corner, OpenGL's default
+        LowerLeft = gl::LOWER_LEFT,
+        /// The origin of the window coordinates is in the upper left corner, as used by most other graphic APIs
+        UpperLeft = gl::UPPER_LEFT,
+    }
+}
+
+gl_enum! {
+    /// The range of the NDC depth values that are mapped to the depth range by OpenGL's clip control.
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum ClipDepth {
+        /// Depth values from -1 to 1 are mapped to the depth range, OpenGL's default
+        NegativeOneToOne = gl::NEGATIVE_ONE_TO_ONE,
+        /// Depth values from 0 to 1 are mapped to the depth range, as used by most other graphic APIs
+        ZeroToOne = gl::ZERO_TO_ONE,
+    }
+}
+
+/// Error enum for the failed configuration or retrieval of the clip control
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ClipControlError {
+    /// Used if clip control isn't supported, it requires OpenGL 4.5 or GL_ARB_clip_control
+    #[error("Clip control requires OpenGL 4.5 or GL_ARB_clip_control")]
+    Unsupported,
+    /// Used if either the origin or the depth weren't accepted by OpenGL. This ought not to ever happen
+    #[error("Invalid ClipOrigin or ClipDepth enum")]
+    InvalidEnum,
+    /// Used if OpenGL reported a value that isn't known to graphene
+    #[error("OpenGL reported an unknown clip control value: {source}")]
+    UnknownValue {
+        #[from]
+        /// The underlying enum conversion error
+        source: super::InvalidEnumValue,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/**
+Configures the origin of the window coordinates and the mapping of the NDC depth values.
+
+Using [UpperLeft](ClipOrigin::UpperLeft) and [ZeroToOne](ClipDepth::ZeroToOne) matches the conventions of most other graphic APIs.
+Keep in mind that this affects how projection matrices have to be built.
+Requires OpenGL 4.5 or GL_ARB_clip_control.
+
+# Example
+```ignore
+set_clip_control(ClipOrigin::UpperLeft, ClipDepth::ZeroToOne).expect("Unable to configure clip control");
+assert_eq!(get_clip_control(), Ok((ClipOrigin::UpperLeft, ClipDepth::ZeroToOne)));
+```
+*/
+pub fn set_clip_control(origin: ClipOrigin, depth: ClipDepth) -> Result<(), ClipControlError> {
+    if !gl::ClipControl::is_loaded() {
+        return Err(ClipControlError::Unsupported);
+    }
+
+    let rc = unsafe {
+        gl::ClipControl(origin.as_glenum(), depth.as_glenum());
+        super::get_error()
+    };
+
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_ENUM => Err(ClipControlError::InvalidEnum),
+        _ => Err(ClipControlError::Unknown),
+    }
+}
+
+/// Returns the currently configured origin of the window coordinates and mapping of the NDC depth values.
+/// Requires OpenGL 4.5 or GL_ARB_clip_control.
+pub fn get_clip_control() -> Result<(ClipOrigin, ClipDepth), ClipControlError> {
+    if !gl::ClipControl::is_loaded() {
+        return Err(ClipControlError::Unsupported);
+    }
+
+    let mut origin = 0;
+    let mut depth = 0;
+
+    let rc = unsafe {
+        gl::GetIntegerv(gl::CLIP_ORIGIN, &mut origin);
+        gl::GetIntegerv(gl::CLIP_DEPTH_MODE, &mut depth);
+        super::get_error()
+    };
+
+    match rc {
+        gl::NO_ERROR => {}
+        gl::INVALID_ENUM => return Err(ClipControlError::Unsupported),
+        _ => return Err(ClipControlError::Unknown),
+    }
+
+    Ok((ClipOrigin::try_from(origin as u32)?, ClipDepth::try_from(depth as u32)?))
+}
+
+/// Enables or disables one of OpenGL's server-side capabilities
+fn set_capability(capability: gl::types::GLenum, enabled: bool) {
+    unsafe {
+        if enabled {
+            gl_call!(gl::Enable(capability));
+        } else {
+            gl_call!(gl::Disable(capability));
+        }
+    }
+}
+
+/// Returns whether or not one of OpenGL's server-side capabilities is enabled
+fn is_capability_enabled(capability: gl::types::GLenum) -> bool {
+    unsafe { gl_call!(gl::IsEnabled(capability)) == gl::TRUE }
+}
+
+/**
+Enables or disables dithering of color components before they are written to the color buffer.
+
+Dithering is enabled by default. On targets with a low bit-depth it trades banding in gradients for noise,
+which is not always the desired trade-off.
+*/
+pub fn set_dither_enabled(enabled: bool) {
+    set_capability(gl::DITHER, enabled);
+}
+
+/// Returns whether or not dithering is currently enabled
+pub fn is_dither_enabled() -> bool {
+    is_capability_enabled(gl::DITHER)
+}
+
+/// Error enum for the failed selection of the active texture unit
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ActiveTextureUnitError {
+    /// Used if the unit exceeds GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS - 1
+    #[error("Texture unit {0} exceeds the number of texture units supported by OpenGL")]
+    OutOfRange(u32),
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/**
+Selects the texture unit that subsequent texture binding calls affect.
+
+Units are counted from 0, i.e. unit 3 corresponds to GL_TEXTURE3.
+
+# Example
+```ignore
+set_active_texture_unit(3).expect("Unable to select texture unit");
+assert_eq!(active_texture_unit(), 3);
+```
+*/
+pub fn set_active_texture_unit(unit: u32) -> Result<(), ActiveTextureUnitError> {
+    let texture = gl::TEXTURE0.checked_add(unit).ok_or(ActiveTextureUnitError::OutOfRange(unit))?;
+
+    let rc = unsafe {
+        gl::ActiveTexture(texture);
+        super::get_error()
+    };
+
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_ENUM => Err(ActiveTextureUnitError::OutOfRange(unit)),
+        _ => Err(ActiveTextureUnitError::Unknown),
+    }
+}
+
+/// Returns the currently active texture unit, counted from 0
+pub fn active_texture_unit() -> u32 {
+    let mut unit = 0;
+
+    unsafe { gl_call!(gl::GetIntegerv(gl::ACTIVE_TEXTURE, &mut unit)) };
+
+    (unit as u32).saturating_sub(gl::TEXTURE0)
+}
+
+gl_enum! {
+    /// Whether or not colors read back via glReadPixels are clamped to the range of 0 to 1
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum ClampColor {
+        /// Colors are always clamped
+        Enabled = gl::TRUE as gl::types::GLenum,
+        /// Colors are never clamped
+        Disabled = gl::FALSE as gl::types::GLenum,
+        /// Colors are only clamped when reading from fixed-point targets, OpenGL's default
+        FixedOnly = gl::FIXED_ONLY,
+    }
+}
+
+/**
+Sets whether or not colors read back via glReadPixels are clamped to the range of 0 to 1.
+
+Clamping is only applied to fixed-point targets by default. Disabling it makes sure HDR values can be read back from
+floating-point targets without losing anything above 1, [FixedOnly](ClampColor::FixedOnly) restores the default.
+*/
+pub fn set_clamp_color(clamp: ClampColor) {
+    unsafe { gl_call!(gl::ClampColor(gl::CLAMP_READ_COLOR, clamp.as_glenum())) };
+}
+
+/// Enables or disables the antialiasing of lines. Works best in combination with blending and [HintMode::Nicest](HintMode::Nicest).
+pub fn set_line_smooth_enabled(enabled: bool) {
+    set_capability(gl::LINE_SMOOTH, enabled);
+}
+
+/// Returns whether or not the antialiasing of lines is currently enabled
+pub fn is_line_smooth_enabled() -> bool {
+    is_capability_enabled(gl::LINE_SMOOTH)
+}
+
+/// Enables or disables the antialiasing of polygons. Works best in combination with blending and [HintMode::Nicest](HintMode::Nicest).
+pub fn set_polygon_smooth_enabled(enabled: bool) {
+    set_capability(gl::POLYGON_SMOOTH, enabled);
+}
+
+/// Returns whether or not the antialiasing of polygons is currently enabled
+pub fn is_polygon_smooth_enabled() -> bool {
+    is_capability_enabled(gl::POLYGON_SMOOTH)
+}
+
+gl_enum! {
+    /// The implementation-specific behaviours that can be controlled using hints
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum HintTarget {
+        /// The sampling quality of antialiased lines
+        LineSmooth = gl::LINE_SMOOTH_HINT,
+        /// The sampling quality of antialiased polygons
+        PolygonSmooth = gl::POLYGON_SMOOTH_HINT,
+        /// The quality and performance of compressing textures
+        TextureCompression = gl::TEXTURE_COMPRESSION_HINT,
+        /// The accuracy of the derivative functions in fragment shaders
+        FragmentShaderDerivative = gl::FRAGMENT_SHADER_DERIVATIVE_HINT,
+    }
+}
+
+gl_enum! {
+    /// The behaviour requested by a hint. Drivers are free to ignore hints
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum HintMode {
+        /// The most efficient option should be chosen
+        Fastest = gl::FASTEST,
+        /// The most correct, or highest quality, option should be chosen
+        Nicest = gl::NICEST,
+        /// No preference, the default for all hints
+        DontCare = gl::DONT_CARE,
+    }
+}
+
+/**
+Hints the driver how the passed implementation-specific behaviour should be traded off between quality and performance.
+
+# Example
+```ignore
+set_line_smooth_enabled(true);
+set_hint(HintTarget::LineSmooth, HintMode::Nicest);
+assert_eq!(get_hint(HintTarget::LineSmooth), Ok(HintMode::Nicest));
+```
+*/
+pub fn set_hint(target: HintTarget, mode: HintMode) {
+    unsafe { gl_call!(gl::Hint(target.as_glenum(), mode.as_glenum())) };
+}
+
+/// Returns the mode currently hinted for the passed target
+pub fn get_hint(target: HintTarget) -> Result<HintMode, super::InvalidEnumValue> {
+    let mut mode = 0;
+
+    unsafe { gl_call!(gl::GetIntegerv(target.as_glenum(), &mut mode)) };
+
+    HintMode::try_from(mode as gl::types::GLenum)
+}
+
+/// Sets the length of a row of the pixel data passed to texture uploads, in pixels. 0, the default, means the rows are tightly packed.
+pub fn set_unpack_row_length(pixels: u32) {
+    unsafe { gl_call!(gl::PixelStorei(gl::UNPACK_ROW_LENGTH, pixels as gl::types::GLint)) };
+}
+
+/// Sets the number of pixels at the start of each row that texture uploads skip. Defaults to 0.
+pub fn set_unpack_skip_pixels(pixels: u32) {
+    unsafe { gl_call!(gl::PixelStorei(gl::UNPACK_SKIP_PIXELS, pixels as gl::types::GLint)) };
+}
+
+/// Sets the number of rows at the start of the pixel data that texture uploads skip. Defaults to 0.
+pub fn set_unpack_skip_rows(rows: u32) {
+    unsafe { gl_call!(gl::PixelStorei(gl::UNPACK_SKIP_ROWS, rows as gl::types::GLint)) };
+}
+
+/// Queries a single pixel storage parameter
+fn get_pixel_store(pname: gl::types::GLenum) -> u32 {
+    let mut value = 0;
+
+    unsafe { gl_call!(gl::GetIntegerv(pname, &mut value)) };
+
+    value.max(0) as u32
+}
+
+/// Returns the row length of the pixel data passed to texture uploads, see [set_unpack_row_length]
+pub fn get_unpack_row_length() -> u32 {
+    get_pixel_store(gl::UNPACK_ROW_LENGTH)
+}
+
+/// Returns the number of pixels texture uploads skip at the start of each row, see [set_unpack_skip_pixels]
+pub fn get_unpack_skip_pixels() -> u32 {
+    get_pixel_store(gl::UNPACK_SKIP_PIXELS)
+}
+
+/// Returns the number of rows texture uploads skip at the start of the pixel data, see [set_unpack_skip_rows]
+pub fn get_unpack_skip_rows() -> u32 {
+    get_pixel_store(gl::UNPACK_SKIP_ROWS)
+}
+
+gl_enum! {
+    /// The faces that stencil state can be configured for separately
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum StencilFace {
+        /// Front-facing geometry only
+        Front = gl::FRONT,
+        /// Back-facing geometry only
+        Back = gl::BACK,
+        /// Both front- and back-facing geometry
+        FrontAndBack = gl::FRONT_AND_BACK,
+    }
+}
+
+gl_enum! {
+    /// The functions used to compare a value against a reference value, e.g. in stencil and depth tests
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum CompareFunc {
+        /// The comparison never passes
+        Never = gl::NEVER,
+        /// Passes if the value is less than the reference
+        Less = gl::LESS,
+        /// Passes if the value is equal to the reference
+        Equal = gl::EQUAL,
+        /// Passes if the value is less than or equal to the reference
+        LessEqual = gl::LEQUAL,
+        /// Passes if the value is greater than the reference
+        Greater = gl::GREATER,
+        /// Passes if the value is not equal to the reference
+        NotEqual = gl::NOTEQUAL,
+        /// Passes if the value is greater than or equal to the reference
+        GreaterEqual = gl::GEQUAL,
+        /// The comparison always passes
+        Always = gl::ALWAYS,
+    }
+}
+
+gl_enum! {
+    /// The actions applied to the stored stencil value depending on the outcome of the stencil and depth tests
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum StencilOp {
+        /// Keeps the current value
+        Keep = gl::KEEP,
+        /// Sets the value to 0
+        Zero = gl::ZERO,
+        /// Sets the value to the reference value
+        Replace = gl::REPLACE,
+        /// Increments the value, clamping at the maximum
+        Increment = gl::INCR,
+        /// Increments the value, wrapping around to 0 at the maximum
+        IncrementWrap = gl::INCR_WRAP,
+        /// Decrements the value, clamping at 0
+        Decrement = gl::DECR,
+        /// Decrements the value, wrapping around to the maximum at 0
+        DecrementWrap = gl::DECR_WRAP,
+        /// Inverts the value bitwise
+        Invert = gl::INVERT,
+    }
+}
+
+/// Enables or disables writing into the depth buffer
+pub fn set_depth_mask(enabled: bool) {
+    unsafe { gl_call!(gl::DepthMask(enabled as gl::types::GLboolean)) };
+}
+
+/// Sets which bits of the stencil buffer are written when rendering geometry facing the passed direction
+pub fn set_stencil_mask_separate(face: StencilFace, mask: u32) {
+    unsafe { gl_call!(gl::StencilMaskSeparate(face.as_glenum(), mask)) };
+}
+
+/**
+Sets the stencil test for geometry facing the passed direction.
+
+The test compares `reference & mask` against `stored & mask` using the passed function.
+
+# Example
+```ignore
+// two-sided stencil: front faces increment, back faces decrement the stored value
+set_stencil_func_separate(StencilFace::FrontAndBack, CompareFunc::Always, 0, 0xFF);
+set_stencil_op_separate(StencilFace::Front, StencilOp::Keep, StencilOp::Keep, StencilOp::IncrementWrap);
+set_stencil_op_separate(StencilFace::Back, StencilOp::Keep, StencilOp::Keep, StencilOp::DecrementWrap);
+```
+*/
+pub fn set_stencil_func_separate(face: StencilFace, func: CompareFunc, reference: i32, mask: u32) {
+    unsafe { gl_call!(gl::StencilFuncSeparate(face.as_glenum(), func.as_glenum(), reference, mask)) };
+}
+
+/// Sets the actions taken for geometry facing the passed direction if the stencil test fails, if the stencil test passes
+/// but the depth test fails and if both pass.
+pub fn set_stencil_op_separate(face: StencilFace, stencil_fail: StencilOp, depth_fail: StencilOp, pass: StencilOp) {
+    unsafe {
+        gl_call!(gl::StencilOpSeparate(
+            face.as_glenum(),
+            stencil_fail.as_glenum(),
+            depth_fail.as_glenum(),
+            pass.as_glenum()
+        ))
+    };
+}
+
+/// Error enum for the failed modification of the viewport or the scissor box
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum RegionError {
+    /// Used if the width or height of the region was negative
+    #[error("The region's size must not be negative but was {width}x{height}")]
+    NegativeSize {
+        /// The width passed
+        width: i32,
+        /// The height passed
+        height: i32,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// Rejects negative sizes before they reach OpenGL
+fn check_region_size(w: i32, h: i32) -> Result<(), RegionError> {
+    if w < 0 || h < 0 {
+        return Err(RegionError::NegativeSize { width: w, height: h });
+    }
+
+    Ok(())
+}
+
+/// Maps the errors reported by glViewport and glScissor
+fn region_result(rc: gl::types::GLenum) -> Result<(), RegionError> {
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        _ => Err(RegionError::Unknown),
+    }
+}
+
+/// Retrieves a region such as the viewport as `(x, y, width, height)`
+fn get_region(pname: gl::types::GLenum) -> (i32, i32, i32, i32) {
+    let mut region = [0; 4];
+
+    unsafe { gl_call!(gl::GetIntegerv(pname, region.as_mut_ptr())) };
+
+    (region[0], region[1], region[2], region[3])
+}
+
+/**
+Sets the viewport in window coordinates with the origin in the lower-left corner.
+
+# Example
+```ignore
+// render into the left half of an 800x600 window
+set_viewport(0, 0, 400, 600).expect("Unable to set viewport");
+assert_eq!(get_viewport(), (0, 0, 400, 600));
+```
+*/
+pub fn set_viewport(x: i32, y: i32, w: i32, h: i32) -> Result<(), RegionError> {
+    check_region_size(w, h)?;
+
+    let rc = unsafe {
+        gl::Viewport(x, y, w, h);
+        super::get_error()
+    };
+
+    region_result(rc)
+}
+
+/// Returns the current viewport as `(x, y, width, height)`, see [set_viewport]
+pub fn get_viewport() -> (i32, i32, i32, i32) {
+    get_region(gl::VIEWPORT)
+}
+
+/// Sets the scissor box in window coordinates with the origin in the lower-left corner, it only takes effect while the
+/// scissor test is enabled
+pub fn set_scissor(x: i32, y: i32, w: i32, h: i32) -> Result<(), RegionError> {
+    check_region_size(w, h)?;
+
+    let rc = unsafe {
+        gl::Scissor(x, y, w, h);
+        super::get_error()
+    };
+
+    region_result(rc)
+}
+
+/// Returns the current scissor box as `(x, y, width, height)`, see [set_scissor]
+pub fn get_scissor() -> (i32, i32, i32, i32) {
+    get_region(gl::SCISSOR_BOX)
+}
+
+/// Enables or disables discarding fragments outside of the scissor box
+pub fn enable_scissor_test(enabled: bool) {
+    set_capability(gl::SCISSOR_TEST, enabled);
+}
+
+/// Returns whether or not fragments outside of the scissor box are discarded
+pub fn is_scissor_test_enabled() -> bool {
+    is_capability_enabled(gl::SCISSOR_TEST)
+}
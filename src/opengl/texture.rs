@@ -0,0 +1,1669 @@
+/*!
+The opengl texture module provides idiomatic bindings to OpenGL textures.
+
+As with everything in graphene, the focus is on a 2D application, so only two-dimensional textures are covered.
+You can read more about textures here: <https://www.khronos.org/opengl/wiki/Texture>
+*/
+
+use super::state::{
+    get_unpack_row_length, get_unpack_skip_pixels, get_unpack_skip_rows, set_active_texture_unit, set_unpack_row_length,
+    set_unpack_skip_pixels, set_unpack_skip_rows, ActiveTextureUnitError, CompareFunc,
+};
+use crate::color::Color;
+
+gl_enum! {
+    /// How texture coordinates outside of the range of 0 to 1 are handled
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum TextureWrap {
+        /// The texture is repeated, OpenGL's default
+        Repeat = gl::REPEAT,
+        /// The coordinates are clamped so the texels at the edge are repeated
+        ClampToEdge = gl::CLAMP_TO_EDGE,
+    }
+}
+
+gl_enum! {
+    /// How texels are combined when a texture is sampled
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum TextureFilter {
+        /// The texel closest to the coordinate is used, which keeps pixel art crisp
+        Nearest = gl::NEAREST,
+        /// The four texels closest to the coordinate are interpolated
+        Linear = gl::LINEAR,
+    }
+}
+
+gl_enum! {
+    /// The source of a component when a texture is sampled, see [set_swizzle](Texture2D::set_swizzle)
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum Swizzle {
+        /// The texel's red component
+        Red = gl::RED,
+        /// The texel's green component
+        Green = gl::GREEN,
+        /// The texel's blue component
+        Blue = gl::BLUE,
+        /// The texel's alpha component
+        Alpha = gl::ALPHA,
+        /// A constant 1
+        One = gl::ONE,
+        /// A constant 0
+        Zero = gl::ZERO,
+    }
+}
+
+gl_enum! {
+    /**
+    The formats texture images are stored in, each value being the internal format passed to OpenGL.
+
+    Pixels are uploaded in the layout described by [pixel_format](TextureFormat::pixel_format) and
+    [pixel_type](TextureFormat::pixel_type), i.e. [bytes_per_texel](TextureFormat::bytes_per_texel) bytes per texel.
+    */
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum TextureFormat {
+        /// Red, green, blue and alpha with 8 bits each, uploaded as unsigned bytes
+        Rgba8 = gl::RGBA8,
+        /// Packed unsigned floating point red and green with 11 bits and blue with 10 bits, uploaded as three floats
+        /// which OpenGL packs. A compact format for HDR render targets whose values can't be negative
+        R11fG11fB10f = gl::R11F_G11F_B10F,
+        /// A 24-bit depth component, uploaded as unsigned 32-bit integers
+        DepthComponent24 = gl::DEPTH_COMPONENT24,
+        /// A 32-bit floating point depth component, uploaded as floats
+        DepthComponent32F = gl::DEPTH_COMPONENT32F,
+        /// A 24-bit depth component and an 8-bit stencil index, uploaded as unsigned 32-bit integers with the depth in
+        /// the upper 24 bits
+        Depth24Stencil8 = gl::DEPTH24_STENCIL8,
+    }
+}
+
+impl TextureFormat {
+    /// Returns the components of the uploaded pixels, e.g. GL_RGBA
+    pub fn pixel_format(self) -> gl::types::GLenum {
+        match self {
+            TextureFormat::Rgba8 => gl::RGBA,
+            TextureFormat::R11fG11fB10f => gl::RGB,
+            TextureFormat::DepthComponent24 | TextureFormat::DepthComponent32F => gl::DEPTH_COMPONENT,
+            TextureFormat::Depth24Stencil8 => gl::DEPTH_STENCIL,
+        }
+    }
+
+    /// Returns the type of the uploaded pixels' components, e.g. GL_UNSIGNED_BYTE
+    pub fn pixel_type(self) -> gl::types::GLenum {
+        match self {
+            TextureFormat::Rgba8 => gl::UNSIGNED_BYTE,
+            TextureFormat::R11fG11fB10f => gl::FLOAT,
+            TextureFormat::DepthComponent24 => gl::UNSIGNED_INT,
+            TextureFormat::DepthComponent32F => gl::FLOAT,
+            TextureFormat::Depth24Stencil8 => gl::UNSIGNED_INT_24_8,
+        }
+    }
+
+    /// Returns the size in bytes of a single uploaded texel
+    pub fn bytes_per_texel(self) -> usize {
+        match self {
+            TextureFormat::Rgba8 => 4,
+            TextureFormat::R11fG11fB10f => 12,
+            TextureFormat::DepthComponent24 | TextureFormat::DepthComponent32F | TextureFormat::Depth24Stencil8 => 4,
+        }
+    }
+
+    /// Returns the framebuffer attachment point a texture of this format is attached to as a depth (and stencil)
+    /// attachment, or None if the format has no depth component
+    pub fn depth_attachment_point(self) -> Option<gl::types::GLenum> {
+        match self {
+            TextureFormat::DepthComponent24 | TextureFormat::DepthComponent32F => Some(gl::DEPTH_ATTACHMENT),
+            TextureFormat::Depth24Stencil8 => Some(gl::DEPTH_STENCIL_ATTACHMENT),
+            TextureFormat::Rgba8 | TextureFormat::R11fG11fB10f => None,
+        }
+    }
+}
+
+gl_enum! {
+    /// Whether sampling a depth texture returns the depth or the result of comparing it, see [set_compare_mode](Texture2D::set_compare_mode)
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum CompareMode {
+        /// The stored depth is returned, OpenGL's default
+        None = gl::NONE,
+        /// The texture coordinate's reference value is compared against the stored depth using the
+        /// [compare function](Texture2D::set_compare_func), as required by `sampler2DShadow`
+        CompareRefToTexture = gl::COMPARE_REF_TO_TEXTURE,
+    }
+}
+
+/// The S3TC formats of GL_EXT_texture_compression_s3tc, which aren't part of the OpenGL 4.5 core bindings
+const COMPRESSED_RGB_S3TC_DXT1_EXT: gl::types::GLenum = 0x83F0;
+const COMPRESSED_RGBA_S3TC_DXT1_EXT: gl::types::GLenum = 0x83F1;
+const COMPRESSED_RGBA_S3TC_DXT3_EXT: gl::types::GLenum = 0x83F2;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: gl::types::GLenum = 0x83F3;
+/// The 4x4 format of GL_KHR_texture_compression_astc_ldr, which isn't part of the OpenGL 4.5 core bindings
+const COMPRESSED_RGBA_ASTC_4X4_KHR: gl::types::GLenum = 0x93B0;
+
+gl_enum! {
+    /**
+    The block-compressed formats texture images can be uploaded in, see [compressed_image_2d](Texture2D::compressed_image_2d).
+
+    All of them compress blocks of 4x4 texels into [block_bytes](CompressedFormat::block_bytes) bytes. Only the
+    formats returned by [supported_compressed_formats] can be uploaded, the BC formats are usually supported by desktop
+    drivers while ETC2 and ASTC are mostly supported by mobile ones.
+    */
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum CompressedFormat {
+        /// BC1 (DXT1) without alpha
+        Bc1Rgb = COMPRESSED_RGB_S3TC_DXT1_EXT,
+        /// BC1 (DXT1) with 1-bit alpha
+        Bc1Rgba = COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        /// BC2 (DXT3) with explicit 4-bit alpha
+        Bc2 = COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        /// BC3 (DXT5) with interpolated alpha
+        Bc3 = COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        /// BC4 (RGTC1) with a single red channel
+        Bc4 = gl::COMPRESSED_RED_RGTC1,
+        /// BC5 (RGTC2) with red and green channels, e.g. for normal maps
+        Bc5 = gl::COMPRESSED_RG_RGTC2,
+        /// BC6H (BPTC) with unsigned floating point RGB, for HDR images
+        Bc6hUnsigned = gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+        /// BC7 (BPTC) with high quality RGBA
+        Bc7 = gl::COMPRESSED_RGBA_BPTC_UNORM,
+        /// BC7 (BPTC) with high quality sRGB-encoded RGBA
+        Bc7Srgb = gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        /// ETC2 without alpha
+        Etc2Rgb8 = gl::COMPRESSED_RGB8_ETC2,
+        /// ETC2 with EAC-compressed alpha
+        Etc2Rgba8 = gl::COMPRESSED_RGBA8_ETC2_EAC,
+        /// ASTC with 4x4 blocks
+        Astc4x4 = COMPRESSED_RGBA_ASTC_4X4_KHR,
+    }
+}
+
+impl CompressedFormat {
+    /// Returns the size in bytes of a single compressed block of 4x4 texels
+    pub fn block_bytes(self) -> usize {
+        match self {
+            CompressedFormat::Bc1Rgb | CompressedFormat::Bc1Rgba | CompressedFormat::Bc4 | CompressedFormat::Etc2Rgb8 => 8,
+            _ => 16,
+        }
+    }
+
+    /// Returns the size in bytes of a compressed image with the passed dimensions, partial blocks at the edges being
+    /// stored as complete ones
+    pub fn image_bytes(self, width: u32, height: u32) -> usize {
+        width.div_ceil(4) as usize * height.div_ceil(4) as usize * self.block_bytes()
+    }
+}
+
+/// Returns the compressed formats graphene knows that the current context supports, as queried through GL_COMPRESSED_TEXTURE_FORMATS
+pub fn supported_compressed_formats() -> Vec<CompressedFormat> {
+    let mut count = 0;
+
+    unsafe { gl_call!(gl::GetIntegerv(gl::NUM_COMPRESSED_TEXTURE_FORMATS, &mut count)) };
+
+    let mut formats = vec![0; count.max(0) as usize];
+
+    if !formats.is_empty() {
+        unsafe { gl_call!(gl::GetIntegerv(gl::COMPRESSED_TEXTURE_FORMATS, formats.as_mut_ptr())) };
+    }
+
+    // drivers report formats graphene doesn't model as well, e.g. the generic GL_COMPRESSED_RGBA
+    formats
+        .into_iter()
+        .filter_map(|format| CompressedFormat::try_from(format as gl::types::GLenum).ok())
+        .collect()
+}
+
+gl_enum! {
+    /// How the levels of a mipmapped texture are selected and combined when the texture is minified
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum MipmapFilter {
+        /// The nearest texel of the closest level is used
+        NearestMipmapNearest = gl::NEAREST_MIPMAP_NEAREST,
+        /// The four closest texels of the closest level are interpolated
+        LinearMipmapNearest = gl::LINEAR_MIPMAP_NEAREST,
+        /// The nearest texels of the two closest levels are interpolated
+        NearestMipmapLinear = gl::NEAREST_MIPMAP_LINEAR,
+        /// The four closest texels of the two closest levels are interpolated, also known as trilinear filtering
+        LinearMipmapLinear = gl::LINEAR_MIPMAP_LINEAR,
+    }
+}
+
+/// Stores the underlying data of a texture
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [Texture2D](Texture2D) struct.
+#[derive(Debug)]
+pub struct Texture2DInner {
+    /// The id of the texture, generated by OpenGL and valid for the lifetime of the texture
+    pub id: gl::types::GLuint,
+    /// The width and height of the level 0 image as of the last upload through graphene, (0, 0) before the first one
+    pub size: std::cell::Cell<(u32, u32)>,
+}
+
+/// A copy of the publicly relevant data of a texture, see [Texture2D::snapshot](Texture2D::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct TextureSnapshot {
+    /// The id of the texture, generated by OpenGL. Only valid as long as the texture it was taken from is alive
+    pub id: gl::types::GLuint,
+    /// The width of the level 0 image as of the last upload through graphene
+    pub width: u32,
+    /// The height of the level 0 image as of the last upload through graphene
+    pub height: u32,
+}
+
+impl PartialEq for Texture2DInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for Texture2DInner {}
+
+impl std::hash::Hash for Texture2DInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for textures to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for Texture2DInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Texture2DInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A two-dimensional texture that can be sampled in shaders or rendered into.
+
+All functions that modify the texture bind it to GL_TEXTURE_2D of the currently active texture unit in order to do so.
+
+# Example
+```ignore
+let texture = Texture2D::new().expect("Unable to create texture");
+texture.upload_rgba8(2, 2, &pixels).expect("Unable to upload texture");
+texture.set_filter(TextureFilter::Nearest);
+texture.bind_unit(0).expect("Unable to bind texture");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Texture2D {
+    inner: Texture2DInner,
+}
+
+// the size is the one graphene last uploaded, formatting must not query OpenGL
+impl std::fmt::Debug for Texture2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = self.inner.size.get();
+
+        f.debug_struct("Texture2D")
+            .field("id", &self.inner.id)
+            .field("width", &width)
+            .field("height", &height)
+            .finish()
+    }
+}
+
+impl Texture2D {
+    /// Returns a reference to the inner (private) data of the texture.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &Texture2DInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the texture.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut Texture2DInner {
+        &mut self.inner
+    }
+
+    /// Retrieves the id of the texture.
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the texture's id and uploaded size, which is the safe alternative to [inner](Texture2D::inner) for reading.
+    pub fn snapshot(&self) -> TextureSnapshot {
+        TextureSnapshot {
+            id: self.inner.id,
+            width: self.inner.size.get().0,
+            height: self.inner.size.get().1,
+        }
+    }
+
+    /// Remembers the size of a successfully uploaded level 0 image for [Debug](std::fmt::Debug)
+    fn uploaded(&self, level: gl::types::GLint, width: gl::types::GLsizei, height: gl::types::GLsizei) {
+        if level == 0 {
+            self.inner.size.set((width as u32, height as u32));
+        }
+    }
+
+    /// Binds the texture to GL_TEXTURE_2D of the currently active texture unit
+    fn bind(&self) {
+        unsafe { gl_call!(gl::BindTexture(gl::TEXTURE_2D, self.inner.id)) };
+    }
+
+    /// Makes the passed texture unit the active one and binds the texture to it, so samplers set to this unit sample the texture.
+    pub fn bind_unit(&self, unit: u32) -> Result<(), ActiveTextureUnitError> {
+        set_active_texture_unit(unit)?;
+        self.bind();
+
+        Ok(())
+    }
+
+    /// Retrieves the width and height of the texture's level 0 image, which are 0 until pixels were uploaded.
+    pub fn get_size(&self) -> (u32, u32) {
+        let mut width = 0;
+        let mut height = 0;
+
+        self.bind();
+
+        unsafe {
+            gl_call!(gl::GetTexLevelParameteriv(gl::TEXTURE_2D, 0, gl::TEXTURE_WIDTH, &mut width));
+            gl_call!(gl::GetTexLevelParameteriv(gl::TEXTURE_2D, 0, gl::TEXTURE_HEIGHT, &mut height));
+        }
+
+        (width as u32, height as u32)
+    }
+
+    /// Retrieves the format of the texture's level 0 image, which is `Ok(None)` if no pixels were uploaded yet.
+    pub fn get_format(&self) -> Result<Option<TextureFormat>, super::InvalidEnumValue> {
+        let mut format = 0;
+        let mut width = 0;
+
+        self.bind();
+
+        unsafe {
+            gl_call!(gl::GetTexLevelParameteriv(
+                gl::TEXTURE_2D,
+                0,
+                gl::TEXTURE_INTERNAL_FORMAT,
+                &mut format
+            ));
+            gl_call!(gl::GetTexLevelParameteriv(gl::TEXTURE_2D, 0, gl::TEXTURE_WIDTH, &mut width));
+        }
+
+        if width == 0 {
+            return Ok(None);
+        }
+
+        TextureFormat::try_from(format as gl::types::GLenum).map(Some)
+    }
+
+    /// Sets how texture coordinates outside of the range of 0 to 1 are handled, for both axes.
+    pub fn set_wrap(&self, wrap: TextureWrap) {
+        self.set_wrap_s(wrap);
+        self.set_wrap_t(wrap);
+    }
+
+    /// Sets how horizontal texture coordinates outside of the range of 0 to 1 are handled.
+    pub fn set_wrap_s(&self, wrap: TextureWrap) {
+        self.set_parameter(gl::TEXTURE_WRAP_S, wrap.as_glenum());
+    }
+
+    /// Sets how vertical texture coordinates outside of the range of 0 to 1 are handled.
+    pub fn set_wrap_t(&self, wrap: TextureWrap) {
+        self.set_parameter(gl::TEXTURE_WRAP_T, wrap.as_glenum());
+    }
+
+    /// Sets how texels are combined when the texture is sampled, for both minification and magnification.
+    pub fn set_filter(&self, filter: TextureFilter) {
+        self.set_min_filter(filter);
+        self.set_mag_filter(filter);
+    }
+
+    /// Sets how texels are combined when the texture is minified, which disables sampling the levels above 0.
+    pub fn set_min_filter(&self, filter: TextureFilter) {
+        self.set_parameter(gl::TEXTURE_MIN_FILTER, filter.as_glenum());
+    }
+
+    /// Sets how texels are combined when the texture is magnified.
+    pub fn set_mag_filter(&self, filter: TextureFilter) {
+        self.set_parameter(gl::TEXTURE_MAG_FILTER, filter.as_glenum());
+    }
+
+    /// Binds the texture and sets the passed integer parameter
+    fn set_parameter(&self, parameter: gl::types::GLenum, value: gl::types::GLenum) {
+        self.bind();
+
+        unsafe { gl_call!(gl::TexParameteri(gl::TEXTURE_2D, parameter, value as gl::types::GLint)) };
+    }
+
+    /// Sets how levels are selected and combined when the texture is minified, which makes OpenGL sample the levels
+    /// uploaded using [image_2d_level](Texture2D::image_2d_level). Magnification is unaffected.
+    pub fn set_mipmap_filter(&self, filter: MipmapFilter) {
+        self.set_parameter(gl::TEXTURE_MIN_FILTER, filter.as_glenum());
+    }
+
+    /// Sets whether sampling the texture returns its depth or compares it against a reference value, which only
+    /// affects depth textures.
+    pub fn set_compare_mode(&self, mode: CompareMode) {
+        self.set_parameter(gl::TEXTURE_COMPARE_MODE, mode.as_glenum());
+    }
+
+    /// Sets the function the reference value is compared against the stored depth with if the compare mode is
+    /// [CompareRefToTexture](CompareMode::CompareRefToTexture), the sample is 1 if the comparison passes and 0 otherwise.
+    pub fn set_compare_func(&self, func: CompareFunc) {
+        self.set_parameter(gl::TEXTURE_COMPARE_FUNC, func.as_glenum());
+    }
+
+    /**
+    Sets where the red, green, blue and alpha components of sampled texels are taken from, in that order.
+
+    # Example
+    ```ignore
+    // samples a single channel glyph atlas as white text whose alpha is the glyph's coverage
+    atlas.set_swizzle([Swizzle::One, Swizzle::One, Swizzle::One, Swizzle::Red]);
+    ```
+    */
+    pub fn set_swizzle(&self, swizzle: [Swizzle; 4]) {
+        let swizzle = swizzle.map(|component| component.as_glenum() as gl::types::GLint);
+
+        self.bind();
+
+        unsafe { gl_call!(gl::TexParameteriv(gl::TEXTURE_2D, gl::TEXTURE_SWIZZLE_RGBA, swizzle.as_ptr())) };
+    }
+}
+
+impl super::GlObject for Texture2D {
+    const SHAREABLE: bool = true;
+}
+
+impl Drop for Texture2D {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteTextures(1, &self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a texture
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum TextureCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl Texture2D {
+    /**
+    Returns a new texture or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+
+    The texture uses [Linear](TextureFilter::Linear) filtering for both minification and magnification. OpenGL defaults to a
+    mipmapped minification filter, but graphene only ever uploads level 0, so such a texture would be incomplete and sample black.
+    */
+    pub fn new() -> Result<Texture2D, TextureCreationError> {
+        let mut id = 0;
+
+        unsafe { gl_call!(gl::GenTextures(1, &mut id)) };
+
+        if id == 0 {
+            return Err(TextureCreationError::Unknown);
+        }
+
+        let texture = Texture2D {
+            inner: Texture2DInner {
+                id,
+                size: std::cell::Cell::new((0, 0)),
+            },
+        };
+        texture.set_filter(TextureFilter::Linear);
+
+        Ok(texture)
+    }
+}
+
+/// Error enum for the failed upload of a texture's pixels
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum TextureUploadError {
+    /// Used if the amount of pixel data doesn't match the passed dimensions
+    #[error("Expected {expected} bytes of pixel data but got {actual}")]
+    DimensionMismatch {
+        /// The amount of bytes required by the dimensions
+        expected: usize,
+        /// The amount of bytes that were passed
+        actual: usize,
+    },
+    /// Used if the number of rows doesn't match the height, see [image_2d_rows](Texture2D::image_2d_rows)
+    #[error("Expected {expected} rows but got {actual}")]
+    RowCountMismatch {
+        /// The height of the image
+        expected: usize,
+        /// The number of rows that were passed
+        actual: usize,
+    },
+    /// Used if the length of a row doesn't match the width, see [image_2d_rows](Texture2D::image_2d_rows)
+    #[error("Expected {expected} bytes in row {row} but got {actual}")]
+    RowLengthMismatch {
+        /// The index of the offending row
+        row: usize,
+        /// The amount of bytes required by the width
+        expected: usize,
+        /// The amount of bytes that were passed
+        actual: usize,
+    },
+    /// Used if the compressed format isn't supported by the driver, see [supported_compressed_formats]
+    #[error("The compressed format {0} isn't supported by the driver")]
+    UnsupportedFormat(CompressedFormat),
+    /// Used if the region to upload doesn't fit into the source image or the texture
+    #[error("The region doesn't fit into the source image or the texture")]
+    OutOfBounds,
+    /// Used if the dimensions exceed GL_MAX_TEXTURE_SIZE or the level exceeds the number of levels such a texture can have
+    #[error("The texture's dimensions or level exceed the maximum supported texture size")]
+    TooLarge,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// Checks that the amount of pixel data matches the dimensions and format exactly
+fn check_image_len(width: u32, height: u32, format: TextureFormat, actual: usize) -> Result<(), TextureUploadError> {
+    let expected = width as usize * height as usize * format.bytes_per_texel();
+
+    if actual != expected {
+        return Err(TextureUploadError::DimensionMismatch { expected, actual });
+    }
+
+    Ok(())
+}
+
+impl Texture2D {
+    /// Replaces the texture's level 0 image with the passed RGBA pixels, 8 bits per channel, starting with the first row.
+    /// The amount of pixel data has to match the dimensions exactly.
+    pub fn upload_rgba8(&self, width: u32, height: u32, pixels: &[u8]) -> Result<(), TextureUploadError> {
+        self.image_2d_level(0, width, height, TextureFormat::Rgba8, pixels)
+    }
+
+    /**
+    Replaces the image of the passed mipmap level with pixels in the layout of the passed format, starting with the first row.
+
+    The amount of pixel data has to match the dimensions and format exactly. Levels above 0 are only sampled with a
+    [MipmapFilter], the texture is incomplete then unless every level down to 1x1 was uploaded, each halving the
+    dimensions of the previous one.
+
+    # Example
+    ```ignore
+    texture.image_2d_level(0, 2, 2, TextureFormat::Rgba8, &[255; 2 * 2 * 4]).expect("Unable to upload level 0");
+    texture.image_2d_level(1, 1, 1, TextureFormat::Rgba8, &[0, 0, 0, 255]).expect("Unable to upload level 1");
+    texture.set_mipmap_filter(MipmapFilter::LinearMipmapLinear);
+    ```
+    */
+    pub fn image_2d_level<T: bytemuck::Pod>(
+        &self,
+        level: u32,
+        width: u32,
+        height: u32,
+        format: TextureFormat,
+        data: &[T],
+    ) -> Result<(), TextureUploadError> {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+
+        check_image_len(width, height, format, bytes.len())?;
+
+        let level = gl::types::GLint::try_from(level).map_err(|_| TextureUploadError::TooLarge)?;
+        let width = gl::types::GLsizei::try_from(width).map_err(|_| TextureUploadError::TooLarge)?;
+        let height = gl::types::GLsizei::try_from(height).map_err(|_| TextureUploadError::TooLarge)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                level,
+                format.as_glenum() as gl::types::GLint,
+                width,
+                height,
+                0,
+                format.pixel_format(),
+                format.pixel_type(),
+                bytes.as_ptr() as *const _,
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {
+                self.uploaded(level, width, height);
+                Ok(())
+            }
+            gl::INVALID_VALUE => Err(TextureUploadError::TooLarge),
+            _ => Err(TextureUploadError::Unknown),
+        }
+    }
+}
+
+impl Texture2D {
+    /**
+    Replaces the texture's level 0 image with the passed block-compressed data, see
+    [compressed_image_2d_level](Texture2D::compressed_image_2d_level).
+
+    # Example
+    ```ignore
+    let texture = Texture2D::new().expect("Unable to create texture");
+    // a single opaque white DXT5 block
+    let block = [255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0];
+    texture.compressed_image_2d(CompressedFormat::Bc3, 4, 4, &block).expect("Unable to upload texture");
+    ```
+    */
+    pub fn compressed_image_2d(&self, format: CompressedFormat, width: u32, height: u32, data: &[u8]) -> Result<(), TextureUploadError> {
+        self.compressed_image_2d_level(0, format, width, height, data)
+    }
+
+    /// Replaces the image of the passed mipmap level with the passed block-compressed data, whose length has to match
+    /// [image_bytes](CompressedFormat::image_bytes) exactly. Fails if the format isn't one of the
+    /// [supported_compressed_formats].
+    pub fn compressed_image_2d_level(
+        &self,
+        level: u32,
+        format: CompressedFormat,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Result<(), TextureUploadError> {
+        let expected = format.image_bytes(width, height);
+
+        if data.len() != expected {
+            return Err(TextureUploadError::DimensionMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        if !supported_compressed_formats().contains(&format) {
+            return Err(TextureUploadError::UnsupportedFormat(format));
+        }
+
+        let level = gl::types::GLint::try_from(level).map_err(|_| TextureUploadError::TooLarge)?;
+        let width = gl::types::GLsizei::try_from(width).map_err(|_| TextureUploadError::TooLarge)?;
+        let height = gl::types::GLsizei::try_from(height).map_err(|_| TextureUploadError::TooLarge)?;
+        let size = gl::types::GLsizei::try_from(data.len()).map_err(|_| TextureUploadError::TooLarge)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::CompressedTexImage2D(
+                gl::TEXTURE_2D,
+                level,
+                format.as_glenum(),
+                width,
+                height,
+                0,
+                size,
+                data.as_ptr() as *const _,
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {
+                self.uploaded(level, width, height);
+                Ok(())
+            }
+            gl::INVALID_ENUM => Err(TextureUploadError::UnsupportedFormat(format)),
+            gl::INVALID_VALUE => Err(TextureUploadError::TooLarge),
+            _ => Err(TextureUploadError::Unknown),
+        }
+    }
+}
+
+/// Checks that there are as many rows as the height and that each of them is as long as the width requires, and
+/// concatenates them into a single image
+fn concat_rows(width: u32, height: u32, format: TextureFormat, rows: &[&[u8]]) -> Result<Vec<u8>, TextureUploadError> {
+    if rows.len() != height as usize {
+        return Err(TextureUploadError::RowCountMismatch {
+            expected: height as usize,
+            actual: rows.len(),
+        });
+    }
+
+    let expected = width as usize * format.bytes_per_texel();
+
+    if let Some((row, actual)) = rows.iter().map(|row| row.len()).enumerate().find(|(_, len)| *len != expected) {
+        return Err(TextureUploadError::RowLengthMismatch { row, expected, actual });
+    }
+
+    Ok(rows.concat())
+}
+
+impl Texture2D {
+    /**
+    Replaces the texture's level 0 image with the passed rows of pixels in the layout of the passed format, starting
+    with the first row.
+
+    Each row has to contain exactly `width` texels and there have to be exactly `height` rows, which catches rows
+    that were cut short or padded before anything is uploaded.
+
+    # Example
+    ```ignore
+    let red = [255, 0, 0, 255, 255, 0, 0, 255];
+    let blue = [0, 0, 255, 255, 0, 0, 255, 255];
+    texture.image_2d_rows(2, 2, TextureFormat::Rgba8, &[&red, &blue]).expect("Unable to upload texture");
+    ```
+    */
+    pub fn image_2d_rows(&self, width: u32, height: u32, format: TextureFormat, rows: &[&[u8]]) -> Result<(), TextureUploadError> {
+        self.image_2d_level(0, width, height, format, &concat_rows(width, height, format, rows)?)
+    }
+}
+
+/// Error enum for the failed creation and upload of a texture in one go
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum TextureBuildError {
+    /// Used if the texture couldn't be created
+    #[error("Unable to create the texture: {source}")]
+    Creation {
+        #[from]
+        /// The underlying texture creation error
+        source: TextureCreationError,
+    },
+    /// Used if the texture's pixels couldn't be uploaded
+    #[error("Unable to upload the texture: {source}")]
+    Upload {
+        #[from]
+        /// The underlying upload error
+        source: TextureUploadError,
+    },
+}
+
+/**
+Configures the sampling parameters of a texture before it's created and uploaded in one go.
+
+Parameters that aren't set keep the defaults of [new](Texture2D::new).
+
+# Example
+```ignore
+let mut builder = Texture2DBuilder::new();
+builder
+    .min_filter(TextureFilter::Linear)
+    .mag_filter(TextureFilter::Nearest)
+    .wrap_s(TextureWrap::Repeat)
+    .wrap_t(TextureWrap::ClampToEdge)
+    .swizzle([Swizzle::Red, Swizzle::Red, Swizzle::Red, Swizzle::One]);
+
+let texture = builder.build_with_data(2, 2, TextureFormat::Rgba8, &pixels).expect("Unable to build texture");
+```
+*/
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Texture2DBuilder {
+    min_filter: Option<TextureFilter>,
+    mag_filter: Option<TextureFilter>,
+    mipmap_filter: Option<MipmapFilter>,
+    wrap_s: Option<TextureWrap>,
+    wrap_t: Option<TextureWrap>,
+    swizzle: Option<[Swizzle; 4]>,
+}
+
+impl Texture2DBuilder {
+    /// Creates a builder that doesn't change any parameters
+    pub fn new() -> Texture2DBuilder {
+        Texture2DBuilder::default()
+    }
+
+    /// Sets the minification filter, see [set_min_filter](Texture2D::set_min_filter)
+    pub fn min_filter(&mut self, filter: TextureFilter) -> &mut Texture2DBuilder {
+        self.min_filter = Some(filter);
+        self.mipmap_filter = None;
+        self
+    }
+
+    /// Sets the magnification filter, see [set_mag_filter](Texture2D::set_mag_filter)
+    pub fn mag_filter(&mut self, filter: TextureFilter) -> &mut Texture2DBuilder {
+        self.mag_filter = Some(filter);
+        self
+    }
+
+    /// Sets a mipmapped minification filter instead of a plain one, see [set_mipmap_filter](Texture2D::set_mipmap_filter)
+    pub fn mipmap_filter(&mut self, filter: MipmapFilter) -> &mut Texture2DBuilder {
+        self.mipmap_filter = Some(filter);
+        self.min_filter = None;
+        self
+    }
+
+    /// Sets the horizontal wrap mode, see [set_wrap_s](Texture2D::set_wrap_s)
+    pub fn wrap_s(&mut self, wrap: TextureWrap) -> &mut Texture2DBuilder {
+        self.wrap_s = Some(wrap);
+        self
+    }
+
+    /// Sets the vertical wrap mode, see [set_wrap_t](Texture2D::set_wrap_t)
+    pub fn wrap_t(&mut self, wrap: TextureWrap) -> &mut Texture2DBuilder {
+        self.wrap_t = Some(wrap);
+        self
+    }
+
+    /// Sets the sources of the sampled components, see [set_swizzle](Texture2D::set_swizzle)
+    pub fn swizzle(&mut self, swizzle: [Swizzle; 4]) -> &mut Texture2DBuilder {
+        self.swizzle = Some(swizzle);
+        self
+    }
+
+    /// Creates the texture, applies all parameters that were set and uploads the passed level 0 image, see
+    /// [image_2d_level](Texture2D::image_2d_level)
+    pub fn build_with_data<T: bytemuck::Pod>(
+        self,
+        width: u32,
+        height: u32,
+        format: TextureFormat,
+        data: &[T],
+    ) -> Result<Texture2D, TextureBuildError> {
+        let texture = Texture2D::new()?;
+
+        if let Some(filter) = self.min_filter {
+            texture.set_min_filter(filter);
+        }
+        if let Some(filter) = self.mipmap_filter {
+            texture.set_mipmap_filter(filter);
+        }
+        if let Some(filter) = self.mag_filter {
+            texture.set_mag_filter(filter);
+        }
+        if let Some(wrap) = self.wrap_s {
+            texture.set_wrap_s(wrap);
+        }
+        if let Some(wrap) = self.wrap_t {
+            texture.set_wrap_t(wrap);
+        }
+        if let Some(swizzle) = self.swizzle {
+            texture.set_swizzle(swizzle);
+        }
+
+        texture.image_2d_level(0, width, height, format, data)?;
+
+        Ok(texture)
+    }
+}
+
+/// The kinds of placeholder textures [placeholder](Texture2D::placeholder) generates
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum Placeholder {
+    /// A magenta and black checkerboard of 8x8 cells, the classic "missing texture"
+    Checkerboard,
+    /// A single color
+    Solid(Color),
+    /// A horizontal gradient from the color of the leftmost column to the one of the rightmost column
+    Gradient(Color, Color),
+}
+
+/// Generates the RGBA8 pixels of a square placeholder texture of the passed size
+fn placeholder_pixels(kind: Placeholder, size: u32) -> Vec<u8> {
+    const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+    let cell = (size / 8).max(1);
+
+    (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .flat_map(|(x, y)| match kind {
+            Placeholder::Checkerboard => match (x / cell + y / cell) % 2 {
+                0 => MAGENTA,
+                _ => BLACK,
+            },
+            Placeholder::Solid(color) => color.to_rgba8(),
+            Placeholder::Gradient(from, to) => {
+                let t = x as f32 / (size - 1).max(1) as f32;
+                let [from, to] = [from.to_array(), to.to_array()];
+
+                Color::new(
+                    from[0] + (to[0] - from[0]) * t,
+                    from[1] + (to[1] - from[1]) * t,
+                    from[2] + (to[2] - from[2]) * t,
+                    from[3] + (to[3] - from[3]) * t,
+                )
+                .to_rgba8()
+            }
+        })
+        .collect()
+}
+
+impl Texture2D {
+    /**
+    Creates a new square RGBA8 texture of the passed size showing a generated placeholder, e.g. as a fallback for
+    assets that failed to load.
+
+    # Example
+    ```ignore
+    let texture = Texture2D::from_path("sprites/player.png", TextureLoadOptions::default())
+        .or_else(|_| Texture2D::placeholder(Placeholder::Checkerboard, 64))
+        .expect("Unable to create texture");
+    ```
+    */
+    pub fn placeholder(kind: Placeholder, size: u32) -> Result<Texture2D, TextureBuildError> {
+        let texture = Texture2D::new()?;
+        texture.upload_rgba8(size, size, &placeholder_pixels(kind, size))?;
+
+        Ok(texture)
+    }
+}
+
+/// A rectangular region of pixels
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct PixelRegion {
+    /// The column of the region's first pixel
+    pub x: u32,
+    /// The row of the region's first pixel
+    pub y: u32,
+    /// The width of the region in pixels
+    pub width: u32,
+    /// The height of the region in pixels
+    pub height: u32,
+}
+
+/// Returns whether or not the region lies within an RGBA8 image of `len` bytes that is `src_width` pixels wide
+fn region_fits(len: usize, src_width: u32, region: PixelRegion) -> bool {
+    let row_end = region.x as usize + region.width as usize;
+    // OpenGL reads up to the end of the region's last row, the rest of that row doesn't have to be part of the source
+    let last_byte = match region.height {
+        0 => 0,
+        height => ((region.y as usize + height as usize - 1) * src_width as usize + row_end) * 4,
+    };
+
+    row_end <= src_width as usize && last_byte <= len
+}
+
+impl Texture2D {
+    /**
+    Uploads a region of a larger RGBA8 image into the texture's level 0 image at the passed offset, without copying the region first.
+
+    `src` contains the complete source image with a width of `src_width` pixels, `src_region` is the part that is uploaded.
+    The texture must already have been allocated, e.g. using [upload_rgba8](Texture2D::upload_rgba8), and the region has to fit into it.
+    The region is selected through the unpack state, which is restored to its previous values afterwards.
+
+    # Example
+    ```ignore
+    // updates a single 16x16 glyph of an atlas from a 256 pixel wide sheet
+    let region = PixelRegion { x: 32, y: 16, width: 16, height: 16 };
+    atlas.sub_image_from_region(0, 0, &sheet, 256, region).expect("Unable to update atlas");
+    ```
+    */
+    pub fn sub_image_from_region(
+        &self,
+        dst_x: u32,
+        dst_y: u32,
+        src: &[u8],
+        src_width: u32,
+        src_region: PixelRegion,
+    ) -> Result<(), TextureUploadError> {
+        if !region_fits(src.len(), src_width, src_region) {
+            return Err(TextureUploadError::OutOfBounds);
+        }
+
+        self.bind();
+
+        let previous = (get_unpack_row_length(), get_unpack_skip_pixels(), get_unpack_skip_rows());
+
+        set_unpack_row_length(src_width);
+        set_unpack_skip_pixels(src_region.x);
+        set_unpack_skip_rows(src_region.y);
+
+        let rc = unsafe {
+            gl::TexSubImage2D(
+                gl::TEXTURE_2D,
+                0,
+                dst_x as gl::types::GLint,
+                dst_y as gl::types::GLint,
+                src_region.width as gl::types::GLsizei,
+                src_region.height as gl::types::GLsizei,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                src.as_ptr() as *const _,
+            );
+            super::get_error()
+        };
+
+        // restore the caller's unpack state so it isn't affected by this upload
+        set_unpack_row_length(previous.0);
+        set_unpack_skip_pixels(previous.1);
+        set_unpack_skip_rows(previous.2);
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_VALUE => Err(TextureUploadError::OutOfBounds),
+            _ => Err(TextureUploadError::Unknown),
+        }
+    }
+}
+
+/// Error enum for the failed parsing of a KTX2 or DDS container
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ContainerError {
+    /// Used if the data doesn't start with the magic bytes of the container format
+    #[error("The data doesn't start with the container's magic bytes")]
+    InvalidMagic,
+    /// Used if the data ends before the header or one of the levels it describes
+    #[error("The container is truncated")]
+    Truncated,
+    /// Used if the container's format has no matching [CompressedFormat], e.g. because it's uncompressed.
+    /// Contains the VkFormat of KTX2 containers and the FourCC or DXGI format of DDS containers
+    #[error("The container's format {0:#x} has no matching compressed format")]
+    UnknownFormat(u32),
+    /// Used if the container holds something other than a single 2D image with its mip levels, e.g. an array, a cube
+    /// map or a volume, or if it's supercompressed
+    #[error("The container doesn't hold a plain 2D image")]
+    UnsupportedLayout,
+    /// Used if the size of a level doesn't match its dimensions
+    #[error("Expected {expected} bytes for level {level} but got {actual}")]
+    LevelSizeMismatch {
+        /// The offending mip level
+        level: usize,
+        /// The amount of bytes required by the level's dimensions
+        expected: usize,
+        /// The amount of bytes the container stores for the level
+        actual: usize,
+    },
+}
+
+/// A block-compressed image and all of its mip levels, as parsed from a container
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct CompressedImage<'a> {
+    format: CompressedFormat,
+    width: u32,
+    height: u32,
+    /// The data of each level, starting with level 0
+    levels: Vec<&'a [u8]>,
+}
+
+/// Returns the dimensions of the passed mip level of an image whose level 0 has the passed dimensions
+fn level_size(width: u32, height: u32, level: usize) -> (u32, u32) {
+    let shift = level.min(31) as u32;
+
+    ((width >> shift).max(1), (height >> shift).max(1))
+}
+
+/// Returns the bytes at the passed range or [Truncated](ContainerError::Truncated) if the data is too short
+fn container_bytes(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8], ContainerError> {
+    offset
+        .checked_add(len)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or(ContainerError::Truncated)
+}
+
+/// Reads a little endian u32 at the passed offset
+fn container_u32(bytes: &[u8], offset: usize) -> Result<u32, ContainerError> {
+    Ok(u32::from_le_bytes(container_bytes(bytes, offset, 4)?.try_into().unwrap()))
+}
+
+/// Reads a little endian u64 at the passed offset, e.g. an offset or length of the KTX2 level index
+fn container_u64(bytes: &[u8], offset: usize) -> Result<usize, ContainerError> {
+    let value = u64::from_le_bytes(container_bytes(bytes, offset, 8)?.try_into().unwrap());
+
+    usize::try_from(value).map_err(|_| ContainerError::Truncated)
+}
+
+/// Checks that the level has the size its dimensions require
+fn check_level(format: CompressedFormat, width: u32, height: u32, level: usize, data: &[u8]) -> Result<(), ContainerError> {
+    let (width, height) = level_size(width, height, level);
+    let expected = format.image_bytes(width, height);
+
+    if data.len() != expected {
+        return Err(ContainerError::LevelSizeMismatch {
+            level,
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// The identifier every KTX2 file starts with
+const KTX2_MAGIC: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Maps the VkFormat of a KTX2 container to the compressed format it stores
+fn ktx2_format(vk_format: u32) -> Result<CompressedFormat, ContainerError> {
+    match vk_format {
+        131 => Ok(CompressedFormat::Bc1Rgb),
+        133 => Ok(CompressedFormat::Bc1Rgba),
+        135 => Ok(CompressedFormat::Bc2),
+        137 => Ok(CompressedFormat::Bc3),
+        139 => Ok(CompressedFormat::Bc4),
+        141 => Ok(CompressedFormat::Bc5),
+        143 => Ok(CompressedFormat::Bc6hUnsigned),
+        145 => Ok(CompressedFormat::Bc7),
+        146 => Ok(CompressedFormat::Bc7Srgb),
+        147 => Ok(CompressedFormat::Etc2Rgb8),
+        151 => Ok(CompressedFormat::Etc2Rgba8),
+        157 => Ok(CompressedFormat::Astc4x4),
+        _ => Err(ContainerError::UnknownFormat(vk_format)),
+    }
+}
+
+/// Parses a KTX2 container holding a single block-compressed 2D image, see <https://registry.khronos.org/KTX/specs/2.0/ktxspec.v2.html>
+fn parse_ktx2(bytes: &[u8]) -> Result<CompressedImage<'_>, ContainerError> {
+    if !bytes.starts_with(&KTX2_MAGIC) {
+        return Err(ContainerError::InvalidMagic);
+    }
+
+    let format = ktx2_format(container_u32(bytes, 12)?)?;
+    let width = container_u32(bytes, 20)?;
+    let height = container_u32(bytes, 24)?;
+    let (depth, layers, faces) = (container_u32(bytes, 28)?, container_u32(bytes, 32)?, container_u32(bytes, 36)?);
+    // a level count of 0 asks the loader to generate the mip levels, only level 0 is stored then
+    let level_count = container_u32(bytes, 40)?.max(1) as usize;
+    let supercompression = container_u32(bytes, 44)?;
+
+    if height == 0 || depth != 0 || layers != 0 || faces != 1 || supercompression != 0 {
+        return Err(ContainerError::UnsupportedLayout);
+    }
+
+    // the level index follows the 80 bytes of identifier, header and section index
+    let levels = (0..level_count)
+        .map(|level| {
+            let entry = 80 + level * 24;
+            let data = container_bytes(bytes, container_u64(bytes, entry)?, container_u64(bytes, entry + 8)?)?;
+
+            check_level(format, width, height, level, data)?;
+            Ok(data)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CompressedImage {
+        format,
+        width,
+        height,
+        levels,
+    })
+}
+
+/// Maps the FourCC of a DDS container, or its DXGI format if the FourCC is `DX10`, to the compressed format it stores
+fn dds_format(four_cc: u32, dxgi_format: Option<u32>) -> Result<CompressedFormat, ContainerError> {
+    match (&four_cc.to_le_bytes(), dxgi_format) {
+        (b"DXT1", _) => Ok(CompressedFormat::Bc1Rgba),
+        (b"DXT3", _) => Ok(CompressedFormat::Bc2),
+        (b"DXT5", _) => Ok(CompressedFormat::Bc3),
+        (b"ATI1" | b"BC4U", _) => Ok(CompressedFormat::Bc4),
+        (b"ATI2" | b"BC5U", _) => Ok(CompressedFormat::Bc5),
+        (b"DX10", Some(71)) => Ok(CompressedFormat::Bc1Rgba),
+        (b"DX10", Some(74)) => Ok(CompressedFormat::Bc2),
+        (b"DX10", Some(77)) => Ok(CompressedFormat::Bc3),
+        (b"DX10", Some(80)) => Ok(CompressedFormat::Bc4),
+        (b"DX10", Some(83)) => Ok(CompressedFormat::Bc5),
+        (b"DX10", Some(95)) => Ok(CompressedFormat::Bc6hUnsigned),
+        (b"DX10", Some(98)) => Ok(CompressedFormat::Bc7),
+        (b"DX10", Some(99)) => Ok(CompressedFormat::Bc7Srgb),
+        (_, dxgi_format) => Err(ContainerError::UnknownFormat(dxgi_format.unwrap_or(four_cc))),
+    }
+}
+
+/// Parses a DDS container holding a single block-compressed 2D image, see
+/// <https://learn.microsoft.com/en-us/windows/win32/direct3ddds/dx-graphics-dds-pguide>
+fn parse_dds(bytes: &[u8]) -> Result<CompressedImage<'_>, ContainerError> {
+    /// DDSCAPS2_CUBEMAP and DDSCAPS2_VOLUME
+    const CUBEMAP_OR_VOLUME: u32 = 0x200 | 0x200000;
+
+    if !bytes.starts_with(b"DDS ") {
+        return Err(ContainerError::InvalidMagic);
+    }
+
+    let height = container_u32(bytes, 12)?;
+    let width = container_u32(bytes, 16)?;
+    let level_count = container_u32(bytes, 28)?.max(1) as usize;
+    let four_cc = container_u32(bytes, 84)?;
+    let caps2 = container_u32(bytes, 112)?;
+
+    if height == 0 || width == 0 || caps2 & CUBEMAP_OR_VOLUME != 0 {
+        return Err(ContainerError::UnsupportedLayout);
+    }
+
+    // the DX10 extension header follows the 4 bytes of magic and the 124 bytes of the header
+    let (format, mut offset) = match &four_cc.to_le_bytes() {
+        b"DX10" => {
+            let array_size = container_u32(bytes, 140)?;
+
+            if array_size > 1 {
+                return Err(ContainerError::UnsupportedLayout);
+            }
+
+            (dds_format(four_cc, Some(container_u32(bytes, 128)?))?, 148)
+        }
+        _ => (dds_format(four_cc, None)?, 128),
+    };
+
+    let levels = (0..level_count)
+        .map(|level| {
+            let (width, height) = level_size(width, height, level);
+            let data = container_bytes(bytes, offset, format.image_bytes(width, height))?;
+
+            offset += data.len();
+            Ok(data)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CompressedImage {
+        format,
+        width,
+        height,
+        levels,
+    })
+}
+
+/// Error enum for the failed creation of a texture from a KTX2 or DDS container
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum CompressedLoadError {
+    /// Used if the container couldn't be parsed
+    #[error("Unable to parse the container: {source}")]
+    Container {
+        #[from]
+        /// The underlying parsing error
+        source: ContainerError,
+    },
+    /// Used if the texture couldn't be created
+    #[error("Unable to create the texture: {source}")]
+    Creation {
+        #[from]
+        /// The underlying texture creation error
+        source: TextureCreationError,
+    },
+    /// Used if one of the levels couldn't be uploaded, e.g. because the driver doesn't support the format
+    #[error("Unable to upload the texture: {source}")]
+    Upload {
+        #[from]
+        /// The underlying upload error
+        source: TextureUploadError,
+    },
+}
+
+impl Texture2D {
+    /// Creates a new texture from the parsed image, uploading all of its levels. Images with more than one level are
+    /// sampled with [LinearMipmapLinear](MipmapFilter::LinearMipmapLinear) filtering.
+    fn from_compressed_image(image: CompressedImage<'_>) -> Result<Texture2D, CompressedLoadError> {
+        let texture = Texture2D::new()?;
+
+        for (level, data) in image.levels.iter().enumerate() {
+            let (width, height) = level_size(image.width, image.height, level);
+            texture.compressed_image_2d_level(level as u32, image.format, width, height, data)?;
+        }
+
+        if image.levels.len() > 1 {
+            // containers may stop before the 1x1 level, which leaves the texture complete only if OpenGL knows about it
+            unsafe {
+                gl_call!(gl::TexParameteri(
+                    gl::TEXTURE_2D,
+                    gl::TEXTURE_MAX_LEVEL,
+                    (image.levels.len() - 1) as gl::types::GLint
+                ))
+            };
+            texture.set_mipmap_filter(MipmapFilter::LinearMipmapLinear);
+        }
+
+        Ok(texture)
+    }
+
+    /**
+    Creates a new texture from a KTX2 container holding a block-compressed 2D image, uploading all of its mip levels.
+
+    Only containers whose format maps to one of the [CompressedFormat]s are supported, supercompressed containers,
+    arrays, cube maps and volumes are rejected.
+
+    # Example
+    ```ignore
+    let texture = Texture2D::from_ktx2_bytes(include_bytes!("sprites.ktx2")).expect("Unable to load texture");
+    ```
+    */
+    pub fn from_ktx2_bytes(bytes: &[u8]) -> Result<Texture2D, CompressedLoadError> {
+        Texture2D::from_compressed_image(parse_ktx2(bytes)?)
+    }
+
+    /// Creates a new texture from a DDS container holding a block-compressed 2D image, uploading all of its mip levels.
+    /// The same restrictions as for [from_ktx2_bytes](Texture2D::from_ktx2_bytes) apply.
+    pub fn from_dds_bytes(bytes: &[u8]) -> Result<Texture2D, CompressedLoadError> {
+        Texture2D::from_compressed_image(parse_dds(bytes)?)
+    }
+}
+
+/// Options that control how images are turned into textures
+#[cfg(feature = "image")]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct TextureLoadOptions {
+    /// Whether or not to flip the rows of the image, since OpenGL expects the first row to be the bottom one
+    pub flip_y: bool,
+}
+
+/// Error enum for the failed creation of a texture from an image
+#[cfg(feature = "image")]
+#[derive(thiserror::Error, Debug)]
+pub enum TextureLoadError {
+    /// Used if the image has a width or height of 0
+    #[error("The image has no pixels")]
+    EmptyImage,
+    /// Used if the image couldn't be read or decoded
+    #[error("Unable to decode the image: {source}")]
+    Decode {
+        #[from]
+        /// The underlying decoding error
+        source: image::ImageError,
+    },
+    /// Used if the texture couldn't be created
+    #[error("Unable to create the texture: {source}")]
+    Creation {
+        #[from]
+        /// The underlying texture creation error
+        source: TextureCreationError,
+    },
+    /// Used if the image's pixels couldn't be uploaded
+    #[error("Unable to upload the image: {source}")]
+    Upload {
+        #[from]
+        /// The underlying upload error
+        source: TextureUploadError,
+    },
+}
+
+#[cfg(feature = "image")]
+impl Texture2D {
+    /**
+    Creates a new texture from the passed image, converting it to RGBA with 8 bits per channel.
+
+    Only level 0 is uploaded, the texture is complete regardless since [new](Texture2D::new) sets a filter that doesn't use mipmaps.
+    Call [set_filter](Texture2D::set_filter) afterwards for pixel art.
+
+    # Example
+    ```ignore
+    let img = image::load_from_memory(png_bytes).expect("Unable to decode image");
+    let texture = Texture2D::from_image(&img, TextureLoadOptions { flip_y: true }).expect("Unable to create texture");
+    assert_eq!(texture.get_size(), (img.width(), img.height()));
+    ```
+    */
+    pub fn from_image(img: &image::DynamicImage, options: TextureLoadOptions) -> Result<Texture2D, TextureLoadError> {
+        if img.width() == 0 || img.height() == 0 {
+            return Err(TextureLoadError::EmptyImage);
+        }
+
+        let mut rgba = img.to_rgba8();
+
+        if options.flip_y {
+            image::imageops::flip_vertical_in_place(&mut rgba);
+        }
+
+        let texture = Texture2D::new()?;
+        texture.upload_rgba8(rgba.width(), rgba.height(), rgba.as_raw())?;
+
+        Ok(texture)
+    }
+
+    /// Creates a new texture from the image file at the passed path, see [from_image](Texture2D::from_image) for more information.
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P, options: TextureLoadOptions) -> Result<Texture2D, TextureLoadError> {
+        Texture2D::from_image(&image::open(path)?, options)
+    }
+}
+
+/// Error enum for the failed retrieval of a texture's pixels as an image
+#[cfg(feature = "image")]
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum TextureReadError {
+    /// Used if the texture has no pixels, e.g. because none were uploaded yet
+    #[error("The texture has no pixels")]
+    EmptyTexture,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+#[cfg(feature = "image")]
+impl Texture2D {
+    /**
+    Reads back the texture's level 0 image as RGBA with 8 bits per channel.
+
+    Since OpenGL stores the bottom row first the rows are flipped, making this the inverse of
+    [from_image](Texture2D::from_image) with [flip_y](TextureLoadOptions::flip_y) set.
+    Relies on glGetTexImage, which isn't available in OpenGL ES.
+
+    # Example
+    ```ignore
+    let texture = Texture2D::from_image(&img, TextureLoadOptions { flip_y: true }).expect("Unable to create texture");
+    assert_eq!(texture.to_image().expect("Unable to read texture").to_rgba8(), img.to_rgba8());
+    ```
+    */
+    pub fn to_image(&self) -> Result<image::DynamicImage, TextureReadError> {
+        let (width, height) = self.get_size();
+
+        if width == 0 || height == 0 {
+            return Err(TextureReadError::EmptyTexture);
+        }
+
+        let mut pixels = vec![0u8; width as usize * height as usize * 4];
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::GetTexImage(gl::TEXTURE_2D, 0, gl::RGBA, gl::UNSIGNED_BYTE, pixels.as_mut_ptr() as *mut _);
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => {}
+            _ => return Err(TextureReadError::Unknown),
+        }
+
+        let mut rgba = image::RgbaImage::from_raw(width, height, pixels).ok_or(TextureReadError::Unknown)?;
+        image::imageops::flip_vertical_in_place(&mut rgba);
+
+        Ok(image::DynamicImage::ImageRgba8(rgba))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_depth_formats_have_depth_attachment_points() {
+        assert_eq!(TextureFormat::DepthComponent24.depth_attachment_point(), Some(gl::DEPTH_ATTACHMENT));
+        assert_eq!(
+            TextureFormat::DepthComponent32F.depth_attachment_point(),
+            Some(gl::DEPTH_ATTACHMENT)
+        );
+        assert_eq!(
+            TextureFormat::Depth24Stencil8.depth_attachment_point(),
+            Some(gl::DEPTH_STENCIL_ATTACHMENT)
+        );
+        assert_eq!(TextureFormat::Rgba8.depth_attachment_point(), None);
+        assert_eq!(TextureFormat::R11fG11fB10f.depth_attachment_point(), None);
+    }
+
+    #[test]
+    fn image_data_has_to_match_the_dimensions() {
+        assert_eq!(check_image_len(2, 3, TextureFormat::Rgba8, 24), Ok(()));
+        assert_eq!(check_image_len(2, 3, TextureFormat::R11fG11fB10f, 2 * 3 * 3 * 4), Ok(()));
+        assert_eq!(
+            check_image_len(2, 3, TextureFormat::Rgba8, 20),
+            Err(TextureUploadError::DimensionMismatch { expected: 24, actual: 20 })
+        );
+    }
+
+    #[test]
+    fn compressed_images_consist_of_complete_blocks() {
+        assert_eq!(CompressedFormat::Bc1Rgb.image_bytes(4, 4), 8);
+        assert_eq!(CompressedFormat::Bc3.image_bytes(5, 4), 32);
+        assert_eq!(CompressedFormat::Bc7.image_bytes(1, 1), 16);
+        assert_eq!(CompressedFormat::Etc2Rgb8.image_bytes(8, 9), 48);
+    }
+
+    /// Builds a KTX2 container with the passed VkFormat, dimensions and levels
+    fn ktx2(vk_format: u32, width: u32, height: u32, levels: &[&[u8]]) -> Vec<u8> {
+        let mut header = KTX2_MAGIC.to_vec();
+
+        for value in [vk_format, 1, width, height, 0, 0, 1, levels.len() as u32, 0] {
+            header.extend(value.to_le_bytes());
+        }
+        header.resize(80, 0);
+
+        let mut offset = 80 + levels.len() * 24;
+        for level in levels {
+            for value in [offset, level.len(), level.len()] {
+                header.extend((value as u64).to_le_bytes());
+            }
+            offset += level.len();
+        }
+
+        [header, levels.concat()].concat()
+    }
+
+    /// Builds a DDS container with the passed FourCC, dimensions and levels
+    fn dds(four_cc: &[u8; 4], width: u32, height: u32, levels: &[&[u8]]) -> Vec<u8> {
+        let mut header = vec![0; 128];
+
+        header[..4].copy_from_slice(b"DDS ");
+        header[12..16].copy_from_slice(&height.to_le_bytes());
+        header[16..20].copy_from_slice(&width.to_le_bytes());
+        header[28..32].copy_from_slice(&(levels.len() as u32).to_le_bytes());
+        header[84..88].copy_from_slice(four_cc);
+
+        [header, levels.concat()].concat()
+    }
+
+    #[test]
+    fn ktx2_containers_yield_all_levels() {
+        let (level0, level1, level2) = ([1; 64], [2; 16], [3; 16]);
+        let bytes = ktx2(137, 8, 8, &[&level0, &level1, &level2]);
+
+        let image = parse_ktx2(&bytes).unwrap();
+
+        assert_eq!((image.format, image.width, image.height), (CompressedFormat::Bc3, 8, 8));
+        assert_eq!(image.levels, [&level0[..], &level1[..], &level2[..]]);
+    }
+
+    #[test]
+    fn malformed_ktx2_containers_fail() {
+        let level = [0; 8];
+
+        assert_eq!(parse_ktx2(&dds(b"DXT1", 4, 4, &[&level])), Err(ContainerError::InvalidMagic));
+        assert_eq!(parse_ktx2(&ktx2(37, 4, 4, &[&level])), Err(ContainerError::UnknownFormat(37)));
+        assert_eq!(parse_ktx2(&ktx2(131, 4, 4, &[&level])[..90]), Err(ContainerError::Truncated));
+        assert_eq!(
+            parse_ktx2(&ktx2(137, 4, 4, &[&level])),
+            Err(ContainerError::LevelSizeMismatch {
+                level: 0,
+                expected: 16,
+                actual: 8
+            })
+        );
+    }
+
+    #[test]
+    fn dds_containers_yield_all_levels() {
+        let (level0, level1) = ([1; 16], [2; 8]);
+        let bytes = dds(b"DXT1", 8, 4, &[&level0, &level1]);
+
+        let image = parse_dds(&bytes).unwrap();
+
+        assert_eq!((image.format, image.width, image.height), (CompressedFormat::Bc1Rgba, 8, 4));
+        assert_eq!(image.levels, [&level0[..], &level1[..]]);
+        assert_eq!(parse_dds(&bytes[..bytes.len() - 1]), Err(ContainerError::Truncated));
+        assert_eq!(parse_dds(&bytes[4..]), Err(ContainerError::InvalidMagic));
+    }
+
+    #[test]
+    fn checkerboard_cells_alternate() {
+        let pixels = placeholder_pixels(Placeholder::Checkerboard, 16);
+        let pixel = |x: usize, y: usize| &pixels[(y * 16 + x) * 4..][..4];
+
+        assert_eq!(pixels.len(), 16 * 16 * 4);
+        assert_eq!(pixel(0, 0), [255, 0, 255, 255]);
+        assert_eq!(pixel(1, 1), [255, 0, 255, 255]);
+        assert_eq!(pixel(2, 0), [0, 0, 0, 255]);
+        assert_eq!(pixel(0, 2), [0, 0, 0, 255]);
+        assert_eq!(pixel(2, 2), [255, 0, 255, 255]);
+        assert_eq!(pixel(15, 15), [255, 0, 255, 255]);
+    }
+
+    #[test]
+    fn gradients_span_the_whole_width() {
+        let pixels = placeholder_pixels(Placeholder::Gradient(Color::BLACK, Color::WHITE), 3);
+
+        assert_eq!(&pixels[..12], [0, 0, 0, 255, 128, 128, 128, 255, 255, 255, 255, 255]);
+        assert_eq!(placeholder_pixels(Placeholder::Solid(Color::WHITE), 1), [255; 4]);
+    }
+
+    #[test]
+    fn rows_are_concatenated_in_order() {
+        let first: &[u8] = &[1, 1, 1, 1, 2, 2, 2, 2];
+        let second: &[u8] = &[3, 3, 3, 3, 4, 4, 4, 4];
+
+        assert_eq!(
+            concat_rows(2, 2, TextureFormat::Rgba8, &[first, second]),
+            Ok([first, second].concat())
+        );
+    }
+
+    #[test]
+    fn rows_have_to_match_the_dimensions() {
+        let row: &[u8] = &[0; 8];
+        let short: &[u8] = &[0; 7];
+
+        assert_eq!(
+            concat_rows(2, 3, TextureFormat::Rgba8, &[row, row]),
+            Err(TextureUploadError::RowCountMismatch { expected: 3, actual: 2 })
+        );
+        assert_eq!(
+            concat_rows(2, 2, TextureFormat::Rgba8, &[row, short]),
+            Err(TextureUploadError::RowLengthMismatch {
+                row: 1,
+                expected: 8,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn debug_shows_the_id_and_the_uploaded_size() {
+        // dropping would delete the texture through the unloaded function
+        let texture = Texture2D {
+            inner: Texture2DInner {
+                id: 4,
+                size: std::cell::Cell::new((0, 0)),
+            },
+        };
+
+        assert_eq!(format!("{texture:?}"), "Texture2D { id: 4, width: 0, height: 0 }");
+
+        texture.uploaded(1, 32, 32);
+        assert_eq!(format!("{texture:?}"), "Texture2D { id: 4, width: 0, height: 0 }");
+
+        texture.uploaded(0, 64, 64);
+        assert_eq!(format!("{texture:?}"), "Texture2D { id: 4, width: 64, height: 64 }");
+        std::mem::forget(texture);
+    }
+
+    #[test]
+    fn snapshot_matches_the_getters() {
+        // dropping would delete the texture through the unloaded function
+        let texture = Texture2D {
+            inner: Texture2DInner {
+                id: 4,
+                size: std::cell::Cell::new((0, 0)),
+            },
+        };
+        texture.uploaded(0, 16, 8);
+
+        assert_eq!(
+            texture.snapshot(),
+            TextureSnapshot {
+                id: texture.get_id(),
+                width: 16,
+                height: 8,
+            }
+        );
+        std::mem::forget(texture);
+    }
+
+    #[test]
+    fn regions_have_to_fit_into_the_source() {
+        let region = |x, y, width, height| PixelRegion { x, y, width, height };
+        // a 4x3 image
+        let len = 4 * 3 * 4;
+
+        assert!(region_fits(len, 4, region(0, 0, 4, 3)));
+        assert!(region_fits(len, 4, region(2, 1, 2, 2)));
+        // the last row only has to extend to the end of the region
+        assert!(region_fits(len - 4, 4, region(0, 1, 3, 2)));
+        assert!(region_fits(0, 4, region(3, 7, 1, 0)));
+
+        assert!(!region_fits(len, 4, region(1, 0, 4, 1)));
+        assert!(!region_fits(len, 4, region(0, 1, 4, 3)));
+        assert!(!region_fits(len - 1, 4, region(0, 0, 4, 3)));
+    }
+}
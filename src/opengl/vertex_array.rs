@@ -0,0 +1,545 @@
+/*!
+The opengl vertex array module provides idiomatic bindings to OpenGL vertex array objects (VAOs).
+
+A vertex array describes how the data of the bound vertex buffers is fed into the attributes of the vertex shader.
+A vertex array has to be bound in order to draw anything.
+You can read more about vertex arrays here: <https://www.khronos.org/opengl/wiki/Vertex_Specification#Vertex_Array_Object>
+*/
+
+use super::buffer::Buffer;
+
+gl_enum! {
+    /// The types of the components of a vertex attribute as they are stored in the buffer
+    #[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    pub enum AttribType {
+        /// Signed 8-bit integers
+        Byte = gl::BYTE,
+        /// Unsigned 8-bit integers
+        UnsignedByte = gl::UNSIGNED_BYTE,
+        /// Signed 16-bit integers
+        Short = gl::SHORT,
+        /// Unsigned 16-bit integers
+        UnsignedShort = gl::UNSIGNED_SHORT,
+        /// Signed 32-bit integers
+        Int = gl::INT,
+        /// Unsigned 32-bit integers
+        UnsignedInt = gl::UNSIGNED_INT,
+        /// 16-bit floating point numbers
+        HalfFloat = gl::HALF_FLOAT,
+        /// 32-bit floating point numbers
+        Float = gl::FLOAT,
+        /// 64-bit floating point numbers
+        Double = gl::DOUBLE,
+    }
+}
+
+impl AttribType {
+    /// Returns the size in bytes of a single component of this type
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            AttribType::Byte | AttribType::UnsignedByte => 1,
+            AttribType::Short | AttribType::UnsignedShort | AttribType::HalfFloat => 2,
+            AttribType::Int | AttribType::UnsignedInt | AttribType::Float => 4,
+            AttribType::Double => 8,
+        }
+    }
+}
+
+/// Stores the underlying data of a vertex array
+///
+/// Can only be accessed through the unsafe `[inner](inner)/[inner_mut](inner_mut)` methods of the [VertexArray](VertexArray) struct.
+#[derive(Debug)]
+pub struct VertexArrayInner {
+    /// The id of the vertex array, generated by OpenGL and valid for the lifetime of the vertex array
+    pub id: gl::types::GLuint,
+    /// The context the vertex array was created on if it was known, see [set_current_context](super::context::set_current_context)
+    pub context: Option<u64>,
+}
+
+/// A copy of the publicly relevant data of a vertex array, see [VertexArray::snapshot](VertexArray::snapshot)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct VertexArraySnapshot {
+    /// The id of the vertex array, generated by OpenGL. Only valid as long as the vertex array it was taken from is alive
+    pub id: gl::types::GLuint,
+}
+
+impl PartialEq for VertexArrayInner {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl Eq for VertexArrayInner {}
+
+impl std::hash::Hash for VertexArrayInner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+// It doesn't really make sense for vertex arrays to be ordered but there are usecases where you'd want to store them in a set/map
+impl PartialOrd for VertexArrayInner {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VertexArrayInner {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/**
+A vertex array stores the layout of the vertex attributes and the buffers they are sourced from.
+
+# Example
+```ignore
+// interleaved layout of a vec2 position followed by a vec2 texture coordinate
+let vao = VertexArray::new().expect("Unable to create vertex array");
+buffer.bind();
+vao.vertex_attrib_pointer(0, 2, AttribType::Float, false, 16, 0).expect("Unable to describe positions");
+vao.vertex_attrib_pointer(1, 2, AttribType::Float, false, 16, 8).expect("Unable to describe texture coordinates");
+```
+*/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VertexArray {
+    inner: VertexArrayInner,
+}
+
+impl std::fmt::Debug for VertexArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VertexArray").field("id", &self.inner.id).finish()
+    }
+}
+
+impl VertexArray {
+    /// Returns a reference to the inner (private) data of the vertex array.
+    /// Use at your own risk, no guarantees are made to the data itself.
+    ///
+    /// # Safety
+    /// The returned data must not be used to manipulate the underlying OpenGL object behind graphene's back.
+    pub unsafe fn inner(&self) -> &VertexArrayInner {
+        &self.inner
+    }
+
+    /// Returns a reference to the inner (private) data of the vertex array.
+    /// Use at your own risk, no guarantees are made to the data itself, mutating it is to be considered UB.
+    ///
+    /// # Safety
+    /// Mutating the returned data is undefined behaviour, the reference may only be used for reading.
+    pub unsafe fn inner_mut(&mut self) -> &mut VertexArrayInner {
+        &mut self.inner
+    }
+
+    /// Retrieves the id of the vertex array.
+    pub fn get_id(&self) -> gl::types::GLuint {
+        self.inner.id
+    }
+
+    /// Returns a copy of the vertex array's id, which is the safe alternative to [inner](VertexArray::inner) for reading.
+    pub fn snapshot(&self) -> VertexArraySnapshot {
+        VertexArraySnapshot { id: self.inner.id }
+    }
+
+    /// Binds the vertex array.
+    pub fn bind(&self) {
+        super::check_context::<VertexArray>(self.inner.context, self.inner.id);
+
+        unsafe { gl_call!(gl::BindVertexArray(self.inner.id)) };
+    }
+
+    /// Unbinds any vertex array, including this one.
+    pub fn unbind(&self) {
+        unsafe { gl_call!(gl::BindVertexArray(0)) };
+    }
+}
+
+impl super::GlObject for VertexArray {
+    const SHAREABLE: bool = false;
+}
+
+impl Drop for VertexArray {
+    fn drop(&mut self) {
+        unsafe { gl_call!(gl::DeleteVertexArrays(1, &self.inner.id)) };
+    }
+}
+
+/// Error enum for the failed creation of a vertex array
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum VertexArrayCreationError {
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl VertexArray {
+    /// Returns a new vertex array or an error if one occurs in the underlying driver, which shouldn't happen realistically speaking.
+    pub fn new() -> Result<VertexArray, VertexArrayCreationError> {
+        let mut id = 0;
+
+        unsafe { gl_call!(gl::GenVertexArrays(1, &mut id)) };
+
+        if id == 0 {
+            return Err(VertexArrayCreationError::Unknown);
+        }
+
+        Ok(VertexArray {
+            inner: VertexArrayInner {
+                id,
+                context: super::current_context_id(),
+            },
+        })
+    }
+}
+
+/// Error enum for the failed description of a vertex attribute
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum VertexAttribError {
+    /// Used if the index exceeds GL_MAX_VERTEX_ATTRIBS - 1, the size isn't between 1 and 4 or the stride is negative
+    #[error("Invalid attribute index, size or stride")]
+    InvalidValue,
+    /// Used if no buffer is bound to GL_ARRAY_BUFFER or the type can't be used with the size
+    #[error("No array buffer is bound or the type is incompatible with the size")]
+    InvalidOperation,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+impl VertexArray {
+    /**
+    Binds the vertex array, enables the attribute at the passed index and describes how its data is sourced from the
+    buffer currently bound to GL_ARRAY_BUFFER.
+
+    `size` is the number of components of the attribute, `stride` the distance in bytes between two consecutive
+    vertices and `offset` the offset in bytes of the attribute's first component within the buffer.
+    Integer types are converted to floating point values, normalised into the range of 0 to 1 (or -1 to 1) if `normalized` is set.
+    The vertex array stays bound afterwards.
+    */
+    pub fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: i32,
+        ty: AttribType,
+        normalized: bool,
+        stride: i32,
+        offset: usize,
+    ) -> Result<(), VertexAttribError> {
+        self.bind();
+
+        let rc = unsafe {
+            gl::EnableVertexAttribArray(index);
+            gl::VertexAttribPointer(
+                index,
+                size,
+                ty.as_glenum(),
+                normalized as gl::types::GLboolean,
+                stride,
+                offset as *const _,
+            );
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(()),
+            gl::INVALID_VALUE => Err(VertexAttribError::InvalidValue),
+            gl::INVALID_OPERATION => Err(VertexAttribError::InvalidOperation),
+            _ => Err(VertexAttribError::Unknown),
+        }
+    }
+}
+
+impl VertexArray {
+    /// Binds the vertex array and returns the indices of all enabled vertex attributes in ascending order.
+    pub fn enabled_attributes(&self) -> Vec<u32> {
+        let mut max = 0;
+
+        unsafe { gl_call!(gl::GetIntegerv(gl::MAX_VERTEX_ATTRIBS, &mut max)) };
+
+        self.bind();
+
+        (0..max.max(0) as u32)
+            .filter(|index| {
+                let mut enabled = 0;
+                unsafe { gl_call!(gl::GetVertexAttribiv(*index, gl::VERTEX_ATTRIB_ARRAY_ENABLED, &mut enabled)) };
+                enabled != 0
+            })
+            .collect()
+    }
+}
+
+/// The state of a single vertex attribute of a vertex array as reported by OpenGL, see [attribute_state](VertexArray::attribute_state)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct AttributeState {
+    /// Whether the attribute is enabled, disabled attributes use the current generic value instead of the buffer
+    pub enabled: bool,
+    /// The number of components of the attribute
+    pub size: i32,
+    /// The type of the components as they are stored in the buffer
+    pub ty: AttribType,
+    /// Whether integer components are normalised
+    pub normalized: bool,
+    /// The distance in bytes between two consecutive vertices, 0 if they're tightly packed
+    pub stride: i32,
+    /// The id of the buffer the attribute is sourced from, 0 if none was bound when it was described
+    pub buffer: gl::types::GLuint,
+}
+
+/// Error enum for the failed query of a vertex attribute's state
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum AttributeStateError {
+    /// Used if the index exceeds GL_MAX_VERTEX_ATTRIBS - 1
+    #[error("Invalid attribute index")]
+    InvalidIndex,
+    /// Used if OpenGL reported a component type that isn't known to graphene
+    #[error("OpenGL reported an unknown attribute type: {source}")]
+    UnknownType {
+        #[from]
+        /// The underlying enum conversion error
+        source: super::InvalidEnumValue,
+    },
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// The parameters queried for a vertex attribute, in the order [attribute_state_from_params] expects their values
+const ATTRIBUTE_PARAMS: [gl::types::GLenum; 6] = [
+    gl::VERTEX_ATTRIB_ARRAY_ENABLED,
+    gl::VERTEX_ATTRIB_ARRAY_SIZE,
+    gl::VERTEX_ATTRIB_ARRAY_TYPE,
+    gl::VERTEX_ATTRIB_ARRAY_NORMALIZED,
+    gl::VERTEX_ATTRIB_ARRAY_STRIDE,
+    gl::VERTEX_ATTRIB_ARRAY_BUFFER_BINDING,
+];
+
+/// Builds the state of an attribute from the raw values of [ATTRIBUTE_PARAMS]
+fn attribute_state_from_params(params: [gl::types::GLint; 6]) -> Result<AttributeState, super::InvalidEnumValue> {
+    let [enabled, size, ty, normalized, stride, buffer] = params;
+
+    Ok(AttributeState {
+        enabled: enabled != 0,
+        size,
+        ty: AttribType::try_from(ty as gl::types::GLenum)?,
+        normalized: normalized != 0,
+        stride,
+        buffer: buffer as gl::types::GLuint,
+    })
+}
+
+impl VertexArray {
+    /**
+    Binds the vertex array and returns the state of the attribute at the passed index, e.g. to find out why a mesh is
+    rendered garbled.
+
+    # Example
+    ```ignore
+    vao.vertex_attrib_pointer(1, 2, AttribType::Float, false, 16, 8).expect("Unable to describe texture coordinates");
+
+    let state = vao.attribute_state(1).expect("Unable to query attribute");
+    assert!(state.enabled);
+    assert_eq!((state.size, state.ty, state.stride), (2, AttribType::Float, 16));
+    ```
+    */
+    pub fn attribute_state(&self, index: u32) -> Result<AttributeState, AttributeStateError> {
+        self.bind();
+
+        let mut params = [0; 6];
+
+        let rc = unsafe {
+            for (param, value) in ATTRIBUTE_PARAMS.iter().zip(params.iter_mut()) {
+                gl::GetVertexAttribiv(index, *param, value);
+            }
+            super::get_error()
+        };
+
+        match rc {
+            gl::NO_ERROR => Ok(attribute_state_from_params(params)?),
+            gl::INVALID_VALUE => Err(AttributeStateError::InvalidIndex),
+            _ => Err(AttributeStateError::Unknown),
+        }
+    }
+}
+
+/// Error enum for the failed setup of separate vertex attribute formats and vertex buffer bindings
+#[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum VertexBindingError {
+    /// Used if the driver doesn't provide separate attribute formats, which are part of OpenGL 4.3
+    #[error("Separate vertex attribute formats are not supported by the driver")]
+    Unsupported,
+    /// Used if the index, binding, size, relative offset or stride exceed the limits of the driver
+    #[error("Invalid attribute index, binding point, size, offset or stride")]
+    InvalidValue,
+    /// Used if the type can't be used with the size
+    #[error("The type is incompatible with the size")]
+    InvalidOperation,
+    /// Used if the underlying OpenGL error is unknown to graphene
+    #[error("Unknown Error")]
+    Unknown,
+}
+
+/// Maps the error reported after one of the separate format calls
+fn vertex_binding_result(rc: gl::types::GLenum) -> Result<(), VertexBindingError> {
+    match rc {
+        gl::NO_ERROR => Ok(()),
+        gl::INVALID_VALUE => Err(VertexBindingError::InvalidValue),
+        gl::INVALID_OPERATION => Err(VertexBindingError::InvalidOperation),
+        _ => Err(VertexBindingError::Unknown),
+    }
+}
+
+impl VertexArray {
+    /**
+    Binds the vertex array, enables the attribute at the passed index and describes the format of its data without
+    tying it to a buffer, which is done through [attrib_binding](VertexArray::attrib_binding) and
+    [bind_vertex_buffer](VertexArray::bind_vertex_buffer) instead.
+
+    `relative_offset` is the offset in bytes of the attribute's first component within a vertex.
+    Compared to [vertex_attrib_pointer](VertexArray::vertex_attrib_pointer) the buffers can be swapped without
+    respecifying the format. Requires OpenGL 4.3, fails with [Unsupported](VertexBindingError::Unsupported) otherwise.
+    The vertex array stays bound afterwards.
+
+    # Example
+    ```ignore
+    // the format is described once, both buffers share the interleaved layout
+    vao.attrib_format(0, 2, AttribType::Float, false, 0).expect("Unable to describe positions");
+    vao.attrib_format(1, 2, AttribType::Float, false, 8).expect("Unable to describe texture coordinates");
+    vao.attrib_binding(0, 0).expect("Unable to bind positions");
+    vao.attrib_binding(1, 0).expect("Unable to bind texture coordinates");
+
+    vao.bind_vertex_buffer(0, &first, 0, 16).expect("Unable to bind first buffer");
+    draw(&vao);
+    vao.bind_vertex_buffer(0, &second, 0, 16).expect("Unable to bind second buffer");
+    draw(&vao);
+    ```
+    */
+    pub fn attrib_format(
+        &self,
+        index: u32,
+        size: i32,
+        ty: AttribType,
+        normalized: bool,
+        relative_offset: u32,
+    ) -> Result<(), VertexBindingError> {
+        if !gl::VertexAttribFormat::is_loaded() {
+            return Err(VertexBindingError::Unsupported);
+        }
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::EnableVertexAttribArray(index);
+            gl::VertexAttribFormat(index, size, ty.as_glenum(), normalized as gl::types::GLboolean, relative_offset);
+            super::get_error()
+        };
+
+        vertex_binding_result(rc)
+    }
+
+    /// Binds the vertex array and sources the attribute at the passed index from the buffer bound to the passed binding point.
+    /// Requires OpenGL 4.3, fails with [Unsupported](VertexBindingError::Unsupported) otherwise.
+    pub fn attrib_binding(&self, index: u32, binding: u32) -> Result<(), VertexBindingError> {
+        if !gl::VertexAttribBinding::is_loaded() {
+            return Err(VertexBindingError::Unsupported);
+        }
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::VertexAttribBinding(index, binding);
+            super::get_error()
+        };
+
+        vertex_binding_result(rc)
+    }
+
+    /**
+    Binds the vertex array and the buffer to the passed binding point, `offset` being the offset in bytes of the first
+    vertex within the buffer and `stride` the distance in bytes between two consecutive vertices.
+
+    Requires OpenGL 4.3, fails with [Unsupported](VertexBindingError::Unsupported) otherwise.
+    */
+    pub fn bind_vertex_buffer(&self, binding: u32, buffer: &Buffer, offset: usize, stride: i32) -> Result<(), VertexBindingError> {
+        if !gl::BindVertexBuffer::is_loaded() {
+            return Err(VertexBindingError::Unsupported);
+        }
+
+        let offset = gl::types::GLintptr::try_from(offset).map_err(|_| VertexBindingError::InvalidValue)?;
+
+        self.bind();
+
+        let rc = unsafe {
+            gl::BindVertexBuffer(binding, buffer.get_id(), offset, stride);
+            super::get_error()
+        };
+
+        vertex_binding_result(rc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_state_follows_the_queried_params() {
+        let state = attribute_state_from_params([1, 3, gl::UNSIGNED_BYTE as gl::types::GLint, 1, 24, 7]);
+
+        assert_eq!(
+            state,
+            Ok(AttributeState {
+                enabled: true,
+                size: 3,
+                ty: AttribType::UnsignedByte,
+                normalized: true,
+                stride: 24,
+                buffer: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_attribute_types_are_rejected() {
+        assert!(attribute_state_from_params([0, 4, gl::RGBA as gl::types::GLint, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn separate_formats_are_unsupported_without_gl_4_3() {
+        // no functions are loaded in tests, so the calls have to bail out before touching the vertex array
+        let vao = VertexArray {
+            inner: VertexArrayInner { id: 1, context: None },
+        };
+
+        assert_eq!(
+            vao.attrib_format(0, 2, AttribType::Float, false, 0),
+            Err(VertexBindingError::Unsupported)
+        );
+        assert_eq!(vao.attrib_binding(0, 0), Err(VertexBindingError::Unsupported));
+
+        // dropping would delete the vertex array through the unloaded function
+        std::mem::forget(vao);
+    }
+
+    #[test]
+    fn debug_shows_the_id() {
+        // dropping would delete the vertex array through the unloaded function
+        let vao = VertexArray {
+            inner: VertexArrayInner { id: 5, context: Some(1) },
+        };
+
+        assert_eq!(format!("{vao:?}"), "VertexArray { id: 5 }");
+        std::mem::forget(vao);
+    }
+
+    #[test]
+    fn snapshot_matches_the_getters() {
+        // dropping would delete the vertex array through the unloaded function
+        let vao = VertexArray {
+            inner: VertexArrayInner { id: 5, context: None },
+        };
+
+        assert_eq!(vao.snapshot().id, vao.get_id());
+        std::mem::forget(vao);
+    }
+}